@@ -3,23 +3,107 @@
 __version__ = "0.1.0a2"
 
 __all__ = [
+    "AccessReview",
+    "AccessReviewEntry",
+    "Anomaly",
+    "AnomalyRule",
+    "ArchivedGrant",
     "Authzee",
+    "AuthzeeConfig",
+    "AuthzRequest",
+    "Clock",
+    "CorpusCase",
+    "CorpusReport",
+    "Deadline",
+    "DecisionEvent",
+    "DecisionExplanation",
+    "DecisionOverride",
+    "DenyBurstRule",
+    "ErrorCode",
+    "ErrorSeverity",
+    "EvaluationBudget",
+    "EvaluationSession",
+    "FailPolicy",
+    "FailureDecision",
+    "FixedClock",
     "Grant",
+    "GrantCompaction",
     "GrantEffect",
+    "GrantExplanation",
+    "GrantImpact",
+    "GrantIndex",
+    "GrantLifecyclePolicy",
+    "GrantLimits",
+    "GrantMatchMode",
+    "GrantProvenance",
+    "GrantSort",
+    "GrantStats",
+    "GrantUsageRecorder",
     "GrantsPage",
+    "OverrideDecisionRule",
+    "PolicyCapsule",
+    "RelationTuple",
+    "RelationshipResolver",
+    "RequestLimits",
     "ResourceAction",
     "ResourceAuthz",
+    "ResourceDescription",
+    "RiskProvider",
+    "Rng",
+    "SeededRng",
 ]
 
 from authzee import logging_config
 logging_config
 
+from authzee.access_review import AccessReview
+from authzee.access_review_entry import AccessReviewEntry
+from authzee.anomaly import Anomaly
+from authzee.anomaly_rule import AnomalyRule
+from authzee.archived_grant import ArchivedGrant
 from authzee.authzee import Authzee
+from authzee.authzee_config import AuthzeeConfig
+from authzee.authz_request import AuthzRequest
+from authzee.clock import Clock
+from authzee.corpus_case import CorpusCase
+from authzee.corpus_report import CorpusReport
+from authzee.deadline import Deadline
+from authzee.decision_event import DecisionEvent
+from authzee.decision_explanation import DecisionExplanation
+from authzee.decision_override import DecisionOverride
+from authzee.deny_burst_rule import DenyBurstRule
+from authzee.error_code import ErrorCode
+from authzee.error_severity import ErrorSeverity
+from authzee.evaluation_budget import EvaluationBudget
+from authzee.evaluation_session import EvaluationSession
+from authzee.fail_policy import FailPolicy
+from authzee.failure_decision import FailureDecision
+from authzee.fixed_clock import FixedClock
 from authzee.grant import Grant
+from authzee.grant_compaction import GrantCompaction
 from authzee.grant_effect import GrantEffect
+from authzee.grant_explanation import GrantExplanation
+from authzee.grant_impact import GrantImpact
+from authzee.grant_index import GrantIndex
+from authzee.grant_lifecycle_policy import GrantLifecyclePolicy
+from authzee.grant_limits import GrantLimits
+from authzee.grant_match_mode import GrantMatchMode
+from authzee.grant_provenance import GrantProvenance
+from authzee.grant_sort import GrantSort
+from authzee.grant_stats import GrantStats
+from authzee.grant_usage_recorder import GrantUsageRecorder
 from authzee.grants_page import GrantsPage
+from authzee.override_decision_rule import OverrideDecisionRule
+from authzee.policy_capsule import PolicyCapsule
+from authzee.relation_tuple import RelationTuple
+from authzee.relationship_resolver import RelationshipResolver
+from authzee.request_limits import RequestLimits
 from authzee.resource_action import ResourceAction
 from authzee.resource_authz import ResourceAuthz
+from authzee.resource_description import ResourceDescription
+from authzee.risk_provider import RiskProvider
+from authzee.rng import Rng
+from authzee.seeded_rng import SeededRng
 
 from authzee.compute import *
 from authzee.storage import *
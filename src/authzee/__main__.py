@@ -0,0 +1,67 @@
+
+import argparse
+import importlib
+import sys
+from typing import List, Optional
+
+from authzee.authzee import Authzee
+
+
+def load_app(app_path: str) -> Authzee:
+    """Import an ``Authzee`` app from a ``module:attribute`` path.
+
+    Parameters
+    ----------
+    app_path : str
+        Import path of the app, like ``"my_project.authz:authzee_app"`` .
+        The app should be initialized when the module is imported.
+
+    Returns
+    -------
+    Authzee
+        The app.
+    """
+    module_name, _, attribute = app_path.partition(":")
+    app = getattr(importlib.import_module(module_name), attribute)
+    if isinstance(app, Authzee) is False:
+        raise TypeError("'{}' is not an Authzee app.".format(app_path))
+
+    return app
+
+
+def main(argv: Optional[List[str]] = None) -> int:
+    """Command line interface for ``authzee`` .
+
+    - ``run-corpus APP CORPUS`` - Run a decision corpus file with ``Authzee.run_corpus()`` and print the report as JSON.
+      Exits with ``1`` if any case failed.
+
+    Parameters
+    ----------
+    argv : Optional[List[str]], optional
+        Command line arguments, by default ``sys.argv[1:]`` .
+
+    Returns
+    -------
+    int
+        The exit code.
+    """
+    parser = argparse.ArgumentParser(prog="authzee")
+    subparsers = parser.add_subparsers(dest="command", required=True)
+    run_corpus_parser = subparsers.add_parser("run-corpus", help="Run a decision corpus and report failures and latency.")
+    run_corpus_parser.add_argument("app", help="Import path of an initialized Authzee app, like 'my_project.authz:authzee_app'.")
+    run_corpus_parser.add_argument("corpus", help="Path to the corpus JSON file.")
+    run_corpus_parser.add_argument("--page-size", type=int, default=None, help="Page size for the storage backend.")
+    args = parser.parse_args(argv)
+
+    app = load_app(app_path=args.app)
+    with open(args.corpus) as corpus_file:
+        cases = app.corpus_from_json(corpus_json=corpus_file.read())
+
+    report = app.run_corpus(cases=cases, page_size=args.page_size)
+    print(report.model_dump_json(indent=4))
+
+    return 0 if report.all_passed is True else 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
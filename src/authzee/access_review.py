@@ -0,0 +1,69 @@
+
+import csv
+import io
+import json
+from typing import List
+
+from pydantic import BaseModel
+
+from authzee.access_review_entry import AccessReviewEntry
+
+
+CSV_COLUMNS = ["principal", "resource_type", "resource", "resource_action", "allowed"]
+
+
+class AccessReview(BaseModel):
+    """Entitlement matrix of which principals can perform which actions on which resources.
+
+    - ``entries`` - One ``AccessReviewEntry`` for each principal, resource and resource action.
+    """
+
+    entries: List[AccessReviewEntry]
+
+
+    def allowed_entries(self) -> List[AccessReviewEntry]:
+        """Get only the entries that are allowed.
+
+        Returns
+        -------
+        List[AccessReviewEntry]
+            The allowed entries.
+        """
+        return [entry for entry in self.entries if entry.allowed is True]
+
+
+    def to_csv(self) -> str:
+        """Export the entries as CSV.
+
+        The columns are ``principal`` , ``resource_type`` , ``resource`` , ``resource_action`` and ``allowed`` .
+        The resource is written as JSON.
+
+        Returns
+        -------
+        str
+            CSV with a header row.
+        """
+        output = io.StringIO()
+        writer = csv.writer(output)
+        writer.writerow(CSV_COLUMNS)
+        for entry in self.entries:
+            writer.writerow([
+                entry.principal,
+                entry.resource_type,
+                json.dumps(entry.resource, sort_keys=True),
+                entry.resource_action,
+                entry.allowed
+            ])
+
+        return output.getvalue()
+
+
+    def to_json(self) -> str:
+        """Export the entries as a JSON list.
+
+        Returns
+        -------
+        str
+            JSON list of entries.
+        """
+        return json.dumps([json.loads(entry.json()) for entry in self.entries])
@@ -0,0 +1,21 @@
+
+from typing import Any, Dict
+
+from pydantic import BaseModel
+
+
+class AccessReviewEntry(BaseModel):
+    """One decision of an access review.
+
+    - ``principal`` - Name of the principal, the set of identities that was authorized.
+    - ``resource_type`` - Name of the resource type.
+    - ``resource`` - The canonical resource, without ``ResourceAuthz.hidden_resource_fields`` .
+    - ``resource_action`` - Value of the resource action, like ``"documents:read"`` .
+    - ``allowed`` - ``True`` if the principal is authorized to perform the action on the resource.
+    """
+
+    principal: str
+    resource_type: str
+    resource: Dict[str, Any]
+    resource_action: str
+    allowed: bool
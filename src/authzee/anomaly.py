@@ -0,0 +1,17 @@
+
+from pydantic import BaseModel
+
+from authzee.decision_event import DecisionEvent
+
+
+class Anomaly(BaseModel):
+    """A decision flagged by an ``AnomalyRule`` , passed to the ``Authzee`` anomaly hooks.
+
+    - ``rule`` - Class name of the rule that flagged the decision.
+    - ``reason`` - Why the decision was flagged.
+    - ``event`` - The decision.
+    """
+
+    rule: str
+    reason: str
+    event: DecisionEvent
@@ -0,0 +1,62 @@
+
+from typing import Optional
+
+from authzee import exceptions
+from authzee.decision_event import DecisionEvent
+
+
+class AnomalyRule:
+    """Base class for rules that flag suspicious authorization decisions.
+
+    When anomaly rules are given to ``Authzee`` , every decision of ``authorize`` and ``authorize_many`` is checked by each rule,
+    and the anomaly hooks are called with an ``Anomaly`` for each rule that flags the decision.
+    Rules may keep state between decisions, but must be thread safe when ``Authzee`` is shared between threads.
+
+    Sub-classes must implement:
+
+        - ``check`` - Check a decision.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import AnomalyRule, DecisionEvent
+
+        class AllowedDeleteRule(AnomalyRule):
+
+            def check(self, event: DecisionEvent) -> Optional[str]:
+                if event.allowed is True and event.resource_action.endswith("Delete"):
+                    return "Delete was allowed."
+
+                return None
+
+
+        authzee_app = Authzee(
+            compute_backend=compute,
+            storage_backend=storage,
+            anomaly_rules=[AllowedDeleteRule(), DenyBurstRule(max_denies=10, window=60)],
+            anomaly_hooks=[lambda anomaly: alerts.send(anomaly.json())]
+        )
+
+    """
+
+
+    def check(self, event: DecisionEvent) -> Optional[str]:
+        """Check if a decision is suspicious.
+
+        Parameters
+        ----------
+        event : DecisionEvent
+            The decision.
+
+        Returns
+        -------
+        Optional[str]
+            The reason the decision is suspicious, or ``None`` if it is not.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
@@ -0,0 +1,20 @@
+
+import datetime
+
+from pydantic import BaseModel
+
+from authzee.grant import Grant
+
+
+class ArchivedGrant(BaseModel):
+    """A grant that was moved to the archive.
+
+    Archived grants are not evaluated for requests, but are kept for audits.
+    See ``Authzee.archive_grant()`` and ``GrantLifecyclePolicy`` .
+
+    - ``grant`` - The grant as it was when it was archived.
+    - ``archived_at`` - When the grant was archived.
+    """
+
+    grant: Grant
+    archived_at: datetime.datetime
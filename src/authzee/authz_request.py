@@ -0,0 +1,20 @@
+
+from typing import List
+
+from pydantic import BaseModel
+
+from authzee.resource_action import ResourceAction
+
+
+class AuthzRequest(BaseModel):
+    """The inputs of a single authorization request.
+
+    Holds the same arguments as ``Authzee.authorize`` so requests can be stored and replayed.
+    """
+
+    resource: BaseModel
+    resource_action: ResourceAction
+    parent_resources: List[BaseModel] = []
+    child_resources: List[BaseModel] = []
+    identities: List[BaseModel] = []
+
@@ -1,21 +1,57 @@
 
 import copy
+import datetime
 import json
-from typing import Any, AsyncGenerator, Dict, Generator, List, Optional, Set, Type, Union
+import uuid
+from typing import Any, AsyncGenerator, Callable, Dict, Generator, List, Optional, Set, Tuple, Type, Union
 
 import jmespath
 import jmespath.exceptions
+from loguru import logger
 from pydantic import BaseModel
 
+from authzee.access_review import AccessReview
+from authzee.access_review_entry import AccessReviewEntry
+from authzee.anomaly import Anomaly
+from authzee.anomaly_rule import AnomalyRule
+from authzee.archived_grant import ArchivedGrant
+from authzee.authz_request import AuthzRequest
+from authzee.clock import Clock
+from authzee.authzee_config import AuthzeeConfig
 from authzee.compute.compute_backend import ComputeBackend
 from authzee.jmespath_custom_functions import CustomFunctions
 from authzee import exceptions
 from authzee.compute import general as gc
-from authzee.grant import Grant
+from authzee.corpus_case import CorpusCase
+from authzee.corpus_report import CorpusReport, latency_percentile
+from authzee.deadline import check_deadline, Deadline
+from authzee.decision_event import DecisionEvent
+from authzee.decision_explanation import DecisionExplanation
+from authzee.evaluation_budget import EvaluationBudget, spend_budget
+from authzee.evaluation_session import EvaluationSession
+from authzee.failure_decision import FailureDecision
+from authzee.decision_override import DecisionOverride
+from authzee.grant import Grant, sort_grants
 from authzee.grant_effect import GrantEffect
+from authzee.grant_explanation import GrantExplanation
+from authzee.grant_impact import GrantImpact
+from authzee.grant_index import GrantIndex
+from authzee.grant_lifecycle_policy import GrantLifecyclePolicy
+from authzee.grant_limits import expression_depth
+from authzee.grant_sort import GrantSort
+from authzee.grant_stats import GrantStats
+from authzee.grant_usage_recorder import GrantUsageRecorder, record_grant_usage
 from authzee.grants_page import GrantsPage
+from authzee.policy_capsule import grant_capsule_data, PolicyCapsule, redact_fields
+from authzee.policy_docs import render_policy_markdown
+from authzee.relation_tuple import RelationTuple
+from authzee.relationship_resolver import RelationshipResolver
 from authzee.resource_authz import ResourceAuthz
-from authzee.resource_action import ResourceAction
+from authzee.resource_description import ResourceDescription
+from authzee.resource_action import ResourceAction, verify_action_namespaces
+from authzee.risk_provider import RiskProvider
+from authzee.rng import Rng
+from authzee.storage.relation_store import RelationStore
 from authzee.storage.storage_backend import StorageBackend
 
 
@@ -38,6 +74,35 @@ class Authzee:
         Custom JMESPath options to use for grant computations.
         See `python jmespath Options <https://github.com/jmespath/jmespath.py#options>`_ for more information.
         By default, custom functions are used from ``authzee.jmespath_custom_functions.CustomFunctions`` .
+    config : Optional[AuthzeeConfig], optional
+        Limits and error handling options. See ``authzee.AuthzeeConfig`` .
+        By default, no limits are applied and JMESPath errors are ignored.
+    relationship_resolver : Optional[RelationshipResolver], optional
+        Fetches parent and child resources for ``authorize`` and ``authorize_many`` calls.
+        See ``authzee.RelationshipResolver`` .
+        By default, only the given parent and child resources are used.
+    relation_store : Optional[RelationStore], optional
+        Storage for ``RelationTuple`` s that are added to the grant JMESPath data under ``relations`` .
+        See ``ResourceAuthz.relation_object()`` .
+        By default, ``relations`` is always empty.
+    risk_provider : Optional[RiskProvider], optional
+        Scores the risk of the request identities, which is added to the grant JMESPath data under ``risk_score`` .
+        See ``authzee.RiskProvider`` .
+        By default, ``risk_score`` is always ``null`` .
+    anomaly_rules : Optional[List[AnomalyRule]], optional
+        Rules that flag suspicious ``authorize`` and ``authorize_many`` decisions.
+        See ``authzee.AnomalyRule`` .
+        By default, no decisions are flagged.
+    anomaly_hooks : Optional[List[Callable[[Anomaly], None]]], optional
+        Callbacks for each decision flagged by an anomaly rule.
+    clock : Optional[Clock], optional
+        Time source for override expiry and decision timestamps.
+        See ``authzee.Clock`` and ``authzee.FixedClock`` .
+        By default, the system clock is used.
+    rng : Optional[Rng], optional
+        Entropy source for sampling, like canary grants.
+        See ``authzee.Rng`` and ``authzee.SeededRng`` .
+        By default, the ``random`` module is used.
     
     Examples
     --------
@@ -53,12 +118,21 @@ class Authzee:
         storage_backend: StorageBackend,
         identity_types: Optional[Set[Type[BaseModel]]] = None,
         resource_authz_types: Optional[Set[Type[ResourceAuthz]]] = None,
-        jmespath_options: Optional[jmespath.Options] = None
+        jmespath_options: Optional[jmespath.Options] = None,
+        config: Optional[AuthzeeConfig] = None,
+        relationship_resolver: Optional[RelationshipResolver] = None,
+        relation_store: Optional[RelationStore] = None,
+        risk_provider: Optional[RiskProvider] = None,
+        anomaly_rules: Optional[List[AnomalyRule]] = None,
+        anomaly_hooks: Optional[List[Callable[[Anomaly], None]]] = None,
+        clock: Optional[Clock] = None,
+        rng: Optional[Rng] = None
     ):
         self._compute_backend = compute_backend
         self._storage_backend = storage_backend
         self._identity_types: Set[Type[BaseModel]] = set()
         self._identity_type_names: Set[str] = set()
+        self._identity_cardinalities: Dict[Type[BaseModel], Tuple[int, Optional[int]]] = {}
         self._resource_types: Set[Type[BaseModel]] = set()
         self._resource_type_names: Set[str] = set()
         self._authz_types: Set[Type[ResourceAuthz]] = set()
@@ -68,6 +142,20 @@ class Authzee:
         self._resource_to_authz_lookup: Dict[Type[BaseModel], ResourceAuthz] = {}
         self._authz_name_to_authz_type_lookup: Dict[str, Type[ResourceAuthz]] = {}
         self._authz_type_to_authz_lookup: Dict[Type[ResourceAuthz], ResourceAuthz] = {}
+        self._config = config if config is not None else AuthzeeConfig()
+        self._grant_limits = self._config.grant_limits
+        self._request_limits = self._config.request_limits
+        self._jmespath_error_severity = self._config.jmespath_error_severity
+        self._fail_policy = self._config.fail_policy
+        self._relationship_resolver = relationship_resolver
+        self._relation_store = relation_store
+        self._risk_provider = risk_provider
+        self._anomaly_rules = anomaly_rules if anomaly_rules is not None else []
+        self._anomaly_hooks = anomaly_hooks if anomaly_hooks is not None else []
+        self._clock = clock if clock is not None else Clock()
+        self._rng = rng if rng is not None else Rng()
+        self._grant_usage_recorder: Optional[GrantUsageRecorder] = None
+        self._overrides: Dict[str, DecisionOverride] = {}
 
         if identity_types is not None:
             for identity_type in identity_types:
@@ -110,6 +198,16 @@ class Authzee:
                 )
             )
 
+        if (
+            self._request_limits.max_evaluation_cost is not None
+            and self._compute_backend.supports_evaluation_budget is False
+        ):
+            raise exceptions.InputVerificationError(
+                "RequestLimits.max_evaluation_cost is set, but the compute backend {} does not support evaluation budgets.".format(
+                    self._compute_backend
+                )
+            )
+
 
     def initialize(self) -> None:
         """Initialize the ``Authzee`` app.
@@ -157,18 +255,55 @@ class Authzee:
                     self._authz_type_to_authz_lookup[c_authz_type].resource_type
                 )
 
+            action_values = {action.value for action in authz.resource_action_type}
+            parent_action_values = {
+                action.value
+                for p_authz_type in authz._parent_authz_types
+                for action in self._authz_type_to_authz_lookup[p_authz_type].resource_action_type
+            }
+            for action_value, inherited_values in authz.inherited_actions.items():
+                if action_value not in action_values:
+                    raise exceptions.InitializationError(
+                        "The inherited action '{}' in ResourceAuthz '{}' is not one of its actions.".format(
+                            action_value,
+                            authz.__class__.__name__
+                        )
+                    )
+
+                unknown_values = inherited_values - parent_action_values
+                if len(unknown_values) > 0:
+                    raise exceptions.InitializationError(
+                        "The inherited actions {} in ResourceAuthz '{}' are not actions of a parent resource.".format(
+                            sorted(unknown_values),
+                            authz.__class__.__name__
+                        )
+                    )
+
         # check that storage and compute are process compatible (and async?)
 
         self._storage_backend.initialize(
             identity_types=self._identity_types,
             resource_authzs=self._authzs
         )
+        if self._config.track_grant_usage is True:
+            self._grant_usage_recorder = GrantUsageRecorder(
+                storage_backend=self._storage_backend,
+                batch_size=self._config.grant_usage_batch_size,
+                clock=self._clock
+            )
+
         self._compute_backend.initialize(
             identity_types=self._identity_types,
             jmespath_options=self._jmespath_options,
             resource_authzs=self._authzs,
-            storage_backend=self._storage_backend
+            storage_backend=self._storage_backend,
+            jmespath_error_severity=self._jmespath_error_severity,
+            max_search_result_size=self._grant_limits.max_search_result_size,
+            grant_usage_recorder=self._grant_usage_recorder,
+            rng=self._rng
         )
+        if self._relation_store is not None:
+            self._relation_store.initialize()
     
     def shutdown(self) -> None:
         """Early clean up of resources for authzee.
@@ -183,8 +318,11 @@ class Authzee:
             from authzee import Authzee
 
         """
+        self.flush_grant_usage()
         self._storage_backend.shutdown()
         self._compute_backend.shutdown()
+        if self._relation_store is not None:
+            self._relation_store.shutdown()
     
 
     def setup(self) -> None:
@@ -201,6 +339,8 @@ class Authzee:
         """
         self._storage_backend.setup()
         self._compute_backend.setup()
+        if self._relation_store is not None:
+            self._relation_store.setup()
     
 
     def teardown(self) -> None:
@@ -217,16 +357,28 @@ class Authzee:
         """
         self._storage_backend.teardown()
         self._compute_backend.teardown()
+        if self._relation_store is not None:
+            self._relation_store.teardown()
 
 
 
-    def register_identity_type(self, identity_type: Type[BaseModel]) -> None:
+    def register_identity_type(
+        self,
+        identity_type: Type[BaseModel],
+        min_count: int = 0,
+        max_count: Optional[int] = None
+    ) -> None:
         """Register an Identity model type.
 
         Parameters
         ----------
         identity_type : Type[BaseModel]
             Identity model type to register.
+        min_count : int, optional
+            The minimum number of identities of this type in an authorization request, by default 0.
+        max_count : Optional[int], optional
+            The maximum number of identities of this type in an authorization request.
+            By default there is no maximum.
 
         Raises
         ------
@@ -239,6 +391,10 @@ class Authzee:
 
             from authzee import Authzee
 
+            # every request must have exactly one user, and any number of groups
+            authzee_app.register_identity_type(ADUser, min_count=1, max_count=1)
+            authzee_app.register_identity_type(ADGroup)
+
         """
         if identity_type in self._identity_types:
             raise exceptions.IdentityRegistrationError(
@@ -250,8 +406,24 @@ class Authzee:
                 "Identity with name '{}' is already registered with Authzee".format(identity_type.__name__)
             )
         
+        if min_count < 0:
+            raise exceptions.IdentityRegistrationError(
+                "The min_count for identity type '{}' must not be negative.".format(identity_type.__name__)
+            )
+
+        if (
+            max_count is not None
+            and max_count < max(min_count, 1)
+        ):
+            raise exceptions.IdentityRegistrationError(
+                "The max_count for identity type '{}' must be at least 1 and not less than min_count.".format(
+                    identity_type.__name__
+                )
+            )
+        
         self._identity_types.add(identity_type)
         self._identity_type_names.add(identity_type.__name__)
+        self._identity_cardinalities[identity_type] = (min_count, max_count)
     
 
     def register_resource_authz(self, resource_authz_type: Type[ResourceAuthz]) -> None:
@@ -313,7 +485,43 @@ class Authzee:
                     resource_authz_inst.resource
                 )
             )
-        
+
+        for alias_field in resource_authz_inst.resource_alias_fields:
+            if alias_field not in resource_authz_inst.resource_type.model_fields:
+                raise exceptions.ResourceAuthzRegistrationError(
+                    "Resource alias field '{}' is not a field of the '{}' resource model.".format(
+                        alias_field,
+                        resource_authz_inst.resource_type.__name__
+                    )
+                )
+
+        for hidden_field in resource_authz_inst.hidden_resource_fields:
+            if hidden_field not in resource_authz_inst.resource_type.model_fields:
+                raise exceptions.ResourceAuthzRegistrationError(
+                    "Hidden resource field '{}' is not a field of the '{}' resource model.".format(
+                        hidden_field,
+                        resource_authz_inst.resource_type.__name__
+                    )
+                )
+
+            if hidden_field in resource_authz_inst.resource_alias_fields:
+                raise exceptions.ResourceAuthzRegistrationError(
+                    "Resource alias field '{}' of the '{}' resource model cannot be hidden.".format(
+                        hidden_field,
+                        resource_authz_inst.resource_type.__name__
+                    )
+                )
+
+        try:
+            verify_action_namespaces(resource_action_type=resource_authz_inst.resource_action_type)
+        except exceptions.InputVerificationError as error:
+            raise exceptions.ResourceAuthzRegistrationError(
+                "ResourceAuthz '{}' has invalid resource actions. {}".format(
+                    resource_authz_type.__name__,
+                    error
+                )
+            )
+
         self._resource_types.add(resource_authz_inst.resource_type)
         self._resource_type_names.add(resource_authz_inst.resource_type.__name__)
         self._resource_action_types.add(resource_authz_inst.resource_action_type)
@@ -332,7 +540,8 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None
     ) -> bool:
         """Authorize an entity with the given ``identities`` to perform the
         ``resource_action`` on the ``resource`` that has ``parent_resources``
@@ -353,6 +562,9 @@ class Authzee:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.   
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            By default there is no deadline.
 
         Returns
         -------
@@ -361,6 +573,10 @@ class Authzee:
         
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The ``RequestLimits.max_evaluation_cost`` was spent before a decision was made.
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
         
@@ -371,13 +587,29 @@ class Authzee:
             from authzee import Authzee
 
         """
-        self._verify_auth_args(
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
             resource=resource,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
             identities=identities
         )
+        override_decision = self._override_decision(
+            resource=resource,
+            resource_action=resource_action,
+            identities=identities
+        )
+        if override_decision is not None:
+            self._check_anomalies(
+                resources=[resource],
+                resource_action=resource_action,
+                identities=identities,
+                decisions=[override_decision],
+                override_decisions=[override_decision]
+            )
+
+            return override_decision
+
         jmespath_data = self._generate_jmespath_data(
             resource=resource,
             resource_action=resource_action,
@@ -386,12 +618,44 @@ class Authzee:
             identities=identities
         )
 
-        return self._compute_backend.authorize(
-            resource_type=type(resource),
+        check_deadline(deadline=deadline, phase="verification")
+
+        budget = self._new_budget(jmespath_data_entries=[jmespath_data])
+        try:
+            decision = self._compute_backend.authorize(
+                resource_type=type(resource),
+                resource_action=resource_action,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
+            )
+
+            decision = self._inherited_decision(
+                decision=decision,
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                identities=identities,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                budget=budget,
+                deadline=deadline
+            )
+        except (exceptions.ComputeBackendError, exceptions.StorageBackendError) as error:
+            decision = self._failure_decision(resource_action=resource_action, error=error)
+
+        self._flush_full_grant_usage()
+
+        self._check_anomalies(
+            resources=[resource],
             resource_action=resource_action,
-            jmespath_data=jmespath_data,
-            page_size=page_size
+            identities=identities,
+            decisions=[decision],
+            override_decisions=[None]
         )
+
+        return decision
     
 
     async def authorize_async(
@@ -401,7 +665,8 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None
     ) -> bool:
         """Authorize an entity with the given ``identities`` to perform the
         ``resource_action`` on the ``resource`` that has ``parent_resources``
@@ -422,6 +687,9 @@ class Authzee:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            By default there is no deadline.
 
         Returns
         -------
@@ -432,6 +700,10 @@ class Authzee:
         ------
         authzee.exceptions.AsyncNotAvailableError
             Async is not available for the storage backend.
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The ``RequestLimits.max_evaluation_cost`` was spent before a decision was made.
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
 
@@ -442,13 +714,29 @@ class Authzee:
             from authzee import Authzee
 
         """
-        self._verify_auth_args(
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
             resource=resource,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
             identities=identities
         )
+        override_decision = self._override_decision(
+            resource=resource,
+            resource_action=resource_action,
+            identities=identities
+        )
+        if override_decision is not None:
+            self._check_anomalies(
+                resources=[resource],
+                resource_action=resource_action,
+                identities=identities,
+                decisions=[override_decision],
+                override_decisions=[override_decision]
+            )
+
+            return override_decision
+
         jmespath_data = self._generate_jmespath_data(
             resource=resource,
             resource_action=resource_action,
@@ -457,12 +745,44 @@ class Authzee:
             identities=identities
         )
 
-        return await self._compute_backend.authorize_async(
-            resource_type=type(resource),
+        check_deadline(deadline=deadline, phase="verification")
+
+        budget = self._new_budget(jmespath_data_entries=[jmespath_data])
+        try:
+            decision = await self._compute_backend.authorize_async(
+                resource_type=type(resource),
+                resource_action=resource_action,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
+            )
+
+            decision = await self._inherited_decision_async(
+                decision=decision,
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                identities=identities,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                budget=budget,
+                deadline=deadline
+            )
+        except (exceptions.ComputeBackendError, exceptions.StorageBackendError) as error:
+            decision = self._failure_decision(resource_action=resource_action, error=error)
+
+        await self._flush_full_grant_usage_async()
+
+        self._check_anomalies(
+            resources=[resource],
             resource_action=resource_action,
-            jmespath_data=jmespath_data,
-            page_size=page_size
+            identities=identities,
+            decisions=[decision],
+            override_decisions=[None]
         )
+
+        return decision
     
 
 
@@ -473,7 +793,10 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        per_resource_children: Optional[List[List[BaseModel]]] = None
     ) -> List[bool]:
         """Authorize an entity with the given ``identities`` to perform the
         ``resource_action`` on the ``resource`` s that have ``parent_resources``
@@ -486,14 +809,25 @@ class Authzee:
         resource_action : ResourceAction
             The resource action to authorize against.
         parent_resources : List[BaseModel]
-            The resource's parent resource models to authorize against.
+            Parent resource models shared by all of the ``resources`` .
         child_resources : List[BaseModel]
-            The resource's child resource models to authorize against. 
+            Child resource models shared by all of the ``resources`` .
         identities : List[BaseModel]
             The entities identities to authorize.
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.   
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            By default there is no deadline.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Parent resource models for each of the ``resources`` , in the same order.
+            They are added to the shared ``parent_resources`` .
+            By default there are only the shared parent resources.
+        per_resource_children : Optional[List[List[BaseModel]]], optional
+            Child resource models for each of the ``resources`` , in the same order.
+            They are added to the shared ``child_resources`` .
+            By default there are only the shared child resources.
 
         Returns
         -------
@@ -502,6 +836,10 @@ class Authzee:
         
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The ``RequestLimits.max_evaluation_cost`` was spent before a decision was made.
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
 
@@ -512,28 +850,88 @@ class Authzee:
             from authzee import Authzee
 
         """
-        self._verify_auth_many_args(
+        per_resource_parents, per_resource_children = self._verify_and_resolve_auth_many_args(
             resources=resources,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
-            identities=identities
+            identities=identities,
+            per_resource_parents=per_resource_parents,
+            per_resource_children=per_resource_children
         )
+        override_decisions = [
+            self._override_decision(
+                resource=resource,
+                resource_action=resource_action,
+                identities=identities
+            )
+            for resource in resources
+        ]
+        if None not in override_decisions:
+            self._check_anomalies(
+                resources=resources,
+                resource_action=resource_action,
+                identities=identities,
+                decisions=override_decisions,
+                override_decisions=override_decisions
+            )
+
+            return override_decisions
+
         jmespath_data = self._generate_many_jmespath_data(
             resources=resources,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
-            identities=identities
+            identities=identities,
+            per_resource_parents=per_resource_parents,
+            per_resource_children=per_resource_children
         )
 
-        return self._compute_backend.authorize_many(
-            resource_type=type(resources[0]),
+        check_deadline(deadline=deadline, phase="verification")
+
+        budget = self._new_budget(jmespath_data_entries=jmespath_data)
+        try:
+            results = self._compute_backend.authorize_many(
+                resource_type=type(resources[0]),
+                resource_action=resource_action,
+                jmespath_data_entries=jmespath_data,
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
+            )
+
+            results = self._inherited_decisions(
+                decisions=results,
+                resources=resources,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                identities=identities,
+                jmespath_data_entries=jmespath_data,
+                page_size=page_size,
+                per_resource_parents=per_resource_parents,
+                budget=budget,
+                deadline=deadline
+            )
+        except (exceptions.ComputeBackendError, exceptions.StorageBackendError) as error:
+            results = [self._failure_decision(resource_action=resource_action, error=error)] * len(resources)
+
+        self._flush_full_grant_usage()
+
+        decisions = [
+            result if override_decision is None else override_decision
+            for result, override_decision in zip(results, override_decisions)
+        ]
+        self._check_anomalies(
+            resources=resources,
             resource_action=resource_action,
-            jmespath_data_entries=jmespath_data,
-            page_size=page_size
+            identities=identities,
+            decisions=decisions,
+            override_decisions=override_decisions
         )
 
+        return decisions
+
 
     async def authorize_many_async(
         self,
@@ -542,7 +940,10 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        per_resource_children: Optional[List[List[BaseModel]]] = None
     ) -> List[bool]:
         """Authorize an entity with the given ``identities`` to perform the
         ``resource_action`` on the ``resource`` that has ``parent_resources``
@@ -555,14 +956,25 @@ class Authzee:
         resource_action : ResourceAction
             The resource action to authorize against.
         parent_resources : List[BaseModel]
-            The resource's parent resource models to authorize against.
+            Parent resource models shared by all of the ``resources`` .
         child_resources : List[BaseModel]
-            The resource's child resource models to authorize against. 
+            Child resource models shared by all of the ``resources`` .
         identities : List[BaseModel]
             The entities identities to authorize.
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            By default there is no deadline.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Parent resource models for each of the ``resources`` , in the same order.
+            They are added to the shared ``parent_resources`` .
+            By default there are only the shared parent resources.
+        per_resource_children : Optional[List[List[BaseModel]]], optional
+            Child resource models for each of the ``resources`` , in the same order.
+            They are added to the shared ``child_resources`` .
+            By default there are only the shared child resources.
 
         Returns
         -------
@@ -573,6 +985,10 @@ class Authzee:
         ------
         authzee.exceptions.AsyncNotAvailableError
             Async is not available for the storage backend.
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The ``RequestLimits.max_evaluation_cost`` was spent before a decision was made.
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
 
@@ -583,27 +999,87 @@ class Authzee:
             from authzee import Authzee
 
         """
-        self._verify_auth_many_args(
+        per_resource_parents, per_resource_children = self._verify_and_resolve_auth_many_args(
             resources=resources,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
-            identities=identities
+            identities=identities,
+            per_resource_parents=per_resource_parents,
+            per_resource_children=per_resource_children
         )
+        override_decisions = [
+            self._override_decision(
+                resource=resource,
+                resource_action=resource_action,
+                identities=identities
+            )
+            for resource in resources
+        ]
+        if None not in override_decisions:
+            self._check_anomalies(
+                resources=resources,
+                resource_action=resource_action,
+                identities=identities,
+                decisions=override_decisions,
+                override_decisions=override_decisions
+            )
+
+            return override_decisions
+
         jmespath_data = self._generate_many_jmespath_data(
             resources=resources,
             resource_action=resource_action,
             parent_resources=parent_resources,
             child_resources=child_resources,
-            identities=identities
+            identities=identities,
+            per_resource_parents=per_resource_parents,
+            per_resource_children=per_resource_children
         )
 
-        return await self._compute_backend.authorize_many_async(
-            resource_type=type(resources[0]),
+        check_deadline(deadline=deadline, phase="verification")
+
+        budget = self._new_budget(jmespath_data_entries=jmespath_data)
+        try:
+            results = await self._compute_backend.authorize_many_async(
+                resource_type=type(resources[0]),
+                resource_action=resource_action,
+                jmespath_data_entries=jmespath_data,
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
+            )
+
+            results = await self._inherited_decisions_async(
+                decisions=results,
+                resources=resources,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                identities=identities,
+                jmespath_data_entries=jmespath_data,
+                page_size=page_size,
+                per_resource_parents=per_resource_parents,
+                budget=budget,
+                deadline=deadline
+            )
+        except (exceptions.ComputeBackendError, exceptions.StorageBackendError) as error:
+            results = [self._failure_decision(resource_action=resource_action, error=error)] * len(resources)
+
+        await self._flush_full_grant_usage_async()
+
+        decisions = [
+            result if override_decision is None else override_decision
+            for result, override_decision in zip(results, override_decisions)
+        ]
+        self._check_anomalies(
+            resources=resources,
             resource_action=resource_action,
-            jmespath_data_entries=jmespath_data,
-            page_size=page_size
+            identities=identities,
+            decisions=decisions,
+            override_decisions=override_decisions
         )
+
+        return decisions
   
 
     def list_grants(
@@ -611,7 +1087,8 @@ class Authzee:
         effect: GrantEffect,
         resource_type: Optional[Type[BaseModel]] = None,
         resource_action: Optional[ResourceAction] = None,
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        consistent: bool = False
     ) -> Generator[Grant, None, None]:
         """List Grants.
 
@@ -628,6 +1105,10 @@ class Authzee:
         page_size : Optional[int], optional
             The page size recommendation for the storage backend.
             The default is set on the storage backend. 
+        consistent : bool, optional
+            Read every page from a snapshot of the grants, by default ``False`` .
+            Grants that are added while listing are not returned.
+            Ignored if the storage backend does not support snapshots.
 
         Returns
         -------
@@ -657,7 +1138,8 @@ class Authzee:
             effect=effect,
             resource_type=resource_type,
             resource_action=resource_action,
-            page_size=page_size
+            page_size=page_size,
+            consistent=consistent
         )
 
 
@@ -666,10 +1148,12 @@ class Authzee:
         effect: GrantEffect,
         resource_type: Optional[Type[BaseModel]],
         resource_action: Optional[ResourceAction],
-        page_size: Optional[int]
+        page_size: Optional[int],
+        deadline: Optional[Deadline] = None,
+        consistent: bool = False
     ) -> Generator[Grant, None, None]:
         did_once = False
-        next_page_ref = None
+        next_page_ref = self._snapshot_page_reference(effect=effect) if consistent is True else None
         grants_page = None
         while (
             did_once is not True
@@ -684,6 +1168,7 @@ class Authzee:
                 next_page_reference=next_page_ref
             )
             grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants)
+            check_deadline(deadline=deadline, phase="storage retrieval")
             next_page_ref = grants_page.next_page_reference
             
             for grant in grants_page.grants:
@@ -695,7 +1180,8 @@ class Authzee:
         effect: GrantEffect,
         resource_type: Optional[Type[BaseModel]] = None,
         resource_action: Optional[ResourceAction] = None,
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        consistent: bool = False
     ) -> AsyncGenerator[Grant, None]:
         """List Grants.
 
@@ -714,6 +1200,10 @@ class Authzee:
         page_size : Optional[int], optional
             The page size recommendation for the storage backend.
             The default is set on the storage backend. 
+        consistent : bool, optional
+            Read every page from a snapshot of the grants, by default ``False`` .
+            Grants that are added while listing are not returned.
+            Ignored if the storage backend does not support snapshots.
 
         Returns
         -------
@@ -744,7 +1234,8 @@ class Authzee:
             effect=effect,
             resource_type=resource_type,
             resource_action=resource_action,
-            page_size=page_size
+            page_size=page_size,
+            consistent=consistent
         )
     
 
@@ -753,10 +1244,12 @@ class Authzee:
         effect: GrantEffect,
         resource_type: Optional[Type[BaseModel]] = None,
         resource_action: Optional[ResourceAction] = None,
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        consistent: bool = False
     ) -> AsyncGenerator[Grant, None]:
         did_once = False
-        next_page_ref = None
+        next_page_ref = await self._snapshot_page_reference_async(effect=effect) if consistent is True else None
         grants_page = None
         while (
             did_once is not True
@@ -771,6 +1264,7 @@ class Authzee:
                 next_page_reference=next_page_ref
             )
             grants_page = await self._storage_backend.normalize_raw_grants_page_async(raw_grants_page=raw_grants)
+            check_deadline(deadline=deadline, phase="storage retrieval")
             next_page_ref = grants_page.next_page_reference
             
             for grant in grants_page.grants:
@@ -911,6 +1405,88 @@ class Authzee:
         return await self._storage_backend.normalize_raw_grants_page_async(raw_grants_page=raw_grants_page)
 
 
+    def create_snapshot(self, effect: GrantEffect) -> Optional[str]:
+        """Create a snapshot of the grants currently in storage, so all pages are read from one version of the grants.
+
+        Pass the snapshot as ``next_page_reference`` to ``get_grants_page`` or ``get_matching_grants_page`` for the first page,
+        and every following page reference stays in the snapshot.
+        Grants that are added while the pages are read are not returned by any page.
+
+        ``list_grants`` and ``list_matching_grants`` use a snapshot when called with ``consistent=True`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Optional[str]
+            The page reference for the first page of the snapshot.
+            ``None`` if the storage backend does not support snapshots, which is the reference to the first page.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            next_page_ref = authzee_app.create_snapshot(effect=GrantEffect.ALLOW)
+            grants_page = authzee_app.get_grants_page(effect=GrantEffect.ALLOW, next_page_reference=next_page_ref)
+
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return self._snapshot_page_reference(effect=effect)
+
+
+    async def create_snapshot_async(self, effect: GrantEffect) -> Optional[str]:
+        """Create a snapshot of the grants currently in storage, so all pages are read from one version of the grants.
+
+        Pass the snapshot as ``next_page_reference`` to ``get_grants_page_async`` or ``get_matching_grants_page_async`` for the first page,
+        and every following page reference stays in the snapshot.
+        Grants that are added while the pages are read are not returned by any page.
+
+        ``list_grants_async`` and ``list_matching_grants_async`` use a snapshot when called with ``consistent=True`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Optional[str]
+            The page reference for the first page of the snapshot.
+            ``None`` if the storage backend does not support snapshots, which is the reference to the first page.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return await self._snapshot_page_reference_async(effect=effect)
+
+
+    def _snapshot_page_reference(self, effect: GrantEffect) -> Optional[str]:
+        if self._storage_backend.supports_snapshots is True:
+            return self._storage_backend.create_snapshot(effect=effect)
+
+        return None
+
+
+    async def _snapshot_page_reference_async(self, effect: GrantEffect) -> Optional[str]:
+        if self._storage_backend.supports_snapshots is True:
+            return await self._storage_backend.create_snapshot_async(effect=effect)
+
+        return None
+
+
     def list_matching_grants(
         self,
         effect: GrantEffect,
@@ -919,7 +1495,10 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        sort_by: Optional[GrantSort] = None,
+        descending: bool = False,
+        consistent: bool = False
     ) -> Generator[Grant, None, None]:
         """List matching grants.
 
@@ -940,6 +1519,16 @@ class Authzee:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        sort_by : Optional[GrantSort], optional
+            Sort the matching grants.
+            All pages are retrieved before the first grant is returned.
+            By default grants are returned in storage order.
+        descending : bool, optional
+            Reverse the sort order, by default False.
+        consistent : bool, optional
+            Read every page from a snapshot of the grants, by default ``False`` .
+            Grants that are added while listing are not returned.
+            Ignored if the storage backend does not support snapshots.
 
         Returns
         -------
@@ -979,7 +1568,10 @@ class Authzee:
             resource_type=type(resource),
             resource_action=resource_action,
             jmespath_data=jmespath_data,
-            page_size=page_size
+            page_size=page_size,
+            sort_by=sort_by,
+            descending=descending,
+            consistent=consistent
         )
     
 
@@ -989,11 +1581,16 @@ class Authzee:
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int]
+        page_size: Optional[int],
+        sort_by: Optional[GrantSort],
+        descending: bool,
+        deadline: Optional[Deadline] = None,
+        consistent: bool = False
     ) -> Generator[Grant, None, None]:
         did_once = False
-        next_page_ref = None
+        next_page_ref = self._snapshot_page_reference(effect=effect) if consistent is True else None
         grants_page = None
+        sorted_grants: List[Grant] = []
         while (
             did_once is not True
             or next_page_ref is not None
@@ -1007,9 +1604,17 @@ class Authzee:
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            check_deadline(deadline=deadline, phase="evaluation")
             next_page_ref = grants_page.next_page_reference
             
-            for grant in grants_page.grants:
+            if sort_by is not None:
+                sorted_grants.extend(grants_page.grants)
+            else:
+                for grant in grants_page.grants:
+                    yield grant
+
+        if sort_by is not None:
+            for grant in sort_grants(grants=sorted_grants, sort_by=sort_by, descending=descending):
                 yield grant
 
 
@@ -1021,7 +1626,10 @@ class Authzee:
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
         identities: List[BaseModel],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        sort_by: Optional[GrantSort] = None,
+        descending: bool = False,
+        consistent: bool = False
     ) -> AsyncGenerator[Grant, None]:
         """List matching grants.
 
@@ -1044,6 +1652,16 @@ class Authzee:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        sort_by : Optional[GrantSort], optional
+            Sort the matching grants.
+            All pages are retrieved before the first grant is returned.
+            By default grants are returned in storage order.
+        descending : bool, optional
+            Reverse the sort order, by default False.
+        consistent : bool, optional
+            Read every page from a snapshot of the grants, by default ``False`` .
+            Grants that are added while listing are not returned.
+            Ignored if the storage backend does not support snapshots.
 
 
         Returns
@@ -1086,7 +1704,10 @@ class Authzee:
             resource_type=type(resource),
             resource_action=resource_action,
             jmespath_data=jmespath_data,
-            page_size=page_size
+            page_size=page_size,
+            sort_by=sort_by,
+            descending=descending,
+            consistent=consistent
         )
 
 
@@ -1096,11 +1717,16 @@ class Authzee:
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int]
+        page_size: Optional[int],
+        sort_by: Optional[GrantSort],
+        descending: bool,
+        deadline: Optional[Deadline] = None,
+        consistent: bool = False
     ) -> AsyncGenerator[Grant, None]:
         did_once = False
-        next_page_ref = None
+        next_page_ref = await self._snapshot_page_reference_async(effect=effect) if consistent is True else None
         grants_page = None
+        sorted_grants: List[Grant] = []
         while (
             did_once is not True
             or next_page_ref is not None
@@ -1114,9 +1740,17 @@ class Authzee:
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            check_deadline(deadline=deadline, phase="evaluation")
             next_page_ref = grants_page.next_page_reference
             
-            for grant in grants_page.grants:
+            if sort_by is not None:
+                sorted_grants.extend(grants_page.grants)
+            else:
+                for grant in grants_page.grants:
+                    yield grant
+
+        if sort_by is not None:
+            for grant in sort_grants(grants=sorted_grants, sort_by=sort_by, descending=descending):
                 yield grant
 
 
@@ -1284,20 +1918,25 @@ class Authzee:
         )
     
 
-    def add_grant(self, effect: GrantEffect, grant: Grant) -> Grant:
+    def add_grant(self, effect: GrantEffect, grant: Grant, dry_run: bool = False) -> Grant:
         """Add a grant.
 
+        With ``dry_run=True`` the grant is fully verified, but it is not stored.
+        Use ``validate_grant_against_corpus`` to also check the decisions the grant would change.
+
         Parameters
         ----------
         effect : GrantEffect
             Effect of the grant to add.
         grant : Grant
             Grant to add.
+        dry_run : bool, optional
+            Only verify the grant without storing it, by default False.
 
         Returns
         -------
         Grant
-            The stored grant.
+            The stored grant, or a copy of the verified grant without a UUID for a dry run.
 
         Raises
         ------
@@ -1315,24 +1954,34 @@ class Authzee:
         """
         self._verify_grant_effect(effect=effect)
         self._verify_grant(grant=grant)
+        if grant.created_at is None:
+            grant = grant.model_copy(update={"created_at": self._clock.now()})
         
+        if dry_run is True:
+            return self._dry_run_grant(grant=grant)
+
         return self._storage_backend.add_grant(effect=effect, grant=grant)
     
 
-    async def add_grant_async(self, effect: GrantEffect, grant: Grant) -> Grant:
+    async def add_grant_async(self, effect: GrantEffect, grant: Grant, dry_run: bool = False) -> Grant:
         """Add a grant.
 
+        With ``dry_run=True`` the grant is fully verified, but it is not stored.
+        Use ``validate_grant_against_corpus`` to also check the decisions the grant would change.
+
         Parameters
         ----------
         effect : GrantEffect
             Effect of the grant to add.
         grant : Grant
             Grant to add.
+        dry_run : bool, optional
+            Only verify the grant without storing it, by default False.
 
         Returns
         -------
         Grant
-            The stored grant.
+            The stored grant, or a copy of the verified grant without a UUID for a dry run.
 
         Raises
         ------
@@ -1352,10 +2001,39 @@ class Authzee:
         """
         self._verify_grant_effect(effect=effect)
         self._verify_grant(grant=grant)
+        if grant.created_at is None:
+            grant = grant.model_copy(update={"created_at": self._clock.now()})
+
+        if dry_run is True:
+            return self._dry_run_grant(grant=grant)
 
         return await self._storage_backend.add_grant_async(effect=effect, grant=grant)
 
 
+    def _dry_run_grant(self, grant: Grant) -> Grant:
+        """Check a verified grant like storage would when it is added, without storing it.
+
+        Parameters
+        ----------
+        grant : Grant
+            Verified grant.
+
+        Returns
+        -------
+        Grant
+            A deep copy of the grant.
+
+        Raises
+        ------
+        authzee.exceptions.GrantUUIDError
+            Grants that are being added should not have a UUID.
+        """
+        if grant.uuid is not None:
+            raise exceptions.GrantUUIDError("Cannot create a grant that has a UUID.")
+
+        return copy.deepcopy(grant)
+
+
     def delete_grant(self, effect: GrantEffect, uuid: str) -> None:
         """Delete a grant.
 
@@ -1412,78 +2090,2972 @@ class Authzee:
         await self._storage_backend.delete_grant_async(effect=effect, uuid=uuid)
 
 
-    def grant_matches( 
-        self,
-        resource: BaseModel,
-        resource_action: ResourceAction,
-        parent_resources: List[BaseModel],
-        child_resources: List[BaseModel],
-        identities: List[BaseModel],
-        grant: Grant
-    ) -> bool:
-        """Verifies a grant, the resources, and identities. Then computes if they match.
+    def archive_grant(self, effect: GrantEffect, uuid: str) -> ArchivedGrant:
+        """Move a grant to the archive.
+
+        Archived grants are not evaluated for requests, but are kept for audits with ``list_archived_grants`` .
 
         Parameters
         ----------
-        resource : BaseModel
-            Resource model.
-        resource_action : ResourceAction
+        effect : GrantEffect
+            Effect of the grant to archive.
+        uuid : str
+            UUID of grant to archive.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+
+        Raises
+        ------
+        authzee.exceptions.GrantDoesNotExistError
+            The given grant does not exist.
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival.
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return self._storage_backend.archive_grant(effect=effect, uuid=uuid, archived_at=self._clock.now())
+
+
+    async def archive_grant_async(self, effect: GrantEffect, uuid: str) -> ArchivedGrant:
+        """Move a grant to the archive.
+
+        Archived grants are not evaluated for requests, but are kept for audits with ``list_archived_grants_async`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            Effect of the grant to archive.
+        uuid : str
+            UUID of grant to archive.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.GrantDoesNotExistError
+            The given grant does not exist.
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival.
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return await self._storage_backend.archive_grant_async(effect=effect, uuid=uuid, archived_at=self._clock.now())
+
+
+    def list_archived_grants(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival.
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return self._storage_backend.list_archived_grants(effect=effect)
+
+
+    async def list_archived_grants_async(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival.
+        """
+        self._verify_grant_effect(effect=effect)
+
+        return await self._storage_backend.list_archived_grants_async(effect=effect)
+
+
+    def apply_lifecycle_policy(
+        self,
+        policy: GrantLifecyclePolicy,
+        page_size: Optional[int] = None
+    ) -> List[ArchivedGrant]:
+        """Move the grants that match a lifecycle policy to the archive, to keep the evaluated grants small.
+
+        Parameters
+        ----------
+        policy : GrantLifecyclePolicy
+            Which grants to archive.
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The grants that were archived.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival,
+            or grant usage tracking if ``policy.archive_unused_for`` is set.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            import datetime
+
+            from authzee import GrantLifecyclePolicy
+
+            archived = authzee_app.apply_lifecycle_policy(
+                policy=GrantLifecyclePolicy(
+                    archive_tags={"status": "retired"},
+                    archive_unused_for=datetime.timedelta(days=365)
+                )
+            )
+
+        """
+        now = self._clock.now()
+        archived_grants: List[ArchivedGrant] = []
+        for effect in GrantEffect:
+            last_used = self.get_grant_usage(effect=effect) if policy.archive_unused_for is not None else {}
+            # Find every grant first, so the pages don't change while they are read
+            uuids = [
+                grant.uuid
+                for grant in self._list_grants(
+                    effect=effect,
+                    resource_type=None,
+                    resource_action=None,
+                    page_size=page_size
+                )
+                if self._lifecycle_archives(policy=policy, grant=grant, last_used=last_used, now=now) is True
+            ]
+            for uuid in uuids:
+                archived_grants.append(
+                    self._storage_backend.archive_grant(effect=effect, uuid=uuid, archived_at=now)
+                )
+
+        return archived_grants
+
+
+    async def apply_lifecycle_policy_async(
+        self,
+        policy: GrantLifecyclePolicy,
+        page_size: Optional[int] = None
+    ) -> List[ArchivedGrant]:
+        """Move the grants that match a lifecycle policy to the archive, to keep the evaluated grants small.
+
+        Parameters
+        ----------
+        policy : GrantLifecyclePolicy
+            Which grants to archive.
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The grants that were archived.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant archival,
+            or grant usage tracking if ``policy.archive_unused_for`` is set.
+        """
+        now = self._clock.now()
+        archived_grants: List[ArchivedGrant] = []
+        for effect in GrantEffect:
+            last_used = await self.get_grant_usage_async(effect=effect) if policy.archive_unused_for is not None else {}
+            # Find every grant first, so the pages don't change while they are read
+            uuids = [
+                grant.uuid
+                async for grant in self._list_grants_async(
+                    effect=effect,
+                    resource_type=None,
+                    resource_action=None,
+                    page_size=page_size
+                )
+                if self._lifecycle_archives(policy=policy, grant=grant, last_used=last_used, now=now) is True
+            ]
+            for uuid in uuids:
+                archived_grants.append(
+                    await self._storage_backend.archive_grant_async(effect=effect, uuid=uuid, archived_at=now)
+                )
+
+        return archived_grants
+
+
+    def _lifecycle_archives(
+        self,
+        policy: GrantLifecyclePolicy,
+        grant: Grant,
+        last_used: Dict[str, datetime.datetime],
+        now: datetime.datetime
+    ) -> bool:
+        if (
+            len(policy.archive_tags) > 0
+            and all(grant.tags.get(key) == value for key, value in policy.archive_tags.items())
+        ):
+            return True
+
+        if policy.archive_unused_for is None:
+            return False
+
+        return self._grant_unused(grant=grant, last_used=last_used, since=now - policy.archive_unused_for)
+
+
+    def grant_stats(self, page_size: Optional[int] = None) -> GrantStats:
+        """Get statistics about the stored grants.
+
+        All grants are retrieved from the storage backend.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        GrantStats
+            Statistics about the stored grants.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            stats = authzee_app.grant_stats()
+            uncovered = [name for name, count in stats.grants_by_resource_type.items() if count == 0]
+
+        """
+        stats = self._new_grant_stats()
+        for effect in GrantEffect:
+            for grant in self._list_grants(
+                effect=effect,
+                resource_type=None,
+                resource_action=None,
+                page_size=page_size
+            ):
+                self._add_grant_to_stats(stats=stats, effect=effect, grant=grant)
+        
+        return stats
+
+
+    async def grant_stats_async(self, page_size: Optional[int] = None) -> GrantStats:
+        """Get statistics about the stored grants.
+
+        All grants are retrieved from the storage backend.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        GrantStats
+            Statistics about the stored grants.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            stats = await authzee_app.grant_stats_async()
+            uncovered = [name for name, count in stats.grants_by_resource_type.items() if count == 0]
+
+        """
+        stats = self._new_grant_stats()
+        for effect in GrantEffect:
+            async for grant in self._list_grants_async(
+                effect=effect,
+                resource_type=None,
+                resource_action=None,
+                page_size=page_size
+            ):
+                self._add_grant_to_stats(stats=stats, effect=effect, grant=grant)
+        
+        return stats
+
+
+    def flush_grant_usage(self) -> None:
+        """Write the grant usage that is held for batching to the storage backend.
+
+        Does nothing when ``AuthzeeConfig.track_grant_usage`` is ``False`` .
+
+        Examples
+        --------
+        .. code-block:: python
+
+            authzee_app.flush_grant_usage()
+
+        """
+        if self._grant_usage_recorder is not None:
+            self._grant_usage_recorder.flush()
+
+
+    def get_grant_usage(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants last decided or matched a request.
+
+        Requires ``AuthzeeConfig.track_grant_usage`` .
+        Pending grant usage is flushed to storage first.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+            Grants that were never used are not included.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant usage tracking.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import GrantEffect
+
+            last_used = authzee_app.get_grant_usage(effect=GrantEffect.ALLOW)
+
+        """
+        self._verify_grant_effect(effect=effect)
+        self.flush_grant_usage()
+
+        return self._storage_backend.get_grant_usage(effect=effect)
+
+
+    async def get_grant_usage_async(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants last decided or matched a request.
+
+        Requires ``AuthzeeConfig.track_grant_usage`` .
+        Pending grant usage is flushed to storage first.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+            Grants that were never used are not included.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant usage tracking.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import GrantEffect
+
+            last_used = await authzee_app.get_grant_usage_async(effect=GrantEffect.ALLOW)
+
+        """
+        self._verify_grant_effect(effect=effect)
+        self.flush_grant_usage()
+
+        return await self._storage_backend.get_grant_usage_async(effect=effect)
+
+
+    def list_unused_grants(
+        self,
+        effect: GrantEffect,
+        since: Optional[datetime.datetime] = None,
+        page_size: Optional[int] = None
+    ) -> Generator[Grant, None, None]:
+        """List grants that were never used, or not used since a time.
+
+        Requires ``AuthzeeConfig.track_grant_usage`` .
+        Unused grants are candidates for review and clean up.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        since : Optional[datetime.datetime], optional
+            Also list grants that were last used before this time.
+            Naive times are local time.
+            By default only grants that were never used are listed.
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Yields
+        ------
+        Grant
+            Unused grants.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant usage tracking.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            import datetime
+
+            from authzee import GrantEffect
+
+            stale = list(
+                authzee_app.list_unused_grants(
+                    effect=GrantEffect.ALLOW,
+                    since=datetime.datetime.now(datetime.timezone.utc) - datetime.timedelta(days=90)
+                )
+            )
+
+        """
+        last_used = self.get_grant_usage(effect=effect)
+        for grant in self._list_grants(
+            effect=effect,
+            resource_type=None,
+            resource_action=None,
+            page_size=page_size
+        ):
+            if self._grant_unused(grant=grant, last_used=last_used, since=since) is True:
+                yield grant
+
+
+    async def list_unused_grants_async(
+        self,
+        effect: GrantEffect,
+        since: Optional[datetime.datetime] = None,
+        page_size: Optional[int] = None
+    ) -> AsyncGenerator[Grant, None]:
+        """List grants that were never used, or not used since a time.
+
+        Requires ``AuthzeeConfig.track_grant_usage`` .
+        Unused grants are candidates for review and clean up.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        since : Optional[datetime.datetime], optional
+            Also list grants that were last used before this time.
+            Naive times are local time.
+            By default only grants that were never used are listed.
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Yields
+        ------
+        Grant
+            Unused grants.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.MethodNotImplementedError
+            The storage backend does not support grant usage tracking.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import GrantEffect
+
+            never_used = [grant async for grant in authzee_app.list_unused_grants_async(effect=GrantEffect.DENY)]
+
+        """
+        last_used = await self.get_grant_usage_async(effect=effect)
+        async for grant in self._list_grants_async(
+            effect=effect,
+            resource_type=None,
+            resource_action=None,
+            page_size=page_size
+        ):
+            if self._grant_unused(grant=grant, last_used=last_used, since=since) is True:
+                yield grant
+
+
+    def _grant_unused(
+        self,
+        grant: Grant,
+        last_used: Dict[str, datetime.datetime],
+        since: Optional[datetime.datetime]
+    ) -> bool:
+        if grant.uuid not in last_used:
+            return True
+
+        if since is None:
+            return False
+
+        return last_used[grant.uuid] < since.astimezone(datetime.timezone.utc)
+
+
+    def _new_grant_stats(self) -> GrantStats:
+        return GrantStats(
+            total_grants=0,
+            grants_by_effect={effect.value: 0 for effect in GrantEffect},
+            grants_by_resource_type={resource_type.__name__: 0 for resource_type in self._resource_types},
+            grants_by_action={
+                str(resource_action): 0
+                for resource_action_type in self._resource_action_types
+                for resource_action in resource_action_type
+            },
+            average_grant_size=0.0
+        )
+
+
+    def _add_grant_to_stats(self, stats: GrantStats, effect: GrantEffect, grant: Grant) -> None:
+        stats.total_grants += 1
+        stats.grants_by_effect[effect.value] += 1
+        resource_type_name = grant.resource_type.__name__
+        stats.grants_by_resource_type[resource_type_name] = stats.grants_by_resource_type.get(resource_type_name, 0) + 1
+        for resource_action in grant.resource_actions:
+            action_name = str(resource_action)
+            stats.grants_by_action[action_name] = stats.grants_by_action.get(action_name, 0) + 1
+        
+        grant_size = len(grant.jmespath_expression) + len(json.dumps(grant.result_match))
+        stats.average_grant_size += (grant_size - stats.average_grant_size) / stats.total_grants
+
+
+    def describe(self) -> List[ResourceDescription]:
+        """Describe the valid actions, identity types, parent and child types, and JMESPath data keys for each resource type.
+
+        The description is derived from the registered definitions,
+        so admin UIs and SDK code generation can offer autocompletion without parsing JSON schemas.
+        ``initialize()`` must be called first, so parent and child types are resolved.
+
+        Returns
+        -------
+        List[ResourceDescription]
+            Description of each registered resource type, sorted by resource type name.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            descriptions = {description.resource_type: description for description in authzee_app.describe()}
+            actions = descriptions["Balloon"].resource_actions
+
+        """
+        identity_types = sorted(self._identity_types, key=lambda identity_type: identity_type.__name__)
+        descriptions = []
+        for authz in sorted(self._authzs, key=lambda authz: authz.resource_type.__name__):
+            parent_types = sorted(authz._parent_resource_types, key=lambda resource_type: resource_type.__name__)
+            child_types = sorted(authz._child_resource_types, key=lambda resource_type: resource_type.__name__)
+            jmespath_data_keys = ["resource_type", "resource_action", "relations", "risk_score"]
+            jmespath_data_keys.extend(
+                "resource.{}".format(field_name)
+                for field_name in self._visible_resource_fields(resource_type=authz.resource_type)
+            )
+            for prefix, model_types in (
+                ("identities", identity_types),
+                ("parent_resources", parent_types),
+                ("child_resources", child_types)
+            ):
+                for model_type in model_types:
+                    if prefix == "identities":
+                        field_names = list(model_type.model_fields)
+                    else:
+                        field_names = self._visible_resource_fields(resource_type=model_type)
+
+                    jmespath_data_keys.extend(
+                        "{}.{}[].{}".format(prefix, model_type.__name__, field_name)
+                        for field_name in field_names
+                    )
+
+            descriptions.append(
+                ResourceDescription(
+                    resource_type=authz.resource_type.__name__,
+                    resource_authz=type(authz).__name__,
+                    resource_actions=[str(action) for action in authz.resource_action_type],
+                    identity_types=[identity_type.__name__ for identity_type in identity_types],
+                    parent_resource_types=[resource_type.__name__ for resource_type in parent_types],
+                    child_resource_types=[resource_type.__name__ for resource_type in child_types],
+                    jmespath_data_keys=jmespath_data_keys
+                )
+            )
+
+        return descriptions
+
+
+    def _visible_resource_fields(self, resource_type: Type[BaseModel]) -> List[str]:
+        hidden_fields = self._resource_to_authz_lookup[resource_type].hidden_resource_fields
+
+        return [
+            field_name
+            for field_name in resource_type.model_fields
+            if field_name not in hidden_fields
+        ]
+
+
+    def policy_docs(self, page_size: Optional[int] = None) -> str:
+        """Generate a Markdown report of the registered identity types, resource authzs and the stored grants.
+
+        The report has schema tables for the identity and resource models,
+        the actions and parent and child resource authzs for each resource,
+        and a summary table of the grants for each effect.
+        All grants are retrieved from the storage backend.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        str
+            The Markdown report.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            with open("policy.md", "w") as f:
+                f.write(authzee_app.policy_docs())
+
+        """
+        grants = {
+            effect: list(
+                self._list_grants(
+                    effect=effect,
+                    resource_type=None,
+                    resource_action=None,
+                    page_size=page_size
+                )
+            )
+            for effect in GrantEffect
+        }
+
+        return render_policy_markdown(
+            identity_types=self._identity_cardinalities,
+            resource_authzs=self._authzs,
+            grants=grants
+        )
+
+
+    async def policy_docs_async(self, page_size: Optional[int] = None) -> str:
+        """Generate a Markdown report of the registered identity types, resource authzs and the stored grants.
+
+        The report has schema tables for the identity and resource models,
+        the actions and parent and child resource authzs for each resource,
+        and a summary table of the grants for each effect.
+        All grants are retrieved from the storage backend.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        str
+            The Markdown report.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            with open("policy.md", "w") as f:
+                f.write(await authzee_app.policy_docs_async())
+
+        """
+        grants = {effect: [] for effect in GrantEffect}
+        for effect in GrantEffect:
+            async for grant in self._list_grants_async(
+                effect=effect,
+                resource_type=None,
+                resource_action=None,
+                page_size=page_size
+            ):
+                grants[effect].append(grant)
+
+        return render_policy_markdown(
+            identity_types=self._identity_cardinalities,
+            resource_authzs=self._authzs,
+            grants=grants
+        )
+
+
+    def access_review(
+        self,
+        principals: Dict[str, List[BaseModel]],
+        resources: List[BaseModel],
+        page_size: Optional[int] = None
+    ) -> AccessReview:
+        """Compute which principals can perform which actions on which resources, for periodic access reviews.
+
+        Every action of the ``ResourceAuthz`` of each resource is authorized for every principal,
+        with one ``authorize_many`` call for each principal, resource type and action.
+        Resources are authorized without parent or child resources.
+
+        Parameters
+        ----------
+        principals : Dict[str, List[BaseModel]]
+            Identities for each principal, by principal name.
+        resources : List[BaseModel]
+            The resources to review. They can be of different registered resource types.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        AccessReview
+            The entitlement matrix, which can be exported with ``to_csv()`` or ``to_json()`` .
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            review = authzee_app.access_review(
+                principals={"alice": [ADUser(cn="alice")], "bob": [ADUser(cn="bob")]},
+                resources=[Balloon(color="red", size=1), Balloon(color="blue", size=2)]
+            )
+            with open("access_review.csv", "w") as f:
+                f.write(review.to_csv())
+
+        """
+        entries: List[AccessReviewEntry] = []
+        for principal, identities in principals.items():
+            for resource_type, type_resources in self._resources_by_type(resources=resources).items():
+                for resource_action in self._resource_to_authz_lookup[resource_type].resource_action_type:
+                    results = self.authorize_many(
+                        resources=type_resources,
+                        resource_action=resource_action,
+                        parent_resources=[],
+                        child_resources=[],
+                        identities=identities,
+                        page_size=page_size
+                    )
+                    entries.extend(
+                        self._access_review_entries(
+                            principal=principal,
+                            resources=type_resources,
+                            resource_action=resource_action,
+                            results=results
+                        )
+                    )
+
+        return AccessReview(entries=entries)
+
+
+    async def access_review_async(
+        self,
+        principals: Dict[str, List[BaseModel]],
+        resources: List[BaseModel],
+        page_size: Optional[int] = None
+    ) -> AccessReview:
+        """Compute which principals can perform which actions on which resources, for periodic access reviews.
+
+        Every action of the ``ResourceAuthz`` of each resource is authorized for every principal,
+        with one ``authorize_many_async`` call for each principal, resource type and action.
+        Resources are authorized without parent or child resources.
+
+        Parameters
+        ----------
+        principals : Dict[str, List[BaseModel]]
+            Identities for each principal, by principal name.
+        resources : List[BaseModel]
+            The resources to review. They can be of different registered resource types.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        AccessReview
+            The entitlement matrix, which can be exported with ``to_csv()`` or ``to_json()`` .
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            review = await authzee_app.access_review_async(
+                principals={"alice": [ADUser(cn="alice")], "bob": [ADUser(cn="bob")]},
+                resources=[Balloon(color="red", size=1), Balloon(color="blue", size=2)]
+            )
+            with open("access_review.csv", "w") as f:
+                f.write(review.to_csv())
+
+        """
+        entries: List[AccessReviewEntry] = []
+        for principal, identities in principals.items():
+            for resource_type, type_resources in self._resources_by_type(resources=resources).items():
+                for resource_action in self._resource_to_authz_lookup[resource_type].resource_action_type:
+                    results = await self.authorize_many_async(
+                        resources=type_resources,
+                        resource_action=resource_action,
+                        parent_resources=[],
+                        child_resources=[],
+                        identities=identities,
+                        page_size=page_size
+                    )
+                    entries.extend(
+                        self._access_review_entries(
+                            principal=principal,
+                            resources=type_resources,
+                            resource_action=resource_action,
+                            results=results
+                        )
+                    )
+
+        return AccessReview(entries=entries)
+
+
+    def _resources_by_type(self, resources: List[BaseModel]) -> Dict[Type[BaseModel], List[BaseModel]]:
+        resources_by_type: Dict[Type[BaseModel], List[BaseModel]] = {}
+        for resource in resources:
+            if type(resource) not in self._resource_types:
+                raise exceptions.InputVerificationError(
+                    "Resource type '{}' is not registered with Authzee.".format(type(resource).__name__)
+                )
+
+            resources_by_type.setdefault(type(resource), []).append(resource)
+
+        return resources_by_type
+
+
+    def _access_review_entries(
+        self,
+        principal: str,
+        resources: List[BaseModel],
+        resource_action: ResourceAction,
+        results: List[bool]
+    ) -> List[AccessReviewEntry]:
+        return [
+            AccessReviewEntry(
+                principal=principal,
+                resource_type=type(resource).__name__,
+                resource=self._canonical_resource_data(resource=resource),
+                resource_action=str(resource_action.value),
+                allowed=allowed
+            )
+            for resource, allowed in zip(resources, results)
+        ]
+
+
+    def add_override(self, override: DecisionOverride) -> DecisionOverride:
+        """Add a decision override.
+
+        Overrides are checked before grants by ``authorize`` and ``authorize_many`` .
+        They are held in memory by this ``Authzee`` app.
+
+        Parameters
+        ----------
+        override : DecisionOverride
+            Override to add.
+
+        Returns
+        -------
+        DecisionOverride
+            The added override with a UUID.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            import datetime
+
+            from authzee import DecisionOverride, GrantEffect
+
+            override = authzee_app.add_override(
+                DecisionOverride(
+                    effect=GrantEffect.DENY,
+                    reason="INC-1234 compromised account",
+                    identity=ADUser(cn="mallory"),
+                    resource_type=Balloon,
+                    resource_actions=set(BalloonAction),
+                    expires_at=datetime.datetime.now() + datetime.timedelta(hours=4)
+                )
+            )
+
+        """
+        if override.uuid is not None:
+            raise exceptions.InputVerificationError(
+                "Overrides that are being added should not have a UUID."
+            )
+
+        if type(override.identity) not in self._identity_types:
+            raise exceptions.InputVerificationError(
+                "The override identity type '{}' is not registered.".format(type(override.identity))
+            )
+
+        if len(override.resource_actions) < 1:
+            raise exceptions.InputVerificationError("A set of at least one resource action must be given in an override.")
+
+        for resource_action in override.resource_actions:
+            self._verify_resource_type_and_action_filter(
+                resource_type=override.resource_type,
+                resource_action=resource_action
+            )
+
+        for field_name in override.resource_match:
+            if field_name not in override.resource_type.model_fields:
+                raise exceptions.InputVerificationError(
+                    "The override resource match field '{}' is not a field of '{}'.".format(
+                        field_name,
+                        override.resource_type
+                    )
+                )
+
+        if override.is_expired(now=self._clock.now()) is True:
+            raise exceptions.InputVerificationError("The override has already expired.")
+
+        new_override = copy.deepcopy(override)
+        new_override.uuid = str(uuid.uuid4())
+        self._overrides[new_override.uuid] = new_override
+
+        return new_override
+
+
+    def delete_override(self, uuid: str) -> None:
+        """Delete a decision override.
+
+        Parameters
+        ----------
+        uuid : str
+            UUID of the override to delete.
+
+        Raises
+        ------
+        authzee.exceptions.OverrideDoesNotExistError
+            The given override does not exist.
+        """
+        if uuid not in self._overrides:
+            raise exceptions.OverrideDoesNotExistError(
+                "The override with UUID '{}' does not exist.".format(uuid)
+            )
+
+        del self._overrides[uuid]
+
+
+    def list_overrides(self) -> List[DecisionOverride]:
+        """List the decision overrides that have not expired.
+
+        Returns
+        -------
+        List[DecisionOverride]
+            The active overrides.
+        """
+        return [
+            override for override in self._overrides.values()
+            if override.is_expired(now=self._clock.now()) is False
+        ]
+
+
+    def add_relation(self, relation_tuple: RelationTuple) -> RelationTuple:
+        """Add a relation tuple to the relation store.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            Relation tuple to add.
+
+        Returns
+        -------
+        RelationTuple
+            The added relation tuple.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            ``Authzee`` does not have a relation store.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import RelationTuple
+
+            authzee_app.add_relation(
+                RelationTuple(subject="user:alice", relation="viewer", object="folder:reports")
+            )
+
+        Grants can then check the relations of the resource and its parents.
+
+        .. code-block:: python
+
+            Grant(
+                name="Parent folder viewers",
+                description="Viewers of a parent folder can read documents.",
+                resource_type=Document,
+                resource_actions={DocumentAction.Read},
+                jmespath_expression="contains(relations[?source == 'parent' && relation == 'viewer'].subject, join(':', ['user', identities.ADUser[0].cn]))",
+                result_match=True
+            )
+
+        """
+        self._verify_relation_store()
+        new_relation_tuple = self._relation_store.add_relation(relation_tuple=relation_tuple)
+        self._relation_store.clear_cache(objects=[relation_tuple.object])
+
+        return new_relation_tuple
+
+
+    def delete_relation(self, relation_tuple: RelationTuple) -> None:
+        """Delete a relation tuple from the relation store.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            Relation tuple to delete.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            ``Authzee`` does not have a relation store.
+        authzee.exceptions.RelationDoesNotExistError
+            The given relation tuple does not exist.
+        """
+        self._verify_relation_store()
+        self._relation_store.delete_relation(relation_tuple=relation_tuple)
+        self._relation_store.clear_cache(objects=[relation_tuple.object])
+
+
+    def check_relation(self, subject: str, relation: str, object: str) -> bool:
+        """Check if a subject has a relation to an object, directly or through subject sets like ``"group:admins#member"`` .
+
+        Parameters
+        ----------
+        subject : str
+            The subject, like ``"user:alice"`` .
+        relation : str
+            The relation, like ``"viewer"`` .
+        object : str
+            The object, like ``"folder:reports"`` .
+
+        Returns
+        -------
+        bool
+            ``True`` if the subject has the relation to the object.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            ``Authzee`` does not have a relation store.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import RelationTuple
+
+            authzee_app.add_relation(
+                RelationTuple(subject="group:admins#member", relation="viewer", object="folder:reports")
+            )
+            authzee_app.add_relation(
+                RelationTuple(subject="user:alice", relation="member", object="group:admins")
+            )
+            authzee_app.check_relation(subject="user:alice", relation="viewer", object="folder:reports") # True
+
+        """
+        self._verify_relation_store()
+
+        return self._relation_store.check(subject=subject, relation=relation, object=object)
+
+
+    def expand_relation(self, object: str, relation: str) -> Set[str]:
+        """Expand all of the subjects that have a relation to an object, following subject sets like ``"group:admins#member"`` .
+
+        Parameters
+        ----------
+        object : str
+            The object, like ``"folder:reports"`` .
+        relation : str
+            The relation, like ``"viewer"`` .
+
+        Returns
+        -------
+        Set[str]
+            The subjects with the relation to the object, not including subject sets.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            ``Authzee`` does not have a relation store.
+        """
+        self._verify_relation_store()
+
+        return self._relation_store.expand(object=object, relation=relation)
+
+
+    def grant_matches( 
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel],
+        identities: List[BaseModel],
+        grant: Grant
+    ) -> bool:
+        """Verifies a grant, the resources, and identities. Then computes if they match.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        resource_action : ResourceAction
+            Resource Action.
+        parent_resources : List[BaseModel]
+            Parent resource models.
+        child_resources : List[BaseModel]
+            Child resource models.
+        identities : List[BaseModel]
+            Identity models.
+        grant : Grant
+            Grant to match against.
+
+        Returns
+        -------
+        bool
+            ``True`` if the grant matches, or else ``False``.
+        
+        Raises
+        ------
+        authzee.exceptions.GrantEvaluationError
+            The JMESPath search failed and the JMESPath error severity is ``ErrorSeverity.CRITICAL`` .
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import Authzee
+
+        """
+        self._verify_grant(grant=grant)
+        self._verify_auth_args(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+        if ( 
+            type(resource) != grant.resource_type
+            or resource_action not in grant.resource_actions
+        ):
+            return False
+
+        jmespath_data = self._generate_jmespath_data(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+
+        return gc.grant_matches(
+            grant=grant,
+            jmespath_data=jmespath_data,
+            jmespath_options=self._jmespath_options,
+            jmespath_error_severity=self._jmespath_error_severity,
+            max_search_result_size=self._grant_limits.max_search_result_size,
+            rng=self._rng
+        )
+
+    
+    def shadow_authorize(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel],
+        identities: List[BaseModel],
+        shadow_allow_grants: List[Grant],
+        shadow_deny_grants: List[Grant],
+        page_size: Optional[int] = None
+    ) -> bool:
+        """Authorize with the stored grants, and also with a candidate set of shadow grants.
+
+        The decision from the stored grants is returned.
+        If the shadow grants would make a different decision, a warning is logged with the request,
+        so a new policy can be checked against real traffic before it is stored.
+
+        The shadow grants are computed in the main process, regardless of the compute backend.
+        Overrides, grant inheritance and the evaluation budget apply to the shadow grants like the stored grants.
+        Shadow grants of parent resources are inherited, instead of the stored grants of parent resources.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource to authorize for.
+        resource_action : ResourceAction
+            The resource action to authorize for.
+        parent_resources : List[BaseModel]
+            The parent resources of the resource. 
+        child_resources : List[BaseModel]
+            The child resources of the resource.
+        identities : List[BaseModel]
+            The identities of the entity.
+        shadow_allow_grants : List[Grant]
+            The candidate ``GrantEffect.ALLOW`` grants.
+        shadow_deny_grants : List[Grant]
+            The candidate ``GrantEffect.DENY`` grants.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        bool
+            ``True`` if allowed by the stored grants, ``False`` if denied.
+        
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            authorized = authzee_app.shadow_authorize(
+                resource=my_balloon,
+                resource_action=BalloonAction.CreateBalloon,
+                parent_resources=[],
+                child_resources=[],
+                identities=identities,
+                shadow_allow_grants=candidate_allow_grants,
+                shadow_deny_grants=candidate_deny_grants
+            )
+
+        """
+        for grant in shadow_allow_grants + shadow_deny_grants:
+            self._verify_grant(grant=grant)
+
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+
+        authorized = self.authorize(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities,
+            page_size=page_size
+        )
+        try:
+            shadow_authorized, _, _ = self._decide_in_main_process(
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                child_resources=child_resources,
+                identities=identities,
+                grant_indexes={
+                    GrantEffect.ALLOW: GrantIndex(grants=shadow_allow_grants),
+                    GrantEffect.DENY: GrantIndex(grants=shadow_deny_grants)
+                }
+            )
+        except exceptions.EvaluationBudgetExceededError as error:
+            logger.warning("Shadow grants were not evaluated: {}".format(error))
+            return authorized
+
+        if shadow_authorized != authorized:
+            jmespath_data = self._generate_jmespath_data(
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                child_resources=child_resources,
+                identities=identities
+            )
+            logger.warning(
+                "Shadow grants diverged. Stored grants: {}, shadow grants: {}, JMESPath data: {}".format(
+                    authorized,
+                    shadow_authorized,
+                    json.dumps(jmespath_data)
+                )
+            )
+
+        return authorized
+
+
+    def _decide_in_main_process(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel],
+        identities: List[BaseModel],
+        page_size: Optional[int] = None,
+        grant_indexes: Optional[Dict[GrantEffect, GrantIndex]] = None,
+        extra_grant: Optional[Tuple[GrantEffect, Grant]] = None,
+        grant_usage_recorder: Optional[GrantUsageRecorder] = None
+    ) -> Tuple[bool, Optional[bool], Optional[Grant]]:
+        """Decide a verified request in the main process, like ``authorize`` decides with the compute backend.
+
+        Overrides, grant inheritance, the evaluation budget and the ``FailPolicy`` apply like ``authorize`` .
+        Grants are evaluated in decision order: deny grants of the resource, inherited deny grants,
+        then the same for allow grants.
+        ``authorize`` evaluates all of the grants of the resource before the inherited grants,
+        so for a request close to the budget, the two can go over it at different grants.
+        There is no deadline.
+        Anomaly rules are not checked, and grant usage is only recorded with ``grant_usage_recorder`` ,
+        so callers decide which side effects a decision has.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        resource_action : ResourceAction
+            Resource Action.
+        parent_resources : List[BaseModel]
+            Verified and resolved parent resource models.
+        child_resources : List[BaseModel]
+            Verified and resolved child resource models.
+        identities : List[BaseModel]
+            Identity models.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+        grant_indexes : Optional[Dict[GrantEffect, GrantIndex]], optional
+            Grants to decide with, by effect. By default the stored grants are used.
+        extra_grant : Optional[Tuple[GrantEffect, Grant]], optional
+            An effect and a grant to decide with, in addition to the grants.
+        grant_usage_recorder : Optional[GrantUsageRecorder], optional
+            Records the grant that decides the request.
+
+        Returns
+        -------
+        Tuple[bool, Optional[bool], Optional[Grant]]
+            The decision, the decision forced by an override, and the grant that decided the request.
+        """
+        override_decision = self._override_decision(
+            resource=resource,
+            resource_action=resource_action,
+            identities=identities
+        )
+        if override_decision is not None:
+            return override_decision, override_decision, None
+
+        jmespath_data = self._generate_jmespath_data(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+        requests = [(type(resource), resource_action, jmespath_data, False)] + [
+            (parent_type, parent_action, parent_jmespath_data, True)
+            for parent_type, parent_action, parent_jmespath_data in self._inherited_requests(
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                identities=identities
+            )
+        ]
+        budget = self._new_budget(jmespath_data_entries=[jmespath_data])
+        try:
+            # Deny grants of the resource, then inherited deny grants, then the same for allow grants
+            for effect, decision in ((GrantEffect.DENY, False), (GrantEffect.ALLOW, True)):
+                for request_type, request_action, request_jmespath_data, inherited in requests:
+                    for grant in self._request_grants(
+                        effect=effect,
+                        resource_type=request_type,
+                        resource_action=request_action,
+                        page_size=page_size,
+                        grant_indexes=grant_indexes,
+                        extra_grant=extra_grant
+                    ):
+                        if inherited is True and grant.applies_to_descendants is False:
+                            continue
+
+                        spend_budget(budget=budget, grant=grant)
+                        if gc.grant_matches(
+                            grant=grant,
+                            jmespath_data=request_jmespath_data,
+                            jmespath_options=self._jmespath_options,
+                            jmespath_error_severity=self._jmespath_error_severity,
+                            max_search_result_size=self._grant_limits.max_search_result_size,
+                            rng=self._rng
+                        ) is True:
+                            record_grant_usage(recorder=grant_usage_recorder, effect=effect, grant=grant)
+
+                            return decision, None, grant
+        except (exceptions.ComputeBackendError, exceptions.StorageBackendError) as error:
+            return self._failure_decision(resource_action=resource_action, error=error), None, None
+
+        return False, None, None
+
+
+    def _request_grants(
+        self,
+        effect: GrantEffect,
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        page_size: Optional[int],
+        grant_indexes: Optional[Dict[GrantEffect, GrantIndex]],
+        extra_grant: Optional[Tuple[GrantEffect, Grant]]
+    ) -> Generator[Grant, None, None]:
+        if grant_indexes is None:
+            yield from self._list_grants(
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size
+            )
+        else:
+            yield from grant_indexes[effect].grants(resource_type=resource_type, resource_action=resource_action)
+
+        if (
+            extra_grant is not None
+            and extra_grant[0] is effect
+            and extra_grant[1].resource_type == resource_type
+            and resource_action in extra_grant[1].resource_actions
+        ):
+            yield extra_grant[1]
+
+
+    def evaluation_session(self, page_size: Optional[int] = None) -> EvaluationSession:
+        """Start a session that evaluates a burst of requests against a consistent snapshot of the grants.
+
+        All grants are retrieved from the storage backend once, when the session is created.
+        Use it when many requests, like for the same principal during one API call,
+        must be decided with the same grants even if grants change in the meantime.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        EvaluationSession
+            The session.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            session = authzee_app.evaluation_session()
+            for document in documents:
+                if session.authorize(
+                    resource=document,
+                    resource_action=DocumentAction.Read,
+                    parent_resources=[],
+                    child_resources=[],
+                    identities=identities
+                ) is True:
+                    readable.append(document)
+
+        """
+        grants = {
+            effect: list(
+                self._list_grants(
+                    effect=effect,
+                    resource_type=None,
+                    resource_action=None,
+                    page_size=page_size,
+                    consistent=True
+                )
+            )
+            for effect in GrantEffect
+        }
+
+        return EvaluationSession(authzee_app=self, grants=grants, created_at=self._clock.now())
+
+
+    async def evaluation_session_async(self, page_size: Optional[int] = None) -> EvaluationSession:
+        """Start a session that evaluates a burst of requests against a consistent snapshot of the grants.
+
+        All grants are retrieved from the storage backend once, when the session is created.
+        Use it when many requests, like for the same principal during one API call,
+        must be decided with the same grants even if grants change in the meantime.
+
+        Parameters
+        ----------
+        page_size : Optional[int], optional
+            The page size recommendation for the storage backend.
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        EvaluationSession
+            The session.
+
+        Raises
+        ------
+        authzee.exceptions.AsyncNotAvailableError
+            Async is not available for the storage backend.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            session = await authzee_app.evaluation_session_async()
+            decisions = session.authorize_many(requests=requests)
+
+        """
+        grants: Dict[GrantEffect, List[Grant]] = {}
+        for effect in GrantEffect:
+            grants[effect] = [
+                grant
+                async for grant in self._list_grants_async(
+                    effect=effect,
+                    resource_type=None,
+                    resource_action=None,
+                    page_size=page_size,
+                    consistent=True
+                )
+            ]
+
+        return EvaluationSession(authzee_app=self, grants=grants, created_at=self._clock.now())
+
+
+    def _authorize_with_grants(self, request: AuthzRequest, grant_indexes: Dict[GrantEffect, GrantIndex]) -> bool:
+        """Authorize a request in the main process against the given grants, instead of the stored grants.
+
+        Parameters
+        ----------
+        request : AuthzRequest
+            The request.
+        grant_indexes : Dict[GrantEffect, GrantIndex]
+            Grants by effect.
+
+        Returns
+        -------
+        bool
+            ``True`` if authorized, ``False`` if denied.
+        """
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=request.parent_resources,
+            child_resources=request.child_resources,
+            identities=request.identities
+        )
+        decision, override_decision, _ = self._decide_in_main_process(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=request.identities,
+            grant_indexes=grant_indexes,
+            grant_usage_recorder=self._grant_usage_recorder
+        )
+        self._flush_full_grant_usage()
+
+        self._check_anomalies(
+            resources=[request.resource],
+            resource_action=request.resource_action,
+            identities=request.identities,
+            decisions=[decision],
+            override_decisions=[override_decision]
+        )
+
+        return decision
+
+
+    def explain(self, request: AuthzRequest, page_size: Optional[int] = None) -> DecisionExplanation:
+        """Explain why a request is allowed or denied.
+
+        Every grant for the resource type is evaluated, not just until the decision is known,
+        with whether the resource action matched, the JMESPath search result or error,
+        and whether the result matched ``result_match`` .
+
+        The grants are evaluated in the main process, regardless of the compute backend.
+        The decision applies overrides, grant inheritance, the evaluation budget and the ``FailPolicy`` like ``authorize`` ,
+        but does not trigger anomaly rules or grant usage tracking.
+
+        Parameters
+        ----------
+        request : AuthzRequest
+            The request to explain.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        DecisionExplanation
+            The decision and how each grant was evaluated.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.StorageBackendError
+            The grants to explain could not be retrieved from the storage backend.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import AuthzRequest
+
+            explanation = authzee_app.explain(
+                request=AuthzRequest(
+                    resource=my_balloon,
+                    resource_action=BalloonAction.CreateBalloon,
+                    identities=identities
+                )
+            )
+            for grant_explanation in explanation.grants:
+                print(grant_explanation.name, grant_explanation.search_result, grant_explanation.matched)
+
+        """
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=request.parent_resources,
+            child_resources=request.child_resources,
+            identities=request.identities
+        )
+        jmespath_data = self._generate_jmespath_data(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=request.identities
+        )
+        decision, override_decision, deciding_grant = self._decide_in_main_process(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=request.identities,
+            page_size=page_size
+        )
+        grant_explanations: List[GrantExplanation] = []
+        for effect in (GrantEffect.DENY, GrantEffect.ALLOW):
+            for grant in self._list_grants(
+                effect=effect,
+                resource_type=type(request.resource),
+                resource_action=None,
+                page_size=page_size
+            ):
+                action_matched = request.resource_action in grant.resource_actions
+                search_result = None
+                search_error = None
+                matched = False
+                if action_matched is True:
+                    search_result, search_error = gc.search_grant(
+                        grant=grant,
+                        jmespath_data=jmespath_data,
+                        jmespath_options=self._jmespath_options,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._grant_limits.max_search_result_size
+                    )
+                    if search_error is None:
+                        matched = gc.grant_result_matches(grant=grant, result=search_result)
+
+                grant_explanations.append(
+                    GrantExplanation(
+                        effect=effect.value,
+                        name=grant.name,
+                        uuid=grant.uuid,
+                        tags=grant.tags,
+                        action_matched=action_matched,
+                        search_result=search_result,
+                        search_error=search_error,
+                        result_match=grant.result_match,
+                        matched=matched,
+                        canary=grant.canary_sample_rate is not None
+                    )
+                )
+
+        return DecisionExplanation(
+            decision=decision,
+            override_decision=override_decision,
+            deciding_grant_uuid=deciding_grant.uuid if deciding_grant is not None else None,
+            grants=grant_explanations
+        )
+
+
+    def capture_capsule(
+        self,
+        request: AuthzRequest,
+        redact: Optional[List[str]] = None,
+        page_size: Optional[int] = None
+    ) -> PolicyCapsule:
+        """Capture a capsule with everything needed to reproduce the decision for a request.
+
+        The capsule holds the request JMESPath data, every grant evaluated for the request,
+        whether each grant matched, the registered definitions and the ``AuthzeeConfig`` .
+        Use ``PolicyCapsule.to_archive()`` to package it for a bug report.
+
+        The grants are evaluated in the main process, regardless of the compute backend.
+        The decision applies overrides, grant inheritance, the evaluation budget and the ``FailPolicy`` like ``authorize`` ,
+        but does not trigger anomaly rules or grant usage tracking.
+        The capsule holds the grants of the resource type, not inherited grants of parent resources.
+
+        Parameters
+        ----------
+        request : AuthzRequest
+            The request to capture.
+        redact : Optional[List[str]], optional
+            Names of request data fields to replace with ``"[REDACTED]"`` , at any depth.
+            Grants are evaluated before redaction.
+            Fields in ``ResourceAuthz.hidden_resource_fields`` are always left out.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        PolicyCapsule
+            The captured capsule.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.StorageBackendError
+            The grants to capture could not be retrieved from the storage backend.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import AuthzRequest
+
+            capsule = authzee_app.capture_capsule(
+                request=AuthzRequest(
+                    resource=my_balloon,
+                    resource_action=BalloonAction.CreateBalloon,
+                    identities=identities
+                ),
+                redact=["email"]
+            )
+            with open("capsule.zip", "wb") as capsule_file:
+                capsule_file.write(capsule.to_archive())
+
+        """
+        parent_resources, child_resources = self._verify_and_resolve_auth_args(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=request.parent_resources,
+            child_resources=request.child_resources,
+            identities=request.identities
+        )
+        jmespath_data = self._generate_jmespath_data(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=request.identities
+        )
+        decision, override_decision, _ = self._decide_in_main_process(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=request.identities,
+            page_size=page_size
+        )
+        grants: Dict[str, List[Dict[str, Any]]] = {}
+        trace: List[Dict[str, Any]] = []
+        for effect in (GrantEffect.DENY, GrantEffect.ALLOW):
+            grants[effect.value] = []
+            for grant in self._list_grants(
+                effect=effect,
+                resource_type=type(request.resource),
+                resource_action=request.resource_action,
+                page_size=page_size
+            ):
+                matched = gc.grant_matches(
+                    grant=grant,
+                    jmespath_data=jmespath_data,
+                    jmespath_options=self._jmespath_options,
+                    jmespath_error_severity=self._jmespath_error_severity,
+                    max_search_result_size=self._grant_limits.max_search_result_size,
+                    rng=self._rng
+                )
+                grants[effect.value].append(grant_capsule_data(grant=grant))
+                trace.append({
+                    "effect": effect.value,
+                    "name": grant.name,
+                    "uuid": grant.uuid,
+                    "matched": matched
+                })
+
+        return PolicyCapsule(
+            created_at=self._clock.now(),
+            request=redact_fields(data=jmespath_data, fields=set(redact if redact is not None else [])),
+            decision=decision,
+            override_decision=override_decision,
+            grants=grants,
+            trace=trace,
+            definitions=self._capsule_definitions(),
+            config=json.loads(self._config.json())
+        )
+
+
+    def _capsule_definitions(self) -> Dict[str, Any]:
+        return {
+            "identity_types": {
+                identity_type.__name__: {
+                    "schema": identity_type.model_json_schema(),
+                    "min_count": min_count,
+                    "max_count": max_count
+                }
+                for identity_type, (min_count, max_count) in self._identity_cardinalities.items()
+            },
+            "resource_authzs": {
+                type(authz).__name__: {
+                    "resource_type": authz.resource_type.__name__,
+                    "schema": authz.resource_type.model_json_schema(),
+                    "resource_actions": sorted(str(action) for action in authz.resource_action_type),
+                    "parent_authz_names": sorted(authz.parent_authz_names),
+                    "child_authz_names": sorted(authz.child_authz_names),
+                    "hidden_resource_fields": sorted(authz.hidden_resource_fields)
+                }
+                for authz in self._authzs
+            }
+        }
+
+
+    def validate_grant_against_corpus(
+        self,
+        effect: GrantEffect,
+        grant: Grant,
+        requests: List[AuthzRequest],
+        page_size: Optional[int] = None
+    ) -> GrantImpact:
+        """Find the decisions a new grant would change for a corpus of requests, without adding the grant.
+
+        A ``GrantEffect.DENY`` grant flips requests that are currently allowed and that it matches.
+        A ``GrantEffect.ALLOW`` grant flips requests that are currently denied, that it matches,
+        and that do not match any stored ``GrantEffect.DENY`` grants.
+
+        Each request is decided with and without the grant in the main process, regardless of the compute backend.
+        Overrides, grant inheritance, the evaluation budget and the ``FailPolicy`` apply like ``authorize`` ,
+        but validating has no side effects: anomaly rules are not checked and grant usage is not recorded.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            Effect of the new grant.
+        grant : Grant
+            The new grant.
+        requests : List[AuthzRequest]
+            Historical requests to check the grant against.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        GrantImpact
+            The indexes of the requests that the grant would flip.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            impact = authzee_app.validate_grant_against_corpus(
+                effect=GrantEffect.DENY,
+                grant=new_grant,
+                requests=[
+                    AuthzRequest(
+                        resource=my_balloon,
+                        resource_action=BalloonAction.CreateBalloon,
+                        identities=identities
+                    )
+                ]
+            )
+            print(len(impact.allow_to_deny))
+
+        """
+        self._verify_grant_effect(effect=effect)
+        self._verify_grant(grant=grant)
+        allow_to_deny: List[int] = []
+        deny_to_allow: List[int] = []
+        for i, request in enumerate(requests):
+            parent_resources, child_resources = self._verify_and_resolve_auth_args(
+                resource=request.resource,
+                resource_action=request.resource_action,
+                parent_resources=request.parent_resources,
+                child_resources=request.child_resources,
+                identities=request.identities
+            )
+            request_kwargs = {
+                "resource": request.resource,
+                "resource_action": request.resource_action,
+                "parent_resources": parent_resources,
+                "child_resources": child_resources,
+                "identities": request.identities,
+                "page_size": page_size
+            }
+            authorized, _, _ = self._decide_in_main_process(**request_kwargs)
+            authorized_with_grant, _, _ = self._decide_in_main_process(extra_grant=(effect, grant), **request_kwargs)
+            if authorized is True and authorized_with_grant is False:
+                allow_to_deny.append(i)
+
+            elif authorized is False and authorized_with_grant is True:
+                deny_to_allow.append(i)
+
+        return GrantImpact(
+            total_requests=len(requests),
+            allow_to_deny=allow_to_deny,
+            deny_to_allow=deny_to_allow
+        )
+
+
+    def run_corpus(self, cases: List[CorpusCase], page_size: Optional[int] = None) -> CorpusReport:
+        """Authorize every case of a decision corpus and compare the decisions with the expected decisions.
+
+        Run a corpus in CI to catch policy regressions, and to benchmark the authorization latency.
+        The CLI runs a corpus file with ``authzee run-corpus`` or ``python -m authzee run-corpus`` .
+
+        Cases are decided in the main process, regardless of the compute backend.
+        Overrides, grant inheritance, the evaluation budget and the ``FailPolicy`` apply like ``authorize`` ,
+        but running a corpus has no side effects: anomaly rules are not checked and grant usage is not recorded.
+
+        Parameters
+        ----------
+        cases : List[CorpusCase]
+            The cases to run.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+
+        Returns
+        -------
+        CorpusReport
+            Pass and fail results and latency stats.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs of a case were not verified with the ``Authzee`` configuration.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            with open("corpus.json") as corpus_file:
+                cases = authzee_app.corpus_from_json(corpus_json=corpus_file.read())
+
+            report = authzee_app.run_corpus(cases=cases)
+            assert report.all_passed, report.failed_cases
+
+        """
+        decisions: List[bool] = []
+        failed_cases: List[int] = []
+        latencies: List[float] = []
+        for i, case in enumerate(cases):
+            start = self._clock.time()
+            parent_resources, child_resources = self._verify_and_resolve_auth_args(
+                resource=case.request.resource,
+                resource_action=case.request.resource_action,
+                parent_resources=case.request.parent_resources,
+                child_resources=case.request.child_resources,
+                identities=case.request.identities
+            )
+            decision, _, _ = self._decide_in_main_process(
+                resource=case.request.resource,
+                resource_action=case.request.resource_action,
+                parent_resources=parent_resources,
+                child_resources=child_resources,
+                identities=case.request.identities,
+                page_size=page_size
+            )
+            latencies.append(self._clock.time() - start)
+            decisions.append(decision)
+            if decision is not case.expected_decision:
+                failed_cases.append(i)
+
+        return CorpusReport(
+            total_cases=len(cases),
+            passed=len(cases) - len(failed_cases),
+            failed_cases=failed_cases,
+            decisions=decisions,
+            mean_latency=sum(latencies) / len(latencies) if len(latencies) > 0 else 0.0,
+            p50_latency=latency_percentile(latencies=latencies, percent=50),
+            p95_latency=latency_percentile(latencies=latencies, percent=95),
+            max_latency=max(latencies, default=0.0)
+        )
+
+
+    def corpus_to_json(self, cases: List[CorpusCase]) -> str:
+        """Export a decision corpus as JSON.
+
+        Models are stored with the name of their registered type, like ``{"type": "Balloon", "data": {...}}`` ,
+        and resource actions as strings, like ``"BalloonAction.CreateBalloon"`` .
+
+        Parameters
+        ----------
+        cases : List[CorpusCase]
+            The cases to export.
+
+        Returns
+        -------
+        str
+            The corpus as JSON.
+        """
+        def model_data(model: BaseModel) -> Dict[str, Any]:
+            return {"type": type(model).__name__, "data": json.loads(model.json())}
+
+        return json.dumps(
+            {
+                "cases": [
+                    {
+                        "name": case.name,
+                        "expected_decision": case.expected_decision,
+                        "request": {
+                            "resource": model_data(case.request.resource),
+                            "resource_action": str(case.request.resource_action),
+                            "parent_resources": [model_data(parent) for parent in case.request.parent_resources],
+                            "child_resources": [model_data(child) for child in case.request.child_resources],
+                            "identities": [model_data(identity) for identity in case.request.identities]
+                        }
+                    }
+                    for case in cases
+                ]
+            },
+            indent=4
+        )
+
+
+    def corpus_from_json(self, corpus_json: str) -> List[CorpusCase]:
+        """Import a decision corpus from JSON, in the format of ``corpus_to_json()`` .
+
+        Parameters
+        ----------
+        corpus_json : str
+            The corpus as JSON.
+
+        Returns
+        -------
+        List[CorpusCase]
+            The cases of the corpus.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            A model type or resource action is not registered with ``Authzee`` .
+        """
+        model_types = {
+            model_type.__name__: model_type
+            for model_type in self._identity_types | self._resource_types
+        }
+        resource_actions = {
+            str(resource_action): resource_action
+            for resource_action_type in self._resource_action_types
+            for resource_action in resource_action_type
+        }
+
+        def load_model(model_data: Dict[str, Any]) -> BaseModel:
+            if model_data['type'] not in model_types:
+                raise exceptions.InputVerificationError(
+                    "Model type '{}' is not registered with Authzee.".format(model_data['type'])
+                )
+
+            return model_types[model_data['type']](**model_data['data'])
+
+        cases: List[CorpusCase] = []
+        for case_data in json.loads(corpus_json)['cases']:
+            request_data = case_data['request']
+            if request_data['resource_action'] not in resource_actions:
+                raise exceptions.InputVerificationError(
+                    "Resource action '{}' is not registered with Authzee.".format(request_data['resource_action'])
+                )
+
+            cases.append(
+                CorpusCase(
+                    name=case_data.get("name"),
+                    expected_decision=case_data['expected_decision'],
+                    request=AuthzRequest(
+                        resource=load_model(request_data['resource']),
+                        resource_action=resource_actions[request_data['resource_action']],
+                        parent_resources=[load_model(parent) for parent in request_data.get("parent_resources", [])],
+                        child_resources=[load_model(child) for child in request_data.get("child_resources", [])],
+                        identities=[load_model(identity) for identity in request_data.get("identities", [])]
+                    )
+                )
+            )
+
+        return cases
+
+    
+    def _resolve_relationships(
+        self,
+        resource: BaseModel,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel]
+    ) -> Tuple[List[BaseModel], List[BaseModel]]:
+        """Add the parent and child resources from the ``RelationshipResolver`` to the given ones.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        parent_resources : List[BaseModel]
+            Given parent resource models.
+        child_resources : List[BaseModel]
+            Given child resource models.
+
+        Returns
+        -------
+        Tuple[List[BaseModel], List[BaseModel]]
+            The parent and child resources, without duplicates.
+        """
+        if self._relationship_resolver is None:
+            return parent_resources, child_resources
+
+        resolved_parents = [
+            parent_resource for parent_resource in self._relationship_resolver.get_parent_resources(resource)
+            if parent_resource not in parent_resources
+        ]
+        resolved_children = [
+            child_resource for child_resource in self._relationship_resolver.get_child_resources(resource)
+            if child_resource not in child_resources
+        ]
+
+        return parent_resources + resolved_parents, child_resources + resolved_children
+
+
+    def _verify_and_resolve_auth_args(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel], 
+        child_resources: List[BaseModel],
+        identities: List[BaseModel]
+    ) -> Tuple[List[BaseModel], List[BaseModel]]:
+        """Verify the authorization args, then add and verify the resources from the ``RelationshipResolver`` .
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model to verify.
+        resource_action : ResourceAction
+            Resource Action to verify.
+        parent_resources : List[BaseModel]
+            Given parent resource models to verify.
+        child_resources : List[BaseModel]
+            Given child resource models to verify.
+        identities : List[BaseModel]
+            Identity models to verify.
+
+        Returns
+        -------
+        Tuple[List[BaseModel], List[BaseModel]]
+            The verified parent and child resources, with the resolved resources.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs or resolved resources were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.RequestLimitError
+            The inputs or resolved resources are over the ``RequestLimits`` .
+        """
+        self._verify_auth_args(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+        if self._relationship_resolver is None:
+            return parent_resources, child_resources
+
+        parent_resources, child_resources = self._resolve_relationships(
+            resource=resource,
+            parent_resources=parent_resources,
+            child_resources=child_resources
+        )
+        self._verify_auth_args(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+
+        return parent_resources, child_resources
+
+
+    def _verify_and_resolve_auth_many_args(
+        self,
+        resources: List[BaseModel],
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel], 
+        child_resources: List[BaseModel],
+        identities: List[BaseModel],
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        per_resource_children: Optional[List[List[BaseModel]]] = None
+    ) -> Tuple[Optional[List[List[BaseModel]]], Optional[List[List[BaseModel]]]]:
+        """Verify the authorization args for many resource calls, 
+        then add and verify the resources from the ``RelationshipResolver`` for each resource.
+
+        Parameters
+        ----------
+        resources : List[BaseModel]
+            Resource models to verify.
+        resource_action : ResourceAction
+            Resource Action to verify.
+        parent_resources : List[BaseModel]
+            Shared parent resource models to verify.
+        child_resources : List[BaseModel]
+            Shared child resource models to verify.
+        identities : List[BaseModel]
+            Identity models to verify.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Parent resource models for each resource to verify.
+        per_resource_children : Optional[List[List[BaseModel]]], optional
+            Child resource models for each resource to verify.
+
+        Returns
+        -------
+        Tuple[Optional[List[List[BaseModel]]], Optional[List[List[BaseModel]]]]
+            The verified parent and child resources for each resource, with the resolved resources.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs or resolved resources were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.RequestLimitError
+            The inputs or resolved resources are over the ``RequestLimits`` .
+        """
+        self._verify_auth_many_args(
+            resources=resources,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities,
+            per_resource_parents=per_resource_parents,
+            per_resource_children=per_resource_children
+        )
+        if self._relationship_resolver is None:
+            return per_resource_parents, per_resource_children
+
+        resolved_parents: List[List[BaseModel]] = []
+        resolved_children: List[List[BaseModel]] = []
+        for i, resource in enumerate(resources):
+            resource_parents, resource_children = self._resolve_relationships(
+                resource=resource,
+                parent_resources=parent_resources + (per_resource_parents[i] if per_resource_parents is not None else []),
+                child_resources=child_resources + (per_resource_children[i] if per_resource_children is not None else [])
+            )
+            resolved_parents.append(resource_parents[len(parent_resources):])
+            resolved_children.append(resource_children[len(child_resources):])
+
+        self._verify_auth_many_args(
+            resources=resources,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities,
+            per_resource_parents=resolved_parents,
+            per_resource_children=resolved_children
+        )
+
+        return resolved_parents, resolved_children
+
+
+    def _failure_decision(
+        self,
+        resource_action: ResourceAction,
+        error: Union[exceptions.ComputeBackendError, exceptions.StorageBackendError]
+    ) -> bool:
+        """Decide an authorization request that failed in the storage or compute backend with the ``FailPolicy`` .
+
+        Parameters
+        ----------
+        resource_action : ResourceAction
+            Resource action of the request.
+        error : Union[exceptions.ComputeBackendError, exceptions.StorageBackendError]
+            The backend error.
+
+        Returns
+        -------
+        bool
+            ``True`` if authorized, ``False`` if denied.
+
+        Raises
+        ------
+        authzee.exceptions.ComputeBackendError
+            The compute backend error, when the ``FailPolicy.compute_failure`` is ``FailureDecision.RAISE`` .
+        authzee.exceptions.StorageBackendError
+            The storage backend error, when the ``FailPolicy.storage_failure`` is ``FailureDecision.RAISE`` .
+        """
+        if isinstance(error, exceptions.ComputeBackendError):
+            failure = "compute"
+            failure_decision = self._fail_policy.compute_failure
+        else:
+            failure = "storage"
+            failure_decision = self._fail_policy.storage_failure
+
+        if failure_decision is FailureDecision.RAISE:
+            raise error
+
+        authorized = (
+            failure_decision is FailureDecision.ALLOW_LOW_RISK
+            and str(resource_action) in self._fail_policy.low_risk_actions
+        )
+        logger.warning(
+            "The {} backend failed for '{}', so the request was {} by the fail policy: {!r}".format(
+                failure,
+                resource_action,
+                "allowed" if authorized is True else "denied",
+                error
+            )
+        )
+
+        return authorized
+
+
+    def _check_anomalies(
+        self,
+        resources: List[BaseModel],
+        resource_action: ResourceAction,
+        identities: List[BaseModel],
+        decisions: List[bool],
+        override_decisions: List[Union[bool, None]]
+    ) -> None:
+        """Check decisions with the anomaly rules, and call the anomaly hooks for each anomaly.
+
+        Errors raised by hooks are logged and do not change the decisions.
+
+        Parameters
+        ----------
+        resources : List[BaseModel]
+            Resources of the decisions.
+        resource_action : ResourceAction
+            Resource action of the request.
+        identities : List[BaseModel]
+            Identities of the request.
+        decisions : List[bool]
+            Decision for each resource.
+        override_decisions : List[Union[bool, None]]
+            Override decision for each resource, or ``None`` if it was not overridden.
+        """
+        if len(self._anomaly_rules) == 0:
+            return
+
+        identities_by_type = {identity_name: [] for identity_name in self._identity_type_names}
+        for identity in identities:
+            identities_by_type[type(identity).__name__].append(json.loads(identity.json()))
+
+        timestamp = self._clock.now()
+        for resource, decision, override_decision in zip(resources, decisions, override_decisions):
+            event = DecisionEvent(
+                resource_type=type(resource).__name__,
+                resource=self._canonical_resource_data(resource=resource),
+                resource_action=str(resource_action),
+                identities=identities_by_type,
+                allowed=decision,
+                overridden=override_decision is not None,
+                timestamp=timestamp
+            )
+            for rule in self._anomaly_rules:
+                reason = rule.check(event=event)
+                if reason is None:
+                    continue
+
+                anomaly = Anomaly(rule=type(rule).__name__, reason=reason, event=event)
+                for hook in self._anomaly_hooks:
+                    try:
+                        hook(anomaly)
+                    except Exception as error:
+                        logger.warning("Anomaly hook {} failed: {!r}".format(hook, error))
+
+
+    def _flush_full_grant_usage(self) -> None:
+        """Write a full batch of grant usage after a decision.
+
+        Storage errors are logged, so recording grant usage never changes a decision.
+        """
+        if self._grant_usage_recorder is not None:
+            self._grant_usage_recorder.flush_full()
+
+
+    async def _flush_full_grant_usage_async(self) -> None:
+        """Write a full batch of grant usage after a decision.
+
+        Storage errors are logged, so recording grant usage never changes a decision.
+        """
+        if self._grant_usage_recorder is not None:
+            await self._grant_usage_recorder.flush_full_async()
+
+
+    def _new_budget(self, jmespath_data_entries: List[Dict[str, Any]]) -> Optional[EvaluationBudget]:
+        """Create the evaluation budget for an authorization call.
+
+        The data size of the budget is the size of the largest JMESPath data entry as JSON.
+
+        Parameters
+        ----------
+        jmespath_data_entries : List[Dict[str, Any]]
+            JMESPath data entries of the call.
+
+        Returns
+        -------
+        Optional[EvaluationBudget]
+            The budget, or ``None`` if ``RequestLimits.max_evaluation_cost`` is not set.
+        """
+        if self._request_limits.max_evaluation_cost is None:
+            return None
+
+        return EvaluationBudget(
+            max_cost=self._request_limits.max_evaluation_cost,
+            data_size=max(len(json.dumps(jmespath_data)) for jmespath_data in jmespath_data_entries)
+        )
+
+
+    def _override_decision(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        identities: List[BaseModel]
+    ) -> Union[bool, None]:
+        """Get the decision forced by overrides.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        resource_action : ResourceAction
+            Resource Action.
+        identities : List[BaseModel]
+            Identity models.
+
+        Returns
+        -------
+        Union[bool, None]
+            The forced decision, or ``None`` if no override applies.
+        """
+        if len(self._overrides) == 0:
+            return None
+
+        resource_data = None
+        matching_overrides: List[DecisionOverride] = []
+        for override in self._overrides.values():
+            if (
+                override.resource_type != type(resource)
+                or resource_action not in override.resource_actions
+                or override.identity not in identities
+                or override.is_expired(now=self._clock.now()) is True
+            ):
+                continue
+
+            if resource_data is None:
+                resource_data = self._canonical_resource_data(resource=resource)
+
+            if all(
+                resource_data.get(field_name) == value 
+                for field_name, value in override.resource_match.items()
+            ):
+                matching_overrides.append(override)
+
+        if len(matching_overrides) == 0:
+            return None
+
+        override = next(
+            (override for override in matching_overrides if override.effect is GrantEffect.DENY),
+            matching_overrides[0]
+        )
+        logger.warning(
+            "Decision override '{}' forced {} for action '{}': {}".format(
+                override.uuid,
+                override.effect.value,
+                resource_action,
+                override.reason
+            )
+        )
+
+        return override.effect is GrantEffect.ALLOW
+
+
+    def _inherited_decision(
+        self,
+        decision: bool,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        identities: List[BaseModel],
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int],
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> bool:
+        """Apply the grants of parent resources that apply to descendants.
+
+        A matching inherited deny grant denies the request.
+        A matching inherited allow grant allows the request, unless a deny grant of the resource matches.
+
+        Parameters
+        ----------
+        decision : bool
+            The decision from the grants of the resource.
+        resource : BaseModel
+            Resource model.
+        resource_action : ResourceAction
+            Resource Action.
+        parent_resources : List[BaseModel]
+            Parent resource models.
+        identities : List[BaseModel]
+            Identity models.
+        jmespath_data : Dict[str, Any]
+            JMESPath data of the request.
+        page_size : Optional[int]
+            The page size to use for the storage backend.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget of the call, that each evaluated inherited grant is spent from.
+        deadline : Optional[Deadline], optional
+            Deadline of the call, that is checked after each page of grants and between grants.
+
+        Returns
+        -------
+        bool
+            ``True`` if allowed, ``False`` if denied.
+
+        Raises
+        ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The budget was spent before a decision was made.
+        """
+        inherited_requests = self._inherited_requests(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            identities=identities
+        )
+        if len(inherited_requests) == 0:
+            return decision
+
+        if self._inherited_grant_matches(
+            effect=GrantEffect.DENY,
+            inherited_requests=inherited_requests,
+            page_size=page_size,
+            budget=budget,
+            deadline=deadline
+        ) is True:
+            return False
+
+        if decision is True:
+            return True
+
+        if self._inherited_grant_matches(
+            effect=GrantEffect.ALLOW,
+            inherited_requests=inherited_requests,
+            page_size=page_size,
+            budget=budget,
+            deadline=deadline
+        ) is False:
+            return False
+
+        # The decision may be a deny from the resource grants, which an inherited allow does not override.
+        # The compute backend already spent these deny grants from the budget.
+        for _ in self._list_matching_grants(
+            effect=GrantEffect.DENY,
+            resource_type=type(resource),
+            resource_action=resource_action,
+            jmespath_data=jmespath_data,
+            page_size=page_size,
+            sort_by=None,
+            descending=False,
+            deadline=deadline
+        ):
+            return False
+
+        return True
+
+
+    async def _inherited_decision_async(
+        self,
+        decision: bool,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        identities: List[BaseModel],
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int],
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> bool:
+        inherited_requests = self._inherited_requests(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            identities=identities
+        )
+        if len(inherited_requests) == 0:
+            return decision
+
+        if await self._inherited_grant_matches_async(
+            effect=GrantEffect.DENY,
+            inherited_requests=inherited_requests,
+            page_size=page_size,
+            budget=budget,
+            deadline=deadline
+        ) is True:
+            return False
+
+        if decision is True:
+            return True
+
+        if await self._inherited_grant_matches_async(
+            effect=GrantEffect.ALLOW,
+            inherited_requests=inherited_requests,
+            page_size=page_size,
+            budget=budget,
+            deadline=deadline
+        ) is False:
+            return False
+
+        async for _ in self._list_matching_grants_async(
+            effect=GrantEffect.DENY,
+            resource_type=type(resource),
+            resource_action=resource_action,
+            jmespath_data=jmespath_data,
+            page_size=page_size,
+            sort_by=None,
+            descending=False,
+            deadline=deadline
+        ):
+            return False
+
+        return True
+
+
+    def _inherited_decisions(
+        self,
+        decisions: List[bool],
+        resources: List[BaseModel],
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        identities: List[BaseModel],
+        jmespath_data_entries: List[Dict[str, Any]],
+        page_size: Optional[int],
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> List[bool]:
+        """Apply the grants of parent resources that apply to descendants to each resource of ``authorize_many`` .
+
+        Parameters
+        ----------
+        decisions : List[bool]
+            The decisions from the grants of the resources.
+        resources : List[BaseModel]
+            Resource models.
+        resource_action : ResourceAction
+            Resource Action.
+        parent_resources : List[BaseModel]
+            Parent resource models shared by all resources.
+        identities : List[BaseModel]
+            Identity models.
+        jmespath_data_entries : List[Dict[str, Any]]
+            JMESPath data of the request for each resource.
+        page_size : Optional[int]
+            The page size to use for the storage backend.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Additional parent resource models for each resource.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget of the call, that each evaluated inherited grant is spent from.
+        deadline : Optional[Deadline], optional
+            Deadline of the call, that is checked after each page of grants and between grants.
+
+        Returns
+        -------
+        List[bool]
+            ``True`` if allowed, ``False`` if denied, for each resource.
+
+        Raises
+        ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
+        authzee.exceptions.EvaluationBudgetExceededError
+            The budget was spent before a decision was made.
+        """
+        if self._config.grant_inheritance is False:
+            return decisions
+
+        return [
+            self._inherited_decision(
+                decision=decision,
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources + (per_resource_parents[i] if per_resource_parents is not None else []),
+                identities=identities,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                budget=budget,
+                deadline=deadline
+            )
+            for i, (decision, resource, jmespath_data) in enumerate(zip(decisions, resources, jmespath_data_entries))
+        ]
+
+
+    async def _inherited_decisions_async(
+        self,
+        decisions: List[bool],
+        resources: List[BaseModel],
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        identities: List[BaseModel],
+        jmespath_data_entries: List[Dict[str, Any]],
+        page_size: Optional[int],
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> List[bool]:
+        if self._config.grant_inheritance is False:
+            return decisions
+
+        return [
+            await self._inherited_decision_async(
+                decision=decision,
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources + (per_resource_parents[i] if per_resource_parents is not None else []),
+                identities=identities,
+                jmespath_data=jmespath_data,
+                page_size=page_size,
+                budget=budget,
+                deadline=deadline
+            )
+            for i, (decision, resource, jmespath_data) in enumerate(zip(decisions, resources, jmespath_data_entries))
+        ]
+
+
+    def _inherited_requests(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        identities: List[BaseModel]
+    ) -> List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]]:
+        """Get the parent resource requests whose descendant grants apply to a request.
+
+        Each parent resource is the resource of its request, with the requested resource as the child resource.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        resource_action : ResourceAction
             Resource Action.
         parent_resources : List[BaseModel]
             Parent resource models.
-        child_resources : List[BaseModel]
-            Child resource models.
         identities : List[BaseModel]
             Identity models.
-        grant : Grant
-            Grant to match against.
 
         Returns
         -------
-        bool
-            ``True`` if the grant matches, or else ``False``.
-        
-        Raises
-        ------
-        authzee.exceptions.InputVerificationError
-            The inputs were not verified with the ``Authzee`` configuration.
+        List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]]
+            The resource type, resource action and JMESPath data of each parent request.
+        """
+        if self._config.grant_inheritance is False:
+            return []
 
-        Examples
-        --------
-        .. code-block:: python
+        inherited_values = self._resource_to_authz_lookup[type(resource)].inherited_actions.get(
+            resource_action.value,
+            set()
+        )
+        inherited_requests: List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]] = []
+        for parent_resource in parent_resources:
+            parent_type = type(parent_resource)
+            for parent_action in self._resource_to_authz_lookup[parent_type].resource_action_type:
+                if parent_action.value in inherited_values:
+                    inherited_requests.append(
+                        (
+                            parent_type,
+                            parent_action,
+                            self._generate_jmespath_data(
+                                resource=parent_resource,
+                                resource_action=parent_action,
+                                parent_resources=[],
+                                child_resources=[resource],
+                                identities=identities
+                            )
+                        )
+                    )
 
-            from authzee import Authzee
+        return inherited_requests
+
+
+    def _inherited_grant_matches(
+        self,
+        effect: GrantEffect,
+        inherited_requests: List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]],
+        page_size: Optional[int],
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> bool:
+        """Check if a grant of a parent request that applies to descendants matches.
+
+        Each evaluated grant is spent from the ``budget`` , and the ``deadline`` is checked between grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            Effect of the grants.
+        inherited_requests : List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]]
+            The parent requests from ``_inherited_requests`` .
+        page_size : Optional[int]
+            The page size to use for the storage backend.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget of the call.
+        deadline : Optional[Deadline], optional
+            Deadline of the call.
 
+        Returns
+        -------
+        bool
+            ``True`` if a grant matches.
         """
-        self._verify_grant(grant=grant)
-        self._verify_auth_args(
-            resource=resource,
-            resource_action=resource_action,
-            parent_resources=parent_resources,
-            child_resources=child_resources,
-            identities=identities
-        )
-        if ( 
-            type(resource) != grant.resource_type
-            or resource_action not in grant.resource_actions
-        ):
+        for parent_type, parent_action, parent_jmespath_data in inherited_requests:
+            for grant in self._list_grants(
+                effect=effect,
+                resource_type=parent_type,
+                resource_action=parent_action,
+                page_size=page_size,
+                deadline=deadline
+            ):
+                if self._inherited_grant_match(
+                    grant=grant,
+                    jmespath_data=parent_jmespath_data,
+                    budget=budget,
+                    deadline=deadline
+                ) is True:
+                    return True
+
+        return False
+
+
+    async def _inherited_grant_matches_async(
+        self,
+        effect: GrantEffect,
+        inherited_requests: List[Tuple[Type[BaseModel], ResourceAction, Dict[str, Any]]],
+        page_size: Optional[int],
+        budget: Optional[EvaluationBudget] = None,
+        deadline: Optional[Deadline] = None
+    ) -> bool:
+        for parent_type, parent_action, parent_jmespath_data in inherited_requests:
+            async for grant in self._list_grants_async(
+                effect=effect,
+                resource_type=parent_type,
+                resource_action=parent_action,
+                page_size=page_size,
+                deadline=deadline
+            ):
+                if self._inherited_grant_match(
+                    grant=grant,
+                    jmespath_data=parent_jmespath_data,
+                    budget=budget,
+                    deadline=deadline
+                ) is True:
+                    return True
+
+        return False
+
+
+    def _inherited_grant_match(
+        self,
+        grant: Grant,
+        jmespath_data: Dict[str, Any],
+        budget: Optional[EvaluationBudget],
+        deadline: Optional[Deadline]
+    ) -> bool:
+        if grant.applies_to_descendants is False:
             return False
 
-        jmespath_data = self._generate_jmespath_data(
-            resource=resource,
-            resource_action=resource_action,
-            parent_resources=parent_resources,
-            child_resources=child_resources,
-            identities=identities
-        )
+        check_deadline(deadline=deadline, phase="evaluation")
+        spend_budget(budget=budget, grant=grant)
 
         return gc.grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=self._jmespath_options
+            jmespath_options=self._jmespath_options,
+            jmespath_error_severity=self._jmespath_error_severity,
+            max_search_result_size=self._grant_limits.max_search_result_size,
+            rng=self._rng
         )
 
-    
+
     def _generate_jmespath_data(
         self,
         resource: BaseModel,
@@ -1516,7 +5088,7 @@ class Authzee:
         parent_resources_by_type = {parent_type.__name__: [] for parent_type in self._resource_to_authz_lookup[resource_type]._parent_resource_types}
         for parent_resource in parent_resources:
             parent_type = type(parent_resource)
-            parent_resources_by_type[parent_type.__name__].append(json.loads(parent_resource.json()))
+            parent_resources_by_type[parent_type.__name__].append(self._canonical_resource_data(resource=parent_resource))
         
         child_resources_by_type = {child_type.__name__: [] for child_type in self._resource_to_authz_lookup[resource_type]._child_resource_types}
         for child_resource in child_resources:
@@ -1524,7 +5096,7 @@ class Authzee:
             if child_type.__name__ not in child_resources_by_type:
                 child_resources_by_type[child_type.__name__] = []
             
-            child_resources_by_type[child_type.__name__].append(json.loads(child_resource.json()))
+            child_resources_by_type[child_type.__name__].append(self._canonical_resource_data(resource=child_resource))
 
         identities_by_type = {identity_name: [] for identity_name in self._identity_type_names}
         for identity in identities:
@@ -1533,11 +5105,17 @@ class Authzee:
         
         jmespath_data = {
             "identities": identities_by_type,
-            "resource": json.loads(resource.json()),
+            "resource": self._canonical_resource_data(resource=resource),
             "resource_type": type(resource).__name__,
             "resource_action": str(resource_action),
             "parent_resources": parent_resources_by_type,
-            "child_resources": child_resources_by_type
+            "child_resources": child_resources_by_type,
+            "relations": self._relation_data(
+                resource=resource,
+                parent_resources=parent_resources,
+                child_resources=child_resources
+            ),
+            "risk_score": self._risk_score(identities=identities)
         }
 
         return jmespath_data
@@ -1550,6 +5128,8 @@ class Authzee:
         parent_resources: List[BaseModel],
         child_resources: List[BaseModel],
         identities: List[BaseModel],
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        per_resource_children: Optional[List[List[BaseModel]]] = None
     ) -> List[Dict[str, Any]]:
         """Generate JMESPath data.
 
@@ -1560,11 +5140,15 @@ class Authzee:
         resource_action : ResourceAction
             Resource Action.
         parent_resources : List[BaseModel]
-            Parent resource models.
+            Parent resource models shared by all resources.
         child_resources : List[BaseModel]
-            Child resource models.
+            Child resource models shared by all resources.
         identities : List[BaseModel]
             Identity models.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Additional parent resource models for each resource.
+        per_resource_children : Optional[List[List[BaseModel]]], optional
+            Additional child resource models for each resource.
 
         Returns
         -------
@@ -1575,7 +5159,7 @@ class Authzee:
         parent_resources_by_type = {parent_type.__name__: [] for parent_type in self._resource_to_authz_lookup[resource_type]._parent_resource_types}
         for parent_resource in parent_resources:
             parent_type = type(parent_resource)
-            parent_resources_by_type[parent_type.__name__].append(json.loads(parent_resource.json()))
+            parent_resources_by_type[parent_type.__name__].append(self._canonical_resource_data(resource=parent_resource))
         
         child_resources_by_type = {child_type.__name__: [] for child_type in self._resource_to_authz_lookup[resource_type]._child_resource_types}
         for child_resource in child_resources:
@@ -1583,7 +5167,7 @@ class Authzee:
             if child_type.__name__ not in child_resources_by_type:
                 child_resources_by_type[child_type.__name__] = []
             
-            child_resources_by_type[child_type.__name__].append(json.loads(child_resource.json()))
+            child_resources_by_type[child_type.__name__].append(self._canonical_resource_data(resource=child_resource))
 
         identities_by_type = {identity_name: [] for identity_name in self._identity_type_names}
         for identity in identities:
@@ -1595,17 +5179,160 @@ class Authzee:
             "resource_type": type(resources[0]).__name__,
             "resource_action": str(resource_action),
             "parent_resources": parent_resources_by_type,
-            "child_resources": child_resources_by_type
+            "child_resources": child_resources_by_type,
+            "risk_score": self._risk_score(identities=identities)
         }
         data_entries = []
-        for resource in resources:
+        for i, resource in enumerate(resources):
+            resource_parents = per_resource_parents[i] if per_resource_parents is not None else []
+            resource_children = per_resource_children[i] if per_resource_children is not None else []
             new_jmespath_data = copy.deepcopy(jmespath_data)
-            new_jmespath_data['resource'] = json.loads(resource.json())
+            new_jmespath_data['resource'] = self._canonical_resource_data(resource=resource)
+            for parent_resource in resource_parents:
+                new_jmespath_data['parent_resources'][type(parent_resource).__name__].append(
+                    self._canonical_resource_data(resource=parent_resource)
+                )
+
+            for child_resource in resource_children:
+                new_jmespath_data['child_resources'].setdefault(type(child_resource).__name__, []).append(
+                    self._canonical_resource_data(resource=child_resource)
+                )
+
+            new_jmespath_data['relations'] = self._relation_data(
+                resource=resource,
+                parent_resources=parent_resources + resource_parents,
+                child_resources=child_resources + resource_children
+            )
             data_entries.append(new_jmespath_data)
 
         return data_entries
 
 
+    def _relation_data(
+        self,
+        resource: BaseModel,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel]
+    ) -> List[Dict[str, str]]:
+        """Get the relation tuples of a resource and its parent and child resources as JMESPath data.
+
+        Each relation has a ``source`` of ``"resource"`` , ``"parent"`` or ``"child"`` for where the object came from.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+        parent_resources : List[BaseModel]
+            Parent resource models.
+        child_resources : List[BaseModel]
+            Child resource models.
+
+        Returns
+        -------
+        List[Dict[str, str]]
+            The relation tuples as JMESPath data.
+        """
+        if self._relation_store is None:
+            return []
+
+        object_sources: Dict[str, List[str]] = {}
+        for source, source_resources in (
+            ("resource", [resource]),
+            ("parent", parent_resources),
+            ("child", child_resources)
+        ):
+            for source_resource in source_resources:
+                resource_authz_inst = self._resource_to_authz_lookup[type(source_resource)]
+                relation_object = resource_authz_inst.relation_object(
+                    resource_authz_inst.canonicalize(source_resource)
+                )
+                if relation_object is not None:
+                    object_sources.setdefault(relation_object, [])
+                    if source not in object_sources[relation_object]:
+                        object_sources[relation_object].append(source)
+
+        if len(object_sources) == 0:
+            return []
+
+        return [
+            {
+                **json.loads(relation_tuple.json()),
+                "source": source
+            }
+            for relation_tuple in self._relation_store.get_relations(objects=list(object_sources))
+            for source in object_sources[relation_tuple.object]
+        ]
+
+
+    def _risk_score(self, identities: List[BaseModel]) -> Optional[float]:
+        """Score the risk of the request identities with the risk provider.
+
+        Parameters
+        ----------
+        identities : List[BaseModel]
+            Identity models.
+
+        Returns
+        -------
+        Optional[float]
+            The risk score, or ``None`` if there is no risk provider or the risk is unknown.
+        """
+        if self._risk_provider is None:
+            return None
+
+        return self._risk_provider.risk_score(identities=identities)
+
+
+    def _verify_relation_store(self) -> None:
+        """Verify that ``Authzee`` has a relation store.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            ``Authzee`` does not have a relation store.
+        """
+        if self._relation_store is None:
+            raise exceptions.InputVerificationError(
+                "A relation store must be given to Authzee to use relation tuples."
+            )
+
+
+    def _canonical_resource_data(self, resource: BaseModel) -> Dict[str, Any]:
+        """Canonicalize a resource with its ``ResourceAuthz`` and convert it to JMESPath data.
+
+        Fields in ``ResourceAuthz.hidden_resource_fields`` are left out.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model.
+
+        Returns
+        -------
+        Dict[str, Any]
+            The JMESPath data for the canonical resource.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The canonical resource is missing resource alias fields.
+        """
+        resource_authz_inst = self._resource_to_authz_lookup[type(resource)]
+        canonical_resource = resource_authz_inst.canonicalize(resource)
+        for alias_field in resource_authz_inst.resource_alias_fields:
+            if getattr(canonical_resource, alias_field) is None:
+                raise exceptions.InputVerificationError(
+                    "Resource alias field '{}' of resource type '{}' was not filled in by canonicalization.".format(
+                        alias_field,
+                        type(resource).__name__
+                    )
+                )
+
+        return json.loads(
+            canonical_resource.json(exclude=resource_authz_inst.hidden_resource_fields)
+        )
+
+
     def _verify_auth_args(
         self,
         resource: BaseModel,
@@ -1633,7 +5360,14 @@ class Authzee:
         ------
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.RequestLimitError
+            The inputs are over the ``RequestLimits`` .
         """
+        self._verify_request_limits(
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
         resource_type = type(resource)
         self._verify_resource_type_and_action_filter(
             resource_type=resource_type,
@@ -1674,6 +5408,7 @@ class Authzee:
                     )
                 )
 
+        identity_counts = {identity_type: 0 for identity_type in self._identity_types}
         for identity in identities:
             identity_type = type(identity)
             if identity_type not in self._identity_types:
@@ -1683,6 +5418,82 @@ class Authzee:
                     )
                 )
 
+            identity_counts[identity_type] += 1
+        
+        for identity_type, count in identity_counts.items():
+            min_count, max_count = self._identity_cardinalities[identity_type]
+            if (
+                count < min_count
+                or (max_count is not None and count > max_count)
+            ):
+                raise exceptions.InputVerificationError(
+                    "{} identities of type '{}' were given, but between {} and {} are required.".format(
+                        count,
+                        identity_type.__name__,
+                        min_count,
+                        "any" if max_count is None else max_count
+                    )
+                )
+
+
+    def _verify_request_limits(
+        self,
+        parent_resources: List[BaseModel], 
+        child_resources: List[BaseModel],
+        identities: List[BaseModel]
+    ) -> None:
+        """Verify the authorization args with the ``RequestLimits`` .
+
+        Parameters
+        ----------
+        parent_resources : List[BaseModel]
+            Parent resource models to verify.
+        child_resources : List[BaseModel]
+            Child resource models to verify.
+        identities : List[BaseModel]
+            Identity models to verify.
+
+        Raises
+        ------
+        authzee.exceptions.RequestLimitError
+            The inputs are over the ``RequestLimits`` .
+        """
+        limits = self._request_limits
+        if (
+            limits.max_parent_resources is not None
+            and len(parent_resources) > limits.max_parent_resources
+        ):
+            raise exceptions.RequestLimitError(
+                "{} parent resources were given, but the max is {}.".format(
+                    len(parent_resources),
+                    limits.max_parent_resources
+                )
+            )
+
+        if (
+            limits.max_child_resources is not None
+            and len(child_resources) > limits.max_child_resources
+        ):
+            raise exceptions.RequestLimitError(
+                "{} child resources were given, but the max is {}.".format(
+                    len(child_resources),
+                    limits.max_child_resources
+                )
+            )
+
+        if limits.max_identities_per_type is not None:
+            identity_counts: Dict[str, int] = {}
+            for identity in identities:
+                identity_type_name = type(identity).__name__
+                identity_counts[identity_type_name] = identity_counts.get(identity_type_name, 0) + 1
+                if identity_counts[identity_type_name] > limits.max_identities_per_type:
+                    raise exceptions.RequestLimitError(
+                        "More than {} identities of type '{}' were given.".format(
+                            limits.max_identities_per_type,
+                            identity_type_name
+                        )
+                    )
+
 
     def _verify_auth_many_args(
         self,
@@ -1690,7 +5501,9 @@ class Authzee:
         resource_action: ResourceAction,
         parent_resources: List[BaseModel], 
         child_resources: List[BaseModel],
-        identities: List[BaseModel]
+        identities: List[BaseModel],
+        per_resource_parents: Optional[List[List[BaseModel]]] = None,
+        per_resource_children: Optional[List[List[BaseModel]]] = None
     ) -> None:
         """Verify the authorization args for many resource calls.
 
@@ -1701,17 +5514,34 @@ class Authzee:
         resource_action : ResourceAction
             Resource Action to verify.
         parent_resources : List[BaseModel]
-            Parent resource models to verify.
+            Shared parent resource models to verify.
         child_resources : List[BaseModel]
-            Child resource models to verify.
+            Shared child resource models to verify.
         identities : List[BaseModel]
             Identity models to verify.
+        per_resource_parents : Optional[List[List[BaseModel]]], optional
+            Parent resource models for each resource to verify.
+        per_resource_children : Optional[List[List[BaseModel]]], optional
+            Child resource models for each resource to verify.
 
         Raises
         ------
         authzee.exceptions.InputVerificationError
             The inputs were not verified with the ``Authzee`` configuration.
+        authzee.exceptions.RequestLimitError
+            The inputs are over the ``RequestLimits`` .
         """
+        if (
+            self._request_limits.max_resources is not None
+            and len(resources) > self._request_limits.max_resources
+        ):
+            raise exceptions.RequestLimitError(
+                "{} resources were given, but the max is {}.".format(
+                    len(resources),
+                    self._request_limits.max_resources
+                )
+            )
+
         resource_type = type(resources[0])
         for resource in resources:
             if isinstance(resource, resource_type) is False:
@@ -1721,13 +5551,38 @@ class Authzee:
                     )
                 )
         
-        self._verify_auth_args(
-            resource=resources[0],
-            resource_action=resource_action,
-            parent_resources=parent_resources,
-            child_resources=child_resources,
-            identities=identities
-        )
+        for name, per_resource in (
+            ("per_resource_parents", per_resource_parents),
+            ("per_resource_children", per_resource_children)
+        ):
+            if per_resource is not None and len(per_resource) != len(resources):
+                raise exceptions.InputVerificationError(
+                    "'{}' has {} entries, but {} resources were given.".format(
+                        name,
+                        len(per_resource),
+                        len(resources)
+                    )
+                )
+
+        if per_resource_parents is None and per_resource_children is None:
+            self._verify_auth_args(
+                resource=resources[0],
+                resource_action=resource_action,
+                parent_resources=parent_resources,
+                child_resources=child_resources,
+                identities=identities
+            )
+
+            return
+
+        for i, resource in enumerate(resources):
+            self._verify_auth_args(
+                resource=resource,
+                resource_action=resource_action,
+                parent_resources=parent_resources + (per_resource_parents[i] if per_resource_parents is not None else []),
+                child_resources=child_resources + (per_resource_children[i] if per_resource_children is not None else []),
+                identities=identities
+            )
 
 
     def _verify_grant(self, grant: Grant) -> None:
@@ -1753,6 +5608,15 @@ class Authzee:
         
         if len(grant.resource_actions) < 1:
             raise exceptions.InputVerificationError("A set of at least one resource action must be given in a grant.")
+
+        try:
+            parsed = jmespath.compile(grant.jmespath_expression).parsed
+        except jmespath.exceptions.JMESPathError as error:
+            raise exceptions.InputVerificationError(
+                "The grant JMESPath expression is not valid: {}".format(error)
+            )
+
+        self._verify_grant_limits(grant=grant, parsed_expression=parsed)
         
         resource_authz_inst = self._resource_to_authz_lookup[resource_type]
         for resource_action in grant.resource_actions:
@@ -1773,6 +5637,54 @@ class Authzee:
                 )
 
 
+    def _verify_grant_limits(self, grant: Grant, parsed_expression: Dict[str, Any]) -> None:
+        """Verify a grant with the ``GrantLimits`` .
+
+        Parameters
+        ----------
+        grant : Grant
+            Grant to verify.
+        parsed_expression : Dict[str, Any]
+            The parsed AST of the grant's JMESPath expression.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The grant is over the configured limits.
+        """
+        limits = self._grant_limits
+        if (
+            limits.max_expression_length is not None
+            and len(grant.jmespath_expression) > limits.max_expression_length
+        ):
+            raise exceptions.InputVerificationError(
+                "The grant JMESPath expression has {} characters, but the max is {}.".format(
+                    len(grant.jmespath_expression),
+                    limits.max_expression_length
+                )
+            )
+
+        if limits.max_expression_depth is not None:
+            depth = expression_depth(parsed=parsed_expression)
+            if depth > limits.max_expression_depth:
+                raise exceptions.InputVerificationError(
+                    "The grant JMESPath expression has a depth of {}, but the max is {}.".format(
+                        depth,
+                        limits.max_expression_depth
+                    )
+                )
+
+        if limits.max_result_match_size is not None:
+            result_match_size = len(json.dumps(grant.result_match))
+            if result_match_size > limits.max_result_match_size:
+                raise exceptions.InputVerificationError(
+                    "The grant result_match is {} characters as JSON, but the max is {}.".format(
+                        result_match_size,
+                        limits.max_result_match_size
+                    )
+                )
+
+
     def _verify_resource_type_and_action_filter(
         self, 
         resource_type: Union[Type[BaseModel], None], 
@@ -0,0 +1,112 @@
+
+import json
+import os
+from typing import Mapping, Optional
+
+from pydantic import BaseModel
+
+from authzee.error_severity import ErrorSeverity
+from authzee.fail_policy import FailPolicy
+from authzee.grant_limits import GrantLimits
+from authzee.request_limits import RequestLimits
+
+
+class AuthzeeConfig(BaseModel):
+    """Configuration options for the ``Authzee`` app.
+
+    By default no limits are applied and JMESPath errors are ignored.
+
+    - ``grant_limits`` - Limits on the size and complexity of grants. See ``authzee.GrantLimits`` .
+    - ``request_limits`` - Limits on the size of authorization requests. See ``authzee.RequestLimits`` .
+    - ``jmespath_error_severity`` - How errors from searching grant JMESPath expressions are handled.
+      ``ErrorSeverity.CRITICAL`` raises ``authzee.exceptions.GrantEvaluationError`` instead of treating the grant as not matching.
+    - ``grant_inheritance`` - Grants of parent resources with ``applies_to_descendants`` also apply to their child resources
+      in ``authorize`` and ``authorize_many`` calls. See ``ResourceAuthz.inherited_actions`` .
+    - ``fail_policy`` - How storage and compute failures during authorization are decided. See ``authzee.FailPolicy`` .
+    - ``track_grant_usage`` - Record when each grant last decided or matched a request with the storage backend.
+    - ``grant_usage_batch_size`` - Number of used grants to hold before they are written to storage.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import AuthzeeConfig
+
+        config = AuthzeeConfig.from_json(
+            '{"jmespath_error_severity": "CRITICAL", "grant_limits": {"max_expression_length": 1000}}'
+        )
+        # or from AUTHZEE_JMESPATH_ERROR_SEVERITY=CRITICAL, AUTHZEE_GRANT_LIMITS__MAX_EXPRESSION_LENGTH=1000
+        config = AuthzeeConfig.from_env()
+
+    """
+
+    grant_limits: GrantLimits = GrantLimits()
+    request_limits: RequestLimits = RequestLimits()
+    jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE
+    grant_inheritance: bool = False
+    fail_policy: FailPolicy = FailPolicy()
+    track_grant_usage: bool = False
+    grant_usage_batch_size: int = 1
+
+
+    @classmethod
+    def from_json(cls, config_json: str) -> "AuthzeeConfig":
+        """Load the config from a JSON string.
+
+        Parameters
+        ----------
+        config_json : str
+            The config as a JSON object.
+
+        Returns
+        -------
+        AuthzeeConfig
+            The loaded config.
+        """
+        return cls(**json.loads(config_json))
+
+
+    @classmethod
+    def from_env(
+        cls,
+        prefix: str = "AUTHZEE_",
+        environ: Optional[Mapping[str, str]] = None
+    ) -> "AuthzeeConfig":
+        """Load the config from environment variables.
+
+        Variable names are the upper case field names after ``prefix`` .
+        Nested fields are separated with ``__`` , like ``AUTHZEE_REQUEST_LIMITS__MAX_RESOURCES`` .
+        Variables that are not set keep the default.
+
+        Parameters
+        ----------
+        prefix : str, optional
+            Prefix of the environment variables, by default ``"AUTHZEE_"`` .
+        environ : Optional[Mapping[str, str]], optional
+            Environment variables to load from.
+            By default ``os.environ`` .
+
+        Returns
+        -------
+        AuthzeeConfig
+            The loaded config.
+        """
+        if environ is None:
+            environ = os.environ
+
+        config = {}
+        for field_name, field_info in cls.model_fields.items():
+            env_name = "{}{}".format(prefix, field_name.upper())
+            if issubclass(field_info.annotation, BaseModel):
+                nested = {
+                    nested_name: environ["{}__{}".format(env_name, nested_name.upper())]
+                    for nested_name in field_info.annotation.model_fields
+                    if "{}__{}".format(env_name, nested_name.upper()) in environ
+                }
+                if len(nested) > 0:
+                    config[field_name] = nested
+            
+            elif env_name in environ:
+                config[field_name] = environ[env_name]
+
+        return cls(**config)
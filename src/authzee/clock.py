@@ -0,0 +1,44 @@
+
+import datetime
+import time
+
+
+class Clock:
+    """Time source for ``Authzee`` .
+
+    The base class uses the system clock.
+    Sub-class it to supply another time source, like in embedded environments,
+    or use ``authzee.FixedClock`` for deterministic tests and replay runs.
+
+    Sub-classes may implement:
+
+        - ``now`` - The current time.
+        - ``time`` - The current time in seconds since the epoch.
+
+    ``time`` defaults to the timestamp of ``now`` .
+    """
+
+
+    def now(self) -> datetime.datetime:
+        """The current time.
+
+        Returns
+        -------
+        datetime.datetime
+            Timezone aware current time.
+        """
+        return datetime.datetime.now(datetime.timezone.utc)
+
+
+    def time(self) -> float:
+        """The current time in seconds since the epoch.
+
+        Returns
+        -------
+        float
+            Seconds since the epoch.
+        """
+        if type(self).now is Clock.now:
+            return time.time()
+
+        return self.now().timestamp()
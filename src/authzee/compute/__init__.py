@@ -3,6 +3,7 @@ __all__ = [
     "ComputeBackend",
     "MainProcessCompute",
     "MultiprocessCompute",
+    "ScriptedComputeBackend",
     "ThreadedCompute"
 ]
 
@@ -10,4 +11,5 @@ from authzee.compute.compute_backend import ComputeBackend
 
 from authzee.compute.main_process_compute import MainProcessCompute
 from authzee.compute.multiprocess_compute import MultiprocessCompute
+from authzee.compute.scripted_compute_backend import ScriptedComputeBackend
 from authzee.compute.threaded_compute import ThreadedCompute
\ No newline at end of file
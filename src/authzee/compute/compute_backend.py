@@ -7,7 +7,12 @@ from pydantic import BaseModel
 
 from authzee import exceptions
 from authzee.backend_locality import BackendLocality
+from authzee.deadline import Deadline
+from authzee.error_severity import ErrorSeverity
+from authzee.evaluation_budget import EvaluationBudget
 from authzee.grant_effect import GrantEffect
+from authzee.grant_usage_recorder import GrantUsageRecorder
+from authzee.rng import Rng
 from authzee.grants_page import GrantsPage
 from authzee.resource_action import ResourceAction
 from authzee.resource_authz import ResourceAuthz
@@ -48,6 +53,10 @@ class ComputeBackend:
     compatible_localities : Set[BackendLocality]
         Set of compatible storage backend localities.
         This parameter should not be exposed on the child class.
+    supports_evaluation_budget : bool, optional
+        This instance of the compute backend spends one ``EvaluationBudget`` for all of the grant evaluations of a call,
+        by default ``True`` .
+        This parameter should not be exposed on the child class.
     """
 
 
@@ -55,11 +64,13 @@ class ComputeBackend:
         self,
         async_enabled: bool,
         backend_locality: BackendLocality,
-        compatible_localities: Set[BackendLocality]
+        compatible_localities: Set[BackendLocality],
+        supports_evaluation_budget: bool = True
     ):
         self.async_enabled = async_enabled
         self.backend_locality = backend_locality
         self.compatible_localities = compatible_localities
+        self.supports_evaluation_budget = supports_evaluation_budget
         # Reassign all to a method with a better error
         if async_enabled is False:
             self.authorize_async = self._async_not_supported
@@ -73,6 +84,10 @@ class ComputeBackend:
         jmespath_options: jmespath.Options,
         resource_authzs: List[ResourceAuthz],
         storage_backend: StorageBackend,
+        jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+        max_search_result_size: Optional[int] = None,
+        grant_usage_recorder: Optional[GrantUsageRecorder] = None,
+        rng: Optional[Rng] = None
     ) -> None:
         """Initialize the compute backend.
 
@@ -91,11 +106,24 @@ class ComputeBackend:
             ``ResourceAuthz`` s registered with the ``Authzee`` app.
         storage_backend : StorageBackend
             Storage backend registered with the ``Authzee`` app.
+        jmespath_error_severity : ErrorSeverity, optional
+            How JMESPath errors while computing grants are handled, by default ``ErrorSeverity.IGNORE`` .
+        max_search_result_size : Optional[int], optional
+            Max size of a JMESPath search result as a JSON string.
+            Larger results are handled with ``jmespath_error_severity`` . By default there is no limit.
+        grant_usage_recorder : Optional[GrantUsageRecorder], optional
+            Records the grants that decide or match requests, when grant usage is tracked.
+        rng : Optional[Rng], optional
+            Entropy source for sampling, like canary grants. By default, the ``random`` module is used.
         """
         self._identity_types = identity_types
         self._jmespath_options = jmespath_options
         self._resource_authzs = resource_authzs
         self._storage_backend = storage_backend
+        self._jmespath_error_severity = jmespath_error_severity
+        self._max_search_result_size = max_search_result_size
+        self._grant_usage_recorder = grant_usage_recorder
+        self._rng = rng if rng is not None else Rng()
 
 
     def shutdown(self) -> None:
@@ -122,6 +150,8 @@ class ComputeBackend:
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
         page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -142,6 +172,12 @@ class ComputeBackend:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Should be checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Should be spent for each grant evaluation.
 
         Returns
         -------
@@ -150,6 +186,8 @@ class ComputeBackend:
 
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
         authzee.exceptions.MethodNotImplementedError
             Sub-classes must implement this method.
         """
@@ -162,6 +200,8 @@ class ComputeBackend:
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
         page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -182,6 +222,12 @@ class ComputeBackend:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Should be checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Should be spent for each grant evaluation.
 
         Returns
         -------
@@ -190,6 +236,8 @@ class ComputeBackend:
 
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
         authzee.exceptions.MethodNotImplementedError
             Sub-classes *may* implement this method if ``async`` is supported.
         """
@@ -202,6 +250,8 @@ class ComputeBackend:
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
         page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -222,6 +272,12 @@ class ComputeBackend:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Should be checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Should be spent for each grant evaluation.
 
         Returns
         -------
@@ -231,6 +287,8 @@ class ComputeBackend:
 
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
         authzee.exceptions.MethodNotImplementedError
             Sub-classes must implement this method.
         """
@@ -242,7 +300,9 @@ class ComputeBackend:
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -263,6 +323,12 @@ class ComputeBackend:
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Should be checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Should be spent for each grant evaluation.
 
         Returns
         -------
@@ -272,6 +338,8 @@ class ComputeBackend:
 
         Raises
         ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline passed before a decision was made.
         authzee.exceptions.MethodNotImplementedError
             Sub-classes must implement this method.
         """
@@ -1,52 +1,452 @@
 
-from typing import Any, Dict, List, Union
+from concurrent.futures import BrokenExecutor
+import functools
+from typing import Any, Awaitable, Callable, Dict, List, Optional, Tuple, TypeVar, Union
 
 import jmespath
+import jmespath.parser
 from loguru import logger
 
+from authzee import exceptions
+from authzee.deadline import check_deadline, Deadline
+from authzee.error_severity import ErrorSeverity
+from authzee.evaluation_budget import EvaluationBudget, spend_budget
 from authzee.grant import Grant
+from authzee.grant_effect import GrantEffect
+from authzee.grant_match_mode import GrantMatchMode
+from authzee.grant_usage_recorder import GrantUsageRecorder, record_grant_usage
 from authzee.grants_page import GrantsPage
+from authzee.rng import Rng
+
+
+# Node types that can read the request data, or run functions that might.
+DATA_NODE_TYPES = {"current", "field", "function_expression", "identity"}
+
+T = TypeVar("T")
+
+
+def compute_errors(func: Callable[..., Awaitable[T]]) -> Callable[..., Awaitable[T]]:
+    """Decorate an async compute backend method to raise errors from lost workers, 
+    like a broken pool or pipe, as ``authzee.exceptions.ComputeBackendError`` .
+    """
+    @functools.wraps(func)
+    async def wrapper(*args, **kwargs) -> T:
+        try:
+            return await func(*args, **kwargs)
+        except (BrokenExecutor, BrokenPipeError, EOFError) as error:
+            raise exceptions.ComputeBackendError("A compute worker was lost: {!r}".format(error)) from error
+
+    return wrapper
 
 
 def grant_matches(
     grant: Grant,
     jmespath_data: Dict[str, Any],
-    jmespath_options: jmespath.Options
+    jmespath_options: jmespath.Options,
+    jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+    max_search_result_size: Optional[int] = None,
+    rng: Optional[Rng] = None
 ) -> bool:
+    if grant.canary_sample_rate is not None:
+        evaluate_canary_grant(
+            grant=grant,
+            jmespath_data=jmespath_data,
+            jmespath_options=jmespath_options,
+            max_search_result_size=max_search_result_size,
+            rng=rng
+        )
+
+        return False
+
+    result, search_error = search_grant(
+        grant=grant,
+        jmespath_data=jmespath_data,
+        jmespath_options=jmespath_options,
+        jmespath_error_severity=jmespath_error_severity,
+        max_search_result_size=max_search_result_size
+    )
+    if search_error is not None:
+        return False
+
+    is_match = grant_result_matches(grant=grant, result=result)
+    logger.debug("JMESPath result == result_match: {}".format(is_match))
+
+    return is_match
+
+
+def evaluate_canary_grant(
+    grant: Grant,
+    jmespath_data: Dict[str, Any],
+    jmespath_options: jmespath.Options,
+    max_search_result_size: Optional[int] = None,
+    rng: Optional[Rng] = None
+) -> None:
+    """Evaluate a canary grant for a sample of requests and log whether it matched.
+
+    Canary grants never match, so they don't affect decisions.
+    Search errors are logged instead of raised.
+
+    Parameters
+    ----------
+    grant : Grant
+        The canary grant, with ``canary_sample_rate`` set.
+    jmespath_data : Dict[str, Any]
+        The JMESPath data of the request.
+    jmespath_options : jmespath.Options
+        Options for the search.
+    max_search_result_size : Optional[int], optional
+        Max characters of the result as JSON, by default no max.
+    rng : Optional[Rng], optional
+        Entropy source for sampling requests, by default ``authzee.Rng`` .
+    """
+    rng = rng if rng is not None else Rng()
+    if rng.random() >= grant.canary_sample_rate:
+        return
+
+    result, search_error = search_grant(
+        grant=grant,
+        jmespath_data=jmespath_data,
+        jmespath_options=jmespath_options,
+        jmespath_error_severity=ErrorSeverity.IGNORE,
+        max_search_result_size=max_search_result_size
+    )
+    logger.info(
+        "Canary grant '{}' with UUID '{}' matched: {}, search error: {}".format(
+            grant.name,
+            grant.uuid,
+            search_error is None and grant_result_matches(grant=grant, result=result),
+            search_error
+        )
+    )
+
+
+def search_grant(
+    grant: Grant,
+    jmespath_data: Dict[str, Any],
+    jmespath_options: jmespath.Options,
+    jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+    max_search_result_size: Optional[int] = None
+) -> Tuple[Any, Optional[str]]:
+    """Search the JMESPath data with the grant's JMESPath expression.
+
+    Parameters
+    ----------
+    grant : Grant
+        The grant.
+    jmespath_data : Dict[str, Any]
+        The JMESPath data of the request.
+    jmespath_options : jmespath.Options
+        Options for the search.
+    jmespath_error_severity : ErrorSeverity, optional
+        Raise on search errors if ``CRITICAL`` , by default ``IGNORE`` .
+    max_search_result_size : Optional[int], optional
+        Max characters of the result as JSON, by default no max.
+
+    Returns
+    -------
+    Tuple[Any, Optional[str]]
+        The search result, and why the search failed or ``None`` .
+        A failed search never matches.
+
+    Raises
+    ------
+    authzee.exceptions.GrantEvaluationError
+        The search failed and ``jmespath_error_severity`` is ``CRITICAL`` .
+    """
     import json
     logger.debug("JMESPath Data: {}".format(json.dumps(jmespath_data, indent=4)))
     logger.debug("JMESPath Expression: {}".format(grant.jmespath_expression))
     try:
-        result = jmespath.search(
-            grant.jmespath_expression, 
-            jmespath_data, 
-            options=jmespath_options
-        )
+        is_constant, result = constant_search_result(jmespath_expression=grant.jmespath_expression)
+        if is_constant is False:
+            result = compiled_expression(jmespath_expression=grant.jmespath_expression).search(
+                jmespath_data, 
+                options=jmespath_options
+            )
+
         logger.debug("JMESPath Expression Value: {}".format(result))
-    except jmespath.exceptions.JMESPathError as error:
+    except (jmespath.exceptions.JMESPathError, RecursionError) as error:
         logger.debug("JMESPath Search error: {}".format(error))
+        if jmespath_error_severity is ErrorSeverity.CRITICAL:
+            raise exceptions.GrantEvaluationError(
+                "The JMESPath expression of grant '{}' failed: {}".format(grant.uuid, error)
+            ) from error
+
+        return None, "JMESPath search error: {}".format(error)
+
+    if max_search_result_size is not None:
+        result_size = len(json.dumps(result))
+        if result_size > max_search_result_size:
+            logger.debug("JMESPath result size {} is over the max {}".format(result_size, max_search_result_size))
+            if jmespath_error_severity is ErrorSeverity.CRITICAL:
+                raise exceptions.GrantEvaluationError(
+                    "The JMESPath result of grant '{}' is {} characters as JSON, but the max is {}.".format(
+                        grant.uuid,
+                        result_size,
+                        max_search_result_size
+                    )
+                )
+
+            return None, "JMESPath result size {} is over the max {}".format(result_size, max_search_result_size)
+
+    return result, None
+
+
+def grant_result_matches(grant: Grant, result: Any) -> bool:
+    """Compare a JMESPath search result with the grant's ``result_match`` , using the grant's ``match_mode`` .
+
+    Parameters
+    ----------
+    grant : Grant
+        The grant.
+    result : Any
+        The search result from ``search_grant`` .
+
+    Returns
+    -------
+    bool
+        ``True`` if the result matches.
+    """
+    if grant.match_mode is GrantMatchMode.ONE_OF:
+        return any(
+            result_matches(
+                result=result,
+                result_match=match_value,
+                order_insensitive_arrays=grant.order_insensitive_arrays,
+                numeric_tolerance=grant.numeric_tolerance
+            )
+            for match_value in grant.result_match
+        )
+
+    if grant.match_mode is GrantMatchMode.TRUTHY:
+        return _is_truthy(result)
+
+    return result_matches(
+        result=result,
+        result_match=grant.result_match,
+        order_insensitive_arrays=grant.order_insensitive_arrays,
+        numeric_tolerance=grant.numeric_tolerance
+    )
+
+
+def is_constant_expression(parsed: Dict[str, Any]) -> bool:
+    """Check if a parsed JMESPath expression never reads the request data, like a literal ``true`` .
+
+    Parameters
+    ----------
+    parsed : Dict[str, Any]
+        The AST from ``jmespath.compile(expression).parsed`` .
+
+    Returns
+    -------
+    bool
+        ``True`` if the expression has the same result for all requests.
+    """
+    if parsed.get("type") in DATA_NODE_TYPES:
+        return False
+
+    return all(
+        is_constant_expression(parsed=child)
+        for child in parsed.get("children", [])
+        if isinstance(child, dict)
+    )
+
+
+@functools.lru_cache(maxsize=1024)
+def constant_search_result(jmespath_expression: str) -> Tuple[bool, Any]:
+    """Evaluate a JMESPath expression once if it never reads the request data.
+
+    Grants with constant expressions always or never match, so the result is cached
+    and the search is skipped for each request. The cached result must not be modified.
+
+    Parameters
+    ----------
+    jmespath_expression : str
+        The JMESPath expression.
+
+    Returns
+    -------
+    Tuple[bool, Any]
+        ``True`` and the search result if the expression is constant, else ``False`` and ``None`` .
+
+    Raises
+    ------
+    jmespath.exceptions.JMESPathError
+        The constant expression could not be evaluated.
+    """
+    try:
+        parsed = compiled_expression(jmespath_expression=jmespath_expression).parsed
+    except jmespath.exceptions.JMESPathError:
+        return False, None
+
+    if is_constant_expression(parsed=parsed) is False:
+        return False, None
+
+    return True, compiled_expression(jmespath_expression=jmespath_expression).search({})
+
+
+@functools.lru_cache(maxsize=4096)
+def compiled_expression(jmespath_expression: str) -> jmespath.parser.ParsedResult:
+    """Compile a JMESPath expression, with a cache shared by all evaluations in the process.
+
+    Hot grants are searched with the cached compiled expression instead of being parsed for every request.
+    The least recently used expressions are dropped once the cache is full.
+
+    Parameters
+    ----------
+    jmespath_expression : str
+        The JMESPath expression.
+
+    Returns
+    -------
+    jmespath.parser.ParsedResult
+        The compiled expression.
+
+    Raises
+    ------
+    jmespath.exceptions.JMESPathError
+        The expression could not be compiled.
+    """
+    return jmespath.compile(jmespath_expression)
+
+
+def result_matches(
+    result: Any,
+    result_match: Any,
+    order_insensitive_arrays: bool = False,
+    numeric_tolerance: Optional[float] = None
+) -> bool:
+    """Compare a JMESPath result to a grant's ``result_match`` .
+
+    Parameters
+    ----------
+    result : Any
+        The JMESPath search result.
+    result_match : Any
+        The grant's ``result_match`` .
+    order_insensitive_arrays : bool, optional
+        Arrays are equal if they have the same elements in any order, by default False
+    numeric_tolerance : Optional[float], optional
+        Numbers are equal if they are within this absolute tolerance, by default None
+
+    Returns
+    -------
+    bool
+        ``True`` if the result matches.
+    """
+    if (
+        order_insensitive_arrays is False
+        and numeric_tolerance is None
+    ):
+        return result == result_match
+
+    if (
+        numeric_tolerance is not None
+        and _is_number(result) is True
+        and _is_number(result_match) is True
+    ):
+        return abs(result - result_match) <= numeric_tolerance
+
+    if isinstance(result, list) and isinstance(result_match, list):
+        if len(result) != len(result_match):
+            return False
+
+        if order_insensitive_arrays is False:
+            return all(
+                result_matches(
+                    result=value,
+                    result_match=match_value,
+                    order_insensitive_arrays=order_insensitive_arrays,
+                    numeric_tolerance=numeric_tolerance
+                )
+                for value, match_value in zip(result, result_match)
+            )
+
+        unmatched = list(result_match)
+        for value in result:
+            for i, match_value in enumerate(unmatched):
+                if result_matches(
+                    result=value,
+                    result_match=match_value,
+                    order_insensitive_arrays=order_insensitive_arrays,
+                    numeric_tolerance=numeric_tolerance
+                ) is True:
+                    unmatched.pop(i)
+                    break
+            else:
+                return False
+
+        return True
+
+    if isinstance(result, dict) and isinstance(result_match, dict):
+        if result.keys() != result_match.keys():
+            return False
+
+        return all(
+            result_matches(
+                result=result[key],
+                result_match=result_match[key],
+                order_insensitive_arrays=order_insensitive_arrays,
+                numeric_tolerance=numeric_tolerance
+            )
+            for key in result
+        )
+
+    return result == result_match
+
+
+def _is_truthy(value: Any) -> bool:
+    if value is None or value is False:
         return False
 
-    logger.debug("JMESPath result == result_match: {}".format(result == grant.result_match))
+    if isinstance(value, (str, list, dict)) and len(value) == 0:
+        return False
+
+    return True
+
 
-    return result == grant.result_match
+def _is_number(value: Any) -> bool:
+    return (
+        isinstance(value, (int, float))
+        and isinstance(value, bool) is False
+    )
 
 
 def authorize_many_grants(
     grants_page: GrantsPage, 
     jmespath_data_entries: List[Dict[str, Any]], 
-    jmespath_options: jmespath.Options
+    jmespath_options: jmespath.Options,
+    deadline: Optional[Deadline] = None,
+    budget: Optional[EvaluationBudget] = None,
+    jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+    max_search_result_size: Optional[int] = None,
+    effect: Optional[GrantEffect] = None,
+    grant_usage_recorder: Optional[GrantUsageRecorder] = None,
+    rng: Optional[Rng] = None
 ) -> List[Union[bool, None]]:
     results = {i: None for i in range(len(jmespath_data_entries))}
     for grant in grants_page.grants:        
+        check_deadline(deadline=deadline, phase="evaluation")
+        spend_budget(budget=budget, grant=grant, evaluations=len(jmespath_data_entries))
+        grant_used = False
         for i, jmespath_data in zip(results, jmespath_data_entries):
+            if results[i] is not None:
+                continue
+
             grant_match = grant_matches(
                 grant=grant,
                 jmespath_data=jmespath_data,
-                jmespath_options=jmespath_options
+                jmespath_options=jmespath_options,
+                jmespath_error_severity=jmespath_error_severity,
+                max_search_result_size=max_search_result_size,
+                rng=rng
             )
             if grant_match is True:
                 results[i] = True
+                grant_used = True
+
+        if grant_used is True:
+            record_grant_usage(recorder=grant_usage_recorder, effect=effect, grant=grant)
 
     return list(results.values())
 
@@ -54,14 +454,20 @@ def authorize_many_grants(
 def compute_matching_grants(
     grants_page: GrantsPage, 
     jmespath_data: Dict[str, Any], 
-    jmespath_options: jmespath.Options
+    jmespath_options: jmespath.Options,
+    jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+    max_search_result_size: Optional[int] = None,
+    rng: Optional[Rng] = None
 ) -> List[Grant]:
     matching_grants: List[Grant] = []
     for grant in grants_page.grants:
         grant_match = grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=jmespath_options
+            jmespath_options=jmespath_options,
+            jmespath_error_severity=jmespath_error_severity,
+            max_search_result_size=max_search_result_size,
+            rng=rng
         )
         if grant_match is True:
             matching_grants.append(grant)
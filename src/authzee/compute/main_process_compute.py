@@ -1,12 +1,16 @@
 
-from typing import Any, Dict, List, Optional, Set, Type
+from typing import Any, Dict, Generator, List, Optional, Set, Type
 
 from pydantic import BaseModel
 
 from authzee.backend_locality import BackendLocality
 from authzee.compute.compute_backend import ComputeBackend
 from authzee.compute import general as gc
+from authzee.compute.page_prefetcher import prefetch_grants_pages
+from authzee.deadline import check_deadline, Deadline
+from authzee.evaluation_budget import EvaluationBudget, spend_budget
 from authzee.grant_effect import GrantEffect
+from authzee.grant_usage_recorder import record_grant_usage
 from authzee.grants_page import GrantsPage
 from authzee.resource_action import ResourceAction
 
@@ -14,9 +18,16 @@ from authzee.resource_action import ResourceAction
 class MainProcessCompute(ComputeBackend):
     """Process grants directly.
 
+    Parameters
+    ----------
+    prefetch_pages : int, optional
+        Max number of grants pages to fetch from storage in a background thread,
+        while the current page is evaluated, by default 0 (no prefetching).
+        Prefetching helps when storage round trips take longer than evaluating a page of grants.
+
     """
 
-    def __init__(self):
+    def __init__(self, prefetch_pages: int = 0):
         super().__init__(
             async_enabled=False,
             backend_locality=BackendLocality.MAIN_PROCESS,
@@ -26,6 +37,7 @@ class MainProcessCompute(ComputeBackend):
                 BackendLocality.SYSTEM
             }
         )
+        self._prefetch_pages = prefetch_pages
 
 
     def shutdown(self) -> None:
@@ -41,52 +53,52 @@ class MainProcessCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
-        done_pagination = False
-        next_page_ref = None
-        while done_pagination is False:
-            raw_grants_page = self._storage_backend.get_raw_grants_page(
-                effect=GrantEffect.DENY,
-                resource_type=resource_type,
-                resource_action=resource_action,
-                next_page_reference=next_page_ref
-            )
-            grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
-            next_page_ref = grants_page.next_page_reference
-            if next_page_ref is None:
-                done_pagination = True
-
+        for grants_page in self._grants_pages(
+            effect=GrantEffect.DENY,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            deadline=deadline
+        ):
             for grant in grants_page.grants:
+                check_deadline(deadline=deadline, phase="evaluation")
+                spend_budget(budget=budget, grant=grant)
                 grant_match = gc.grant_matches(
                     grant=grant,
                     jmespath_data=jmespath_data,
-                    jmespath_options=self._jmespath_options
+                    jmespath_options=self._jmespath_options,
+                    jmespath_error_severity=self._jmespath_error_severity,
+                    max_search_result_size=self._max_search_result_size,
+                    rng=self._rng
                 )
                 if grant_match is True:
+                    record_grant_usage(recorder=self._grant_usage_recorder, effect=GrantEffect.DENY, grant=grant)
                     return False
 
-        done_pagination = False
-        next_page_ref = None
-        while done_pagination is False:
-            raw_grants_page = self._storage_backend.get_raw_grants_page(
-                effect=GrantEffect.ALLOW,
-                resource_type=resource_type,
-                resource_action=resource_action,
-                next_page_reference=next_page_ref
-            )
-            grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
-            next_page_ref = grants_page.next_page_reference
-            if next_page_ref is None:
-                done_pagination = True
-
+        for grants_page in self._grants_pages(
+            effect=GrantEffect.ALLOW,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            deadline=deadline
+        ):
             for grant in grants_page.grants:
+                check_deadline(deadline=deadline, phase="evaluation")
+                spend_budget(budget=budget, grant=grant)
                 grant_match = gc.grant_matches(
                     grant=grant,
                     jmespath_data=jmespath_data,
-                    jmespath_options=self._jmespath_options
+                    jmespath_options=self._jmespath_options,
+                    jmespath_error_severity=self._jmespath_error_severity,
+                    max_search_result_size=self._max_search_result_size,
+                    rng=self._rng
                 )
                 if grant_match is True:
+                    record_grant_usage(recorder=self._grant_usage_recorder, effect=GrantEffect.ALLOW, grant=grant)
                     return True
         
         return False
@@ -97,58 +109,64 @@ class MainProcessCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         results = {i: None for i in range(len(jmespath_data_entries))}
-        done_pagination = False
-        next_page_ref = None
-        while done_pagination is False:
-            raw_grants_page = self._storage_backend.get_raw_grants_page(
-                effect=GrantEffect.DENY,
-                resource_type=resource_type,
-                resource_action=resource_action,
-                next_page_reference=next_page_ref
-            )
-            grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
-            next_page_ref = grants_page.next_page_reference
-            if next_page_ref is None:
-                done_pagination = True
-
+        for grants_page in self._grants_pages(
+            effect=GrantEffect.DENY,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            deadline=deadline
+        ):
             for grant in grants_page.grants:
+                check_deadline(deadline=deadline, phase="evaluation")
+                spend_budget(budget=budget, grant=grant, evaluations=len(jmespath_data_entries))
                 for i, jmespath_data in zip(results, jmespath_data_entries):
+                    if results[i] is not None:
+                        continue
+
                     grant_match = gc.grant_matches(
                         grant=grant,
                         jmespath_data=jmespath_data,
-                        jmespath_options=self._jmespath_options
+                        jmespath_options=self._jmespath_options,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        rng=self._rng
                     )
                     if grant_match is True:
+                        record_grant_usage(recorder=self._grant_usage_recorder, effect=GrantEffect.DENY, grant=grant)
                         results[i] = False
                         values = list(results.values())
                         if None not in values:
                             return values
 
-        done_pagination = False
-        next_page_ref = None
-        while done_pagination is False:
-            raw_grants_page = self._storage_backend.get_raw_grants_page(
-                effect=GrantEffect.ALLOW,
-                resource_type=resource_type,
-                resource_action=resource_action,
-                next_page_reference=next_page_ref
-            )
-            grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
-            next_page_ref = grants_page.next_page_reference
-            if next_page_ref is None:
-                done_pagination = True
-
+        for grants_page in self._grants_pages(
+            effect=GrantEffect.ALLOW,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            deadline=deadline
+        ):
             for grant in grants_page.grants:
+                check_deadline(deadline=deadline, phase="evaluation")
+                spend_budget(budget=budget, grant=grant, evaluations=len(jmespath_data_entries))
                 for i, jmespath_data in zip(results, jmespath_data_entries):
+                    if results[i] is not None:
+                        continue
+
                     grant_match = gc.grant_matches(
                         grant=grant,
                         jmespath_data=jmespath_data,
-                        jmespath_options=self._jmespath_options
+                        jmespath_options=self._jmespath_options,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        rng=self._rng
                     )
                     if grant_match is True:
+                        record_grant_usage(recorder=self._grant_usage_recorder, effect=GrantEffect.ALLOW, grant=grant)
                         results[i] = True
                         values = list(results.values())
                         if None not in values:
@@ -179,7 +197,10 @@ class MainProcessCompute(ComputeBackend):
             grant_match = gc.grant_matches(
                 grant=grant,
                 jmespath_data=jmespath_data,
-                jmespath_options=self._jmespath_options
+                jmespath_options=self._jmespath_options,
+                jmespath_error_severity=self._jmespath_error_severity,
+                max_search_result_size=self._max_search_result_size,
+                rng=self._rng
             )
             if grant_match == True:
                 matching_grants.append(grant)
@@ -190,4 +211,71 @@ class MainProcessCompute(ComputeBackend):
         )
 
 
+    def _grants_pages(
+        self,
+        effect: GrantEffect,
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None
+    ) -> Generator[GrantsPage, None, None]:
+        """Iterate over all of the grants pages for a resource type and action.
+
+        Pages are prefetched when ``prefetch_pages`` is greater than ``0`` .
+        The deadline is checked after each page is retrieved.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            Effect of the grants.
+        resource_type : Type[BaseModel]
+            Resource type of the grants.
+        resource_action : ResourceAction
+            Resource action of the grants.
+        page_size : Optional[int], optional
+            The page size to use for the storage backend.
+            The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+
+        Yields
+        ------
+        GrantsPage
+            Normalized grants pages.
+        """
+        if self._prefetch_pages > 0:
+            grants_pages = prefetch_grants_pages(
+                storage_backend=self._storage_backend,
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size,
+                max_prefetched_pages=self._prefetch_pages,
+                deadline=deadline
+            )
+            try:
+                for grants_page in grants_pages:
+                    check_deadline(deadline=deadline, phase="storage retrieval")
+                    yield grants_page
+            finally:
+                grants_pages.close()
+
+            return
+
+        done_pagination = False
+        next_page_ref = None
+        while done_pagination is False:
+            raw_grants_page = self._storage_backend.get_raw_grants_page(
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size,
+                next_page_reference=next_page_ref
+            )
+            grants_page = self._storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
+            check_deadline(deadline=deadline, phase="storage retrieval")
+            next_page_ref = grants_page.next_page_reference
+            if next_page_ref is None:
+                done_pagination = True
 
+            yield grants_page
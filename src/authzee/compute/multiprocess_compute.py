@@ -13,14 +13,21 @@ from loguru import logger
 from pydantic import BaseModel
 
 from authzee.backend_locality import BackendLocality
+from authzee.clock import Clock
 from authzee.compute import general as gc
 from authzee.compute.compute_backend import ComputeBackend
 from authzee.compute.shared_mem_event import SharedMemEvent
+from authzee.compute.worker_pager import WorkerPager
+from authzee.deadline import check_deadline, Deadline
+from authzee.error_severity import ErrorSeverity
+from authzee.evaluation_budget import EvaluationBudget
 from authzee.grant import Grant
 from authzee.grant_effect import GrantEffect
+from authzee.grant_usage_recorder import GrantUsageRecorder, record_grant_usage
 from authzee.grants_page import GrantsPage
 from authzee.resource_action import ResourceAction
 from authzee.resource_authz import ResourceAuthz
+from authzee.rng import Rng
 from authzee.storage.storage_backend import StorageBackend 
 
 
@@ -30,6 +37,15 @@ class MultiprocessCompute(ComputeBackend):
     Uses a pool of processes for compute.
     Made with the "spawn" context. 
 
+    Worker processes fetch their own grants from storage.
+    If the storage backend ``supports_parallel_pagination`` , the page references are listed first
+    and all pages are fetched concurrently.
+    If it ``supports_uuid_pages`` , only pages of grant UUIDs are fetched in the main process,
+    and workers fetch the grants by UUID.
+    Otherwise each worker sends back the next page reference before the next worker is started.
+
+    Worker processes can not share an ``EvaluationBudget`` ,
+    so ``RequestLimits.max_evaluation_cost`` can not be set with this backend.
 
     Parameters
     ----------
@@ -57,7 +73,8 @@ class MultiprocessCompute(ComputeBackend):
                 BackendLocality.MAIN_PROCESS,
                 BackendLocality.NETWORK,
                 BackendLocality.SYSTEM
-            }
+            },
+            supports_evaluation_budget=False
         )
         self._max_workers = max_workers
         if self._max_workers is None:
@@ -70,6 +87,10 @@ class MultiprocessCompute(ComputeBackend):
         jmespath_options: jmespath.Options,
         resource_authzs: List[ResourceAuthz],
         storage_backend: StorageBackend,
+        jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+        max_search_result_size: Optional[int] = None,
+        grant_usage_recorder: Optional[GrantUsageRecorder] = None,
+        rng: Optional[Rng] = None
     ) -> None:
         """Initialize multiprocess backend.
 
@@ -85,12 +106,28 @@ class MultiprocessCompute(ComputeBackend):
             ``ResourceAuthz`` s registered with the ``Authzee`` app.
         storage_backend : StorageBackend
             Storage backend registered with the ``Authzee`` app.
+        jmespath_error_severity : ErrorSeverity, optional
+            How JMESPath errors while computing grants are handled, by default ``ErrorSeverity.IGNORE`` .
+        max_search_result_size : Optional[int], optional
+            Max size of a JMESPath search result as a JSON string.
+            Larger results are handled with ``jmespath_error_severity`` . By default there is no limit.
+        grant_usage_recorder : Optional[GrantUsageRecorder], optional
+            Records the grants that decide or match requests, when grant usage is tracked.
+            Worker processes record grant usage with their own storage backend, without batching,
+            at the end of each task. Errors while recording are logged.
+        rng : Optional[Rng], optional
+            Entropy source for sampling, like canary grants. By default, the ``random`` module is used.
+            Each worker process gets its own copy.
         """
         super().initialize(
             identity_types=identity_types,
             jmespath_options=jmespath_options,
             resource_authzs=resource_authzs,
-            storage_backend=storage_backend
+            storage_backend=storage_backend,
+            jmespath_error_severity=jmespath_error_severity,
+            max_search_result_size=max_search_result_size,
+            grant_usage_recorder=grant_usage_recorder,
+            rng=rng
         )
         self._process_pool = ProcessPoolExecutor(
             max_workers=self._max_workers, 
@@ -100,7 +137,12 @@ class MultiprocessCompute(ComputeBackend):
                 storage_type=type(self._storage_backend),
                 storage_kwargs=self._storage_backend.kwargs,
                 initialize_kwargs=self._storage_backend.initialize_kwargs,
-                jmespath_options=jmespath_options
+                jmespath_options=jmespath_options,
+                jmespath_error_severity=jmespath_error_severity,
+                max_search_result_size=max_search_result_size,
+                track_grant_usage=grant_usage_recorder is not None,
+                clock=grant_usage_recorder.clock if grant_usage_recorder is not None else None,
+                rng=self._rng
             )
         )
         # Thread pool for converting pipe actions to async
@@ -124,7 +166,9 @@ class MultiprocessCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -145,6 +189,11 @@ class MultiprocessCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked by the worker processes after retrieving a page and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Not supported, ``Authzee`` does not allow ``RequestLimits.max_evaluation_cost`` with this backend.
 
         Returns
         -------
@@ -157,17 +206,22 @@ class MultiprocessCompute(ComputeBackend):
                 resource_type=resource_type,
                 resource_action=resource_action,
                 jmespath_data=jmespath_data,
-                page_size=page_size
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
             )
         )
 
 
+    @gc.compute_errors
     async def authorize_async(
         self, 
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -188,6 +242,11 @@ class MultiprocessCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked by the worker processes after retrieving a page and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Not supported, ``Authzee`` does not allow ``RequestLimits.max_evaluation_cost`` with this backend.
 
         Returns
         -------
@@ -196,18 +255,21 @@ class MultiprocessCompute(ComputeBackend):
         """ 
         loop = asyncio.get_running_loop()
         deny_futures: List[asyncio.Future] = []
-        next_page_ref = None
-        did_once = False
+        pager = WorkerPager(
+            storage_backend=self._storage_backend,
+            thread_pool=self._thread_pool,
+            effect=GrantEffect.DENY,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size
+        )
+        await pager.start()
         cancel_event = SharedMemEvent(smm=self._shared_mem_manager)
         while (
-            (
-                did_once is not True
-                or next_page_ref is not None
-            )
+            pager.has_next() is True
             and cancel_event.is_set() is False
         ):
-            did_once = True
-            recv_conn, send_conn = mp.Pipe(duplex=False)
+            next_page_ref, grant_uuids, send_conn = await pager.next_page()
             deny_futures.append(
                 loop.run_in_executor(
                     self._process_pool,
@@ -218,32 +280,33 @@ class MultiprocessCompute(ComputeBackend):
                         resource_action=resource_action,
                         page_size=page_size,
                         next_page_reference=next_page_ref,
+                        grant_uuids=grant_uuids,
                         jmespath_data=jmespath_data,
                         pipe_conn=send_conn,
-                        cancel_event=cancel_event
+                        cancel_event=cancel_event,
+                        deadline=deadline
                     )
                 )
             )
-            # wait for next page ref from child
-            next_page_ref = await loop.run_in_executor(
-                self._thread_pool,
-                recv_conn.recv
-            )
+            await pager.page_sent()
 
         allow_futures: List[asyncio.Future] = []
-        next_page_ref = None
-        did_once = False
+        pager = WorkerPager(
+            storage_backend=self._storage_backend,
+            thread_pool=self._thread_pool,
+            effect=GrantEffect.ALLOW,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size
+        )
+        await pager.start()
         allow_match_event = SharedMemEvent(smm=self._shared_mem_manager)
         while (
-            (
-                did_once is not True
-                or next_page_ref is not None
-            )
+            pager.has_next() is True
             and cancel_event.is_set() is False
             and allow_match_event.is_set() is False
         ):
-            did_once = True
-            recv_conn, send_conn = mp.Pipe(duplex=False)
+            next_page_ref, grant_uuids, send_conn = await pager.next_page()
             allow_futures.append(
                 loop.run_in_executor(
                     self._process_pool,
@@ -254,18 +317,16 @@ class MultiprocessCompute(ComputeBackend):
                         resource_action=resource_action,
                         page_size=page_size,
                         next_page_reference=next_page_ref,
+                        grant_uuids=grant_uuids,
                         jmespath_data=jmespath_data,
                         pipe_conn=send_conn,
                         cancel_event=cancel_event,
-                        allow_match_event=allow_match_event
+                        allow_match_event=allow_match_event,
+                        deadline=deadline
                     )
                 )
             )
-            # wait for next page ref from child
-            next_page_ref = await loop.run_in_executor(
-                self._thread_pool,
-                recv_conn.recv
-            )
+            await pager.page_sent()
         
         # If we found a deny then cleanup tasks and return False
         if cancel_event.is_set() is True:
@@ -311,7 +372,9 @@ class MultiprocessCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -332,6 +395,11 @@ class MultiprocessCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked by the worker processes after retrieving a page and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Not supported, ``Authzee`` does not allow ``RequestLimits.max_evaluation_cost`` with this backend.
 
         Returns
         -------
@@ -345,17 +413,22 @@ class MultiprocessCompute(ComputeBackend):
                 resource_type=resource_type,
                 resource_action=resource_action,
                 jmespath_data_entries=jmespath_data_entries,
-                page_size=page_size
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
             )
         ) 
 
 
+    @gc.compute_errors
     async def authorize_many_async(
         self, 
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -376,6 +449,11 @@ class MultiprocessCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked by the worker processes after retrieving a page and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Not supported, ``Authzee`` does not allow ``RequestLimits.max_evaluation_cost`` with this backend.
 
         Returns
         -------
@@ -386,14 +464,17 @@ class MultiprocessCompute(ComputeBackend):
         results = {i: None for i in range(len(jmespath_data_entries))}
         loop = asyncio.get_running_loop()
         deny_futures: List[asyncio.Future] = []
-        next_page_ref = None
-        did_once = False
-        while (
-            did_once is not True
-            or next_page_ref is not None
-        ):
-            did_once = True
-            recv_conn, send_conn = mp.Pipe(duplex=False)
+        pager = WorkerPager(
+            storage_backend=self._storage_backend,
+            thread_pool=self._thread_pool,
+            effect=GrantEffect.DENY,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size
+        )
+        await pager.start()
+        while pager.has_next() is True:
+            next_page_ref, grant_uuids, send_conn = await pager.next_page()
             deny_futures.append(
                 loop.run_in_executor(
                     self._process_pool,
@@ -404,26 +485,27 @@ class MultiprocessCompute(ComputeBackend):
                         resource_action=resource_action,
                         page_size=page_size,
                         next_page_reference=next_page_ref,
+                        grant_uuids=grant_uuids,
                         jmespath_data_entries=jmespath_data_entries,
-                        pipe_conn=send_conn
+                        pipe_conn=send_conn,
+                        deadline=deadline
                     )
                 )
             )
-            # wait for next page ref from child
-            next_page_ref = await loop.run_in_executor(
-                self._thread_pool,
-                recv_conn.recv
-            )
+            await pager.page_sent()
 
         allow_futures: List[asyncio.Future] = []
-        next_page_ref = None
-        did_once = False
-        while (
-            did_once is not True
-            or next_page_ref is not None
-        ):
-            did_once = True
-            recv_conn, send_conn = mp.Pipe(duplex=False)
+        pager = WorkerPager(
+            storage_backend=self._storage_backend,
+            thread_pool=self._thread_pool,
+            effect=GrantEffect.ALLOW,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size
+        )
+        await pager.start()
+        while pager.has_next() is True:
+            next_page_ref, grant_uuids, send_conn = await pager.next_page()
             allow_futures.append(
                 loop.run_in_executor(
                     self._process_pool,
@@ -434,16 +516,14 @@ class MultiprocessCompute(ComputeBackend):
                         resource_action=resource_action,
                         page_size=page_size,
                         next_page_reference=next_page_ref,
+                        grant_uuids=grant_uuids,
                         jmespath_data_entries=jmespath_data_entries,
-                        pipe_conn=send_conn
+                        pipe_conn=send_conn,
+                        deadline=deadline
                     )
                 )
             )
-            # wait for next page ref from child
-            next_page_ref = await loop.run_in_executor(
-                self._thread_pool,
-                recv_conn.recv
-            )
+            await pager.page_sent()
 
         if len(deny_futures) > 0:
             deny_results: List[List[bool]] = await asyncio.gather(*deny_futures)
@@ -456,7 +536,10 @@ class MultiprocessCompute(ComputeBackend):
             allow_results: List[List[bool]] = await asyncio.gather(*allow_futures)
             for result_set in allow_results:
                 for i, result in zip(results, result_set):
-                    if result is True:
+                    if (
+                        result is True
+                        and results[i] is None
+                    ):
                         results[i] = True
         
         return [val is True for val in list(results.values())]
@@ -516,6 +599,7 @@ class MultiprocessCompute(ComputeBackend):
         )
 
 
+    @gc.compute_errors
     async def get_matching_grants_page_async(
         self, 
         effect: GrantEffect,
@@ -612,27 +696,56 @@ def _executor_init(
     storage_type: Type[StorageBackend],
     storage_kwargs: Dict[str, Any],
     initialize_kwargs: Dict[str, Any],
-    jmespath_options: jmespath.Options
+    jmespath_options: jmespath.Options,
+    jmespath_error_severity: ErrorSeverity,
+    max_search_result_size: Optional[int],
+    track_grant_usage: bool,
+    clock: Optional[Clock],
+    rng: Rng
 ) -> None:
     global authzee_jmespath_options
     authzee_jmespath_options = jmespath_options
+    global authzee_jmespath_error_severity
+    global authzee_max_search_result_size
+    authzee_jmespath_error_severity = jmespath_error_severity
+    authzee_max_search_result_size = max_search_result_size
     global authzee_storage
     authzee_storage = storage_type(**storage_kwargs)
     authzee_storage.initialize(**initialize_kwargs)
+    global authzee_grant_usage_recorder
+    authzee_grant_usage_recorder = None
+    if track_grant_usage is True:
+        authzee_grant_usage_recorder = GrantUsageRecorder(storage_backend=authzee_storage, clock=clock)
+    global authzee_rng
+    authzee_rng = rng
 
 
-def _executor_grant_page_matches_deny(
+def _flush_worker_grant_usage() -> None:
+    """Write the grant usage recorded by the worker task.
+
+    Storage errors are logged, so recording grant usage never changes a decision.
+    """
+    global authzee_grant_usage_recorder
+    if authzee_grant_usage_recorder is not None:
+        authzee_grant_usage_recorder.flush_full()
+
+
+def _worker_grants_page(
     effect: GrantEffect,
     resource_type: Type[BaseModel],
     resource_action: ResourceAction,
     page_size: int,
     next_page_reference: Union[str, None],
-    jmespath_data: Dict[str, Any],
-    pipe_conn: Connection,
-    cancel_event: SharedMemEvent
-) -> bool:
-    global authzee_jmespath_options
+    grant_uuids: Optional[List[str]],
+    pipe_conn: Optional[Connection]
+) -> GrantsPage:
     global authzee_storage
+    if grant_uuids is not None:
+        return GrantsPage(
+            grants=authzee_storage.get_grants_by_uuids(effect=effect, uuids=grant_uuids),
+            next_page_reference=None
+        )
+
     raw_grants = authzee_storage.get_raw_grants_page(
         effect=effect,
         resource_type=resource_type,
@@ -641,22 +754,54 @@ def _executor_grant_page_matches_deny(
         next_page_reference=next_page_reference
     )
     # Send back next page ref to parent
-    pipe_conn.send(raw_grants.next_page_reference)
-    if cancel_event.is_set() is True:
-        return False
+    if pipe_conn is not None:
+        pipe_conn.send(raw_grants.next_page_reference)
+
+    return authzee_storage.normalize_raw_grants_page(raw_grants_page=raw_grants)
 
-    grants_page = authzee_storage.normalize_raw_grants_page(
-        raw_grants_page=raw_grants
+
+def _executor_grant_page_matches_deny(
+    effect: GrantEffect,
+    resource_type: Type[BaseModel],
+    resource_action: ResourceAction,
+    page_size: int,
+    next_page_reference: Union[str, None],
+    grant_uuids: Optional[List[str]],
+    jmespath_data: Dict[str, Any],
+    pipe_conn: Optional[Connection],
+    cancel_event: SharedMemEvent,
+    deadline: Optional[Deadline]
+) -> bool:
+    global authzee_jmespath_options
+    global authzee_jmespath_error_severity
+    global authzee_max_search_result_size
+    global authzee_grant_usage_recorder
+    global authzee_rng
+    grants_page = _worker_grants_page(
+        effect=effect,
+        resource_type=resource_type,
+        resource_action=resource_action,
+        page_size=page_size,
+        next_page_reference=next_page_reference,
+        grant_uuids=grant_uuids,
+        pipe_conn=pipe_conn
     )
+    check_deadline(deadline=deadline, phase="storage retrieval")
     if cancel_event.is_set() is True:
         return False
     
     for grant in grants_page.grants:
+        check_deadline(deadline=deadline, phase="evaluation")
         if gc.grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=authzee_jmespath_options
+            jmespath_options=authzee_jmespath_options,
+            jmespath_error_severity=authzee_jmespath_error_severity,
+            max_search_result_size=authzee_max_search_result_size,
+            rng=authzee_rng
         ) is True:
+            record_grant_usage(recorder=authzee_grant_usage_recorder, effect=GrantEffect.DENY, grant=grant)
+            _flush_worker_grant_usage()
             cancel_event.set()
             return True
 
@@ -672,36 +817,46 @@ def _executor_grant_page_matches_allow(
     resource_action: ResourceAction,
     page_size: int,
     next_page_reference: Union[str, None],
+    grant_uuids: Optional[List[str]],
     jmespath_data: Dict[str, Any],
-    pipe_conn: Connection,
+    pipe_conn: Optional[Connection],
     cancel_event: SharedMemEvent,
-    allow_match_event: SharedMemEvent
+    allow_match_event: SharedMemEvent,
+    deadline: Optional[Deadline]
 ) -> bool:
     global authzee_jmespath_options
-    global authzee_storage
-    raw_grants = authzee_storage.get_raw_grants_page(
+    global authzee_jmespath_error_severity
+    global authzee_max_search_result_size
+    global authzee_grant_usage_recorder
+    global authzee_rng
+    grants_page = _worker_grants_page(
         effect=effect,
         resource_type=resource_type,
         resource_action=resource_action,
         page_size=page_size,
-        next_page_reference=next_page_reference
+        next_page_reference=next_page_reference,
+        grant_uuids=grant_uuids,
+        pipe_conn=pipe_conn
     )
-    pipe_conn.send(raw_grants.next_page_reference)
+    check_deadline(deadline=deadline, phase="storage retrieval")
     if (
         cancel_event.is_set() is True
         or allow_match_event.is_set() is True
     ):
         return False
 
-    grants_page = authzee_storage.normalize_raw_grants_page(
-        raw_grants_page=raw_grants
-    )
     for grant in grants_page.grants:
+        check_deadline(deadline=deadline, phase="evaluation")
         if gc.grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=authzee_jmespath_options
+            jmespath_options=authzee_jmespath_options,
+            jmespath_error_severity=authzee_jmespath_error_severity,
+            max_search_result_size=authzee_max_search_result_size,
+            rng=authzee_rng
         ) is True:
+            record_grant_usage(recorder=authzee_grant_usage_recorder, effect=GrantEffect.ALLOW, grant=grant)
+            _flush_worker_grant_usage()
             allow_match_event.set()
             return True
 
@@ -720,26 +875,40 @@ def _executor_authorize_many(
     resource_action: ResourceAction,
     page_size: int,
     next_page_reference: Union[str, None],
+    grant_uuids: Optional[List[str]],
     jmespath_data_entries: List[Dict[str, Any]],
-    pipe_conn: Connection
+    pipe_conn: Optional[Connection],
+    deadline: Optional[Deadline]
 ) -> List[bool]:
-    global authzee_storage
+    global authzee_grant_usage_recorder
     global authzee_jmespath_options
-    raw_page = authzee_storage.get_raw_grants_page(
+    global authzee_jmespath_error_severity
+    global authzee_max_search_result_size
+    global authzee_rng
+    grants_page = _worker_grants_page(
         effect=effect,
         resource_type=resource_type,
         resource_action=resource_action,
         page_size=page_size,
-        next_page_reference=next_page_reference
+        next_page_reference=next_page_reference,
+        grant_uuids=grant_uuids,
+        pipe_conn=pipe_conn
     )
-    pipe_conn.send(raw_page.next_page_reference)
-    grants_page = authzee_storage.normalize_raw_grants_page(raw_grants_page=raw_page)
-
-    return gc.authorize_many_grants(
+    check_deadline(deadline=deadline, phase="storage retrieval")
+    results = gc.authorize_many_grants(
         grants_page=grants_page,
         jmespath_data_entries=jmespath_data_entries,
-        jmespath_options=authzee_jmespath_options
+        jmespath_options=authzee_jmespath_options,
+        deadline=deadline,
+        jmespath_error_severity=authzee_jmespath_error_severity,
+        max_search_result_size=authzee_max_search_result_size,
+        effect=effect,
+        grant_usage_recorder=authzee_grant_usage_recorder,
+        rng=authzee_rng
     )
+    _flush_worker_grant_usage()
+
+    return results
 
 
 def _executor_matching_grants(
@@ -753,6 +922,9 @@ def _executor_matching_grants(
 ) -> List[Grant]:
     global authzee_storage
     global authzee_jmespath_options
+    global authzee_jmespath_error_severity
+    global authzee_max_search_result_size
+    global authzee_rng
     raw_page = authzee_storage.get_raw_grants_page(
         effect=effect,
         resource_type=resource_type,
@@ -766,6 +938,9 @@ def _executor_matching_grants(
     return gc.compute_matching_grants(
         grants_page=grants_page,
         jmespath_data=jmespath_data,
-        jmespath_options=authzee_jmespath_options
+        jmespath_options=authzee_jmespath_options,
+        jmespath_error_severity=authzee_jmespath_error_severity,
+        max_search_result_size=authzee_max_search_result_size,
+        rng=authzee_rng
     )
 
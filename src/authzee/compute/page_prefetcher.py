@@ -0,0 +1,122 @@
+
+import queue
+import threading
+from typing import Generator, Optional, Type
+
+from pydantic import BaseModel
+
+from authzee.deadline import check_deadline, Deadline
+from authzee.grant_effect import GrantEffect
+from authzee.grants_page import GrantsPage
+from authzee.resource_action import ResourceAction
+from authzee.storage.storage_backend import StorageBackend
+
+
+_DONE = object()
+
+
+def prefetch_grants_pages(
+    storage_backend: StorageBackend,
+    effect: GrantEffect,
+    resource_type: Type[BaseModel],
+    resource_action: ResourceAction,
+    page_size: Optional[int] = None,
+    max_prefetched_pages: int = 1,
+    deadline: Optional[Deadline] = None
+) -> Generator[GrantsPage, None, None]:
+    """Iterate over grants pages, while the next pages are fetched from storage in a background thread.
+
+    At most ``max_prefetched_pages`` pages are held in memory ahead of the page that is being evaluated.
+    Fetching stops once the deadline has passed or the generator is closed,
+    ex. when a grant has matched before all pages were evaluated.
+
+    Parameters
+    ----------
+    storage_backend : StorageBackend
+        Storage backend to fetch pages from.
+    effect : GrantEffect
+        Effect of the grants to fetch.
+    resource_type : Type[BaseModel]
+        Resource type of the grants to fetch.
+    resource_action : ResourceAction
+        Resource action of the grants to fetch.
+    page_size : Optional[int], optional
+        The page size to use for the storage backend.
+        The default is set on the storage backend.
+    max_prefetched_pages : int, optional
+        Max number of pages fetched ahead of the current page, by default 1.
+    deadline : Optional[Deadline], optional
+        Stop fetching pages once the deadline has passed.
+
+    Yields
+    ------
+    GrantsPage
+        Normalized grants pages in storage order.
+    """
+    pages: queue.Queue = queue.Queue(maxsize=max_prefetched_pages)
+    stop = threading.Event()
+
+    def put(item) -> bool:
+        while stop.is_set() is False:
+            try:
+                pages.put(item, timeout=0.05)
+
+                return True
+            except queue.Full:
+                pass
+
+        return False
+
+    def fetch_pages() -> None:
+        next_page_ref = None
+        try:
+            while True:
+                if (
+                    stop.is_set() is True
+                    or (deadline is not None and deadline.expired() is True)
+                ):
+                    return
+
+                raw_grants_page = storage_backend.get_raw_grants_page(
+                    effect=effect,
+                    resource_type=resource_type,
+                    resource_action=resource_action,
+                    page_size=page_size,
+                    next_page_reference=next_page_ref
+                )
+                grants_page = storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
+                if put(grants_page) is False:
+                    return
+
+                next_page_ref = grants_page.next_page_reference
+                if next_page_ref is None:
+                    put(_DONE)
+
+                    return
+        except Exception as error:
+            put(error)
+
+    fetcher = threading.Thread(target=fetch_pages, daemon=True)
+    fetcher.start()
+    try:
+        while True:
+            timeout = None
+            if deadline is not None:
+                timeout = max(deadline.remaining(), 0.01)
+
+            try:
+                item = pages.get(timeout=timeout)
+            except queue.Empty:
+                check_deadline(deadline=deadline, phase="storage retrieval")
+
+                continue
+
+            if item is _DONE:
+                return
+
+            if isinstance(item, Exception):
+                raise item
+
+            yield item
+    finally:
+        stop.set()
@@ -0,0 +1,219 @@
+
+import time
+from typing import Any, Dict, List, Optional, Type, Union
+
+from pydantic import BaseModel
+
+from authzee import exceptions
+from authzee.backend_locality import BackendLocality
+from authzee.compute.compute_backend import ComputeBackend
+from authzee.deadline import check_deadline, Deadline
+from authzee.evaluation_budget import EvaluationBudget
+from authzee.grant_effect import GrantEffect
+from authzee.grants_page import GrantsPage
+from authzee.resource_action import ResourceAction
+
+
+class ScriptedComputeBackend(ComputeBackend):
+    """Compute backend for tests, that returns scripted decisions and pages instead of computing grants.
+
+    Use it to deterministically test code around ``Authzee`` without writing grants.
+    Scripted values are returned in order, and an ``Exception`` in a script is raised instead.
+    The JMESPath data of each call is kept in ``jmespath_data_calls`` for assertions.
+
+    Parameters
+    ----------
+    decisions : Optional[List[Union[bool, List[bool], Exception]]], optional
+        Results for ``authorize`` and ``authorize_many`` calls, in order.
+        A ``bool`` for ``authorize_many`` is used for every resource.
+        When the script runs out ``default_decision`` is used.
+    matching_pages : Optional[List[Union[GrantsPage, Exception]]], optional
+        Results for ``get_matching_grants_page`` calls, in order.
+        When the script runs out an empty last page is returned.
+    default_decision : bool, optional
+        Decision when ``decisions`` runs out, by default False.
+    latency : float, optional
+        Seconds to sleep on every call, before the deadline is checked, by default 0.
+    fail_on_call : Optional[int], optional
+        Raise ``error`` on this call number, starting at 1. By default calls don't fail.
+    error : Optional[Exception], optional
+        The error to raise on ``fail_on_call`` , by default an ``authzee.exceptions.ComputeBackendError`` like a lost worker.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import Authzee, MemoryStorage
+        from authzee.compute import ScriptedComputeBackend
+
+        authzee_app = Authzee(
+            compute_backend=ScriptedComputeBackend(decisions=[True, False]),
+            storage_backend=MemoryStorage(),
+            identity_types={ADUser},
+            resource_authz_types={BalloonAuthz}
+        )
+        authzee_app.initialize()
+        assert authzee_app.authorize(...) is True
+        assert authzee_app.authorize(...) is False
+
+    """
+
+    def __init__(
+        self,
+        decisions: Optional[List[Union[bool, List[bool], Exception]]] = None,
+        matching_pages: Optional[List[Union[GrantsPage, Exception]]] = None,
+        default_decision: bool = False,
+        latency: float = 0.0,
+        fail_on_call: Optional[int] = None,
+        error: Optional[Exception] = None
+    ):
+        super().__init__(
+            async_enabled=True,
+            backend_locality=BackendLocality.MAIN_PROCESS,
+            compatible_localities={
+                BackendLocality.MAIN_PROCESS,
+                BackendLocality.NETWORK,
+                BackendLocality.SYSTEM
+            }
+        )
+        self.decisions = list(decisions) if decisions is not None else []
+        self.matching_pages = list(matching_pages) if matching_pages is not None else []
+        self.default_decision = default_decision
+        self.latency = latency
+        self.fail_on_call = fail_on_call
+        self.error = error if error is not None else exceptions.ComputeBackendError("Injected compute failure.")
+        self.calls: List[str] = []
+        self.jmespath_data_calls: List[Any] = []
+
+
+    def authorize(
+        self, 
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
+    ) -> bool:
+        self._inject(call="authorize", jmespath_data=jmespath_data, deadline=deadline)
+        decision = self._next_decision()
+        if isinstance(decision, list):
+            return decision[0]
+
+        return decision
+
+
+    async def authorize_async(
+        self, 
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
+    ) -> bool:
+        return self.authorize(
+            resource_type=resource_type,
+            resource_action=resource_action,
+            jmespath_data=jmespath_data,
+            page_size=page_size,
+            deadline=deadline,
+            budget=budget
+        )
+
+
+    def authorize_many(
+        self, 
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data_entries: List[Dict[str, Any]],
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
+    ) -> List[bool]:
+        self._inject(call="authorize_many", jmespath_data=jmespath_data_entries, deadline=deadline)
+        decision = self._next_decision()
+        if isinstance(decision, list):
+            return decision
+
+        return [decision] * len(jmespath_data_entries)
+
+
+    async def authorize_many_async(
+        self, 
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data_entries: List[Dict[str, Any]],
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
+    ) -> List[bool]:
+        return self.authorize_many(
+            resource_type=resource_type,
+            resource_action=resource_action,
+            jmespath_data_entries=jmespath_data_entries,
+            page_size=page_size,
+            deadline=deadline,
+            budget=budget
+        )
+
+
+    def get_matching_grants_page(
+        self, 
+        effect: GrantEffect,
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantsPage:
+        self._inject(call="get_matching_grants_page", jmespath_data=jmespath_data)
+        if len(self.matching_pages) == 0:
+            return GrantsPage(grants=[], next_page_reference=None)
+
+        page = self.matching_pages.pop(0)
+        if isinstance(page, Exception):
+            raise page
+
+        return page
+
+
+    async def get_matching_grants_page_async(
+        self, 
+        effect: GrantEffect,
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        jmespath_data: Dict[str, Any],
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantsPage:
+        return self.get_matching_grants_page(
+            effect=effect,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            jmespath_data=jmespath_data,
+            page_size=page_size,
+            next_page_reference=next_page_reference
+        )
+
+
+    def _inject(self, call: str, jmespath_data: Any, deadline: Optional[Deadline] = None) -> None:
+        self.calls.append(call)
+        self.jmespath_data_calls.append(jmespath_data)
+        if self.latency > 0:
+            time.sleep(self.latency)
+
+        check_deadline(deadline=deadline, phase="evaluation")
+        if self.fail_on_call is not None and len(self.calls) == self.fail_on_call:
+            raise self.error
+
+
+    def _next_decision(self) -> Union[bool, List[bool]]:
+        if len(self.decisions) == 0:
+            return self.default_decision
+
+        decision = self.decisions.pop(0)
+        if isinstance(decision, Exception):
+            raise decision
+
+        return decision
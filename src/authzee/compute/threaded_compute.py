@@ -14,12 +14,17 @@ from pydantic import BaseModel
 from authzee.backend_locality import BackendLocality
 from authzee.compute import general as gc
 from authzee.compute.compute_backend import ComputeBackend
+from authzee.deadline import check_deadline, Deadline
+from authzee.error_severity import ErrorSeverity
+from authzee.evaluation_budget import EvaluationBudget, spend_budget
 from authzee.grant import Grant
 from authzee.grant_effect import GrantEffect
+from authzee.grant_usage_recorder import GrantUsageRecorder, record_grant_usage
 from authzee.grants_page import GrantsPage
 from authzee.raw_grants_page import RawGrantsPage
 from authzee.resource_action import ResourceAction
 from authzee.resource_authz import ResourceAuthz
+from authzee.rng import Rng
 from authzee.storage.storage_backend import StorageBackend 
 
 
@@ -72,6 +77,10 @@ class ThreadedCompute(ComputeBackend):
         jmespath_options: jmespath.Options,
         resource_authzs: List[ResourceAuthz],
         storage_backend: StorageBackend,
+        jmespath_error_severity: ErrorSeverity = ErrorSeverity.IGNORE,
+        max_search_result_size: Optional[int] = None,
+        grant_usage_recorder: Optional[GrantUsageRecorder] = None,
+        rng: Optional[Rng] = None
     ) -> None:
         """Initialize multiprocess backend.
 
@@ -87,12 +96,25 @@ class ThreadedCompute(ComputeBackend):
             ``ResourceAuthz`` s registered with the ``Authzee`` app.
         storage_backend : StorageBackend
             Storage backend registered with the ``Authzee`` app.
+        jmespath_error_severity : ErrorSeverity, optional
+            How JMESPath errors while computing grants are handled, by default ``ErrorSeverity.IGNORE`` .
+        max_search_result_size : Optional[int], optional
+            Max size of a JMESPath search result as a JSON string.
+            Larger results are handled with ``jmespath_error_severity`` . By default there is no limit.
+        grant_usage_recorder : Optional[GrantUsageRecorder], optional
+            Records the grants that decide or match requests, when grant usage is tracked.
+        rng : Optional[Rng], optional
+            Entropy source for sampling, like canary grants. By default, the ``random`` module is used.
         """
         super().initialize(
             identity_types=identity_types,
             jmespath_options=jmespath_options,
             resource_authzs=resource_authzs,
-            storage_backend=storage_backend
+            storage_backend=storage_backend,
+            jmespath_error_severity=jmespath_error_severity,
+            max_search_result_size=max_search_result_size,
+            grant_usage_recorder=grant_usage_recorder,
+            rng=rng
         )
         self._thread_pool = ThreadPoolExecutor(
             max_workers=self._max_workers,
@@ -114,7 +136,9 @@ class ThreadedCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -135,6 +159,12 @@ class ThreadedCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Spent for each grant evaluation.
 
         Returns
         -------
@@ -147,17 +177,22 @@ class ThreadedCompute(ComputeBackend):
                 resource_type=resource_type,
                 resource_action=resource_action,
                 jmespath_data=jmespath_data,
-                page_size=page_size
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
             )
         ) 
 
 
+    @gc.compute_errors
     async def authorize_async(
         self, 
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data: Dict[str, Any],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> bool:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -178,6 +213,12 @@ class ThreadedCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Spent for each grant evaluation.
 
         Returns
         -------
@@ -204,6 +245,8 @@ class ThreadedCompute(ComputeBackend):
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            next_page_ref = raw_grants_page.next_page_reference
+            check_deadline(deadline=deadline, phase="storage retrieval")
             deny_futures.append(
                 loop.run_in_executor(
                     self._thread_pool,
@@ -212,7 +255,13 @@ class ThreadedCompute(ComputeBackend):
                         storage_backend=self._storage_backend,
                         raw_grants_page=raw_grants_page,
                         jmespath_data=jmespath_data,
-                        cancel_event=cancel_event
+                        cancel_event=cancel_event,
+                        deadline=deadline,
+                        budget=budget,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        grant_usage_recorder=self._grant_usage_recorder,
+                        rng=self._rng
                     )
                 )
             )
@@ -238,6 +287,8 @@ class ThreadedCompute(ComputeBackend):
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            next_page_ref = raw_grants_page.next_page_reference
+            check_deadline(deadline=deadline, phase="storage retrieval")
             allow_futures.append(
                 loop.run_in_executor(
                     self._thread_pool,
@@ -247,7 +298,13 @@ class ThreadedCompute(ComputeBackend):
                         raw_grants_page=raw_grants_page,
                         jmespath_data=jmespath_data,
                         cancel_event=cancel_event,
-                        allow_match_event=allow_match_event
+                        allow_match_event=allow_match_event,
+                        deadline=deadline,
+                        budget=budget,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        grant_usage_recorder=self._grant_usage_recorder,
+                        rng=self._rng
                     )
                 )
             )
@@ -282,7 +339,9 @@ class ThreadedCompute(ComputeBackend):
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -303,6 +362,12 @@ class ThreadedCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Spent for each grant evaluation.
 
         Returns
         -------
@@ -316,17 +381,22 @@ class ThreadedCompute(ComputeBackend):
                 resource_type=resource_type,
                 resource_action=resource_action,
                 jmespath_data_entries=jmespath_data_entries,
-                page_size=page_size
+                page_size=page_size,
+                deadline=deadline,
+                budget=budget
             )
         ) 
 
 
+    @gc.compute_errors
     async def authorize_many_async(
         self, 
         resource_type: Type[BaseModel],
         resource_action: ResourceAction,
         jmespath_data_entries: List[Dict[str, Any]],
-        page_size: Optional[int] = None
+        page_size: Optional[int] = None,
+        deadline: Optional[Deadline] = None,
+        budget: Optional[EvaluationBudget] = None
     ) -> List[bool]:
         """Authorize a given resource and action, with the JMESPath data against stored grants.
 
@@ -347,6 +417,12 @@ class ThreadedCompute(ComputeBackend):
         page_size : Optional[int], optional
             The page size to use for the storage backend.
             The default is set on the storage backend.
+        deadline : Optional[Deadline], optional
+            Deadline for the call.
+            Checked between storage pages and between grants.
+        budget : Optional[EvaluationBudget], optional
+            Evaluation budget for the call.
+            Spent for each grant evaluation.
 
         Returns
         -------
@@ -371,6 +447,8 @@ class ThreadedCompute(ComputeBackend):
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            next_page_ref = raw_grants_page.next_page_reference
+            check_deadline(deadline=deadline, phase="storage retrieval")
             deny_futures.append(
                 loop.run_in_executor(
                     self._thread_pool,
@@ -378,7 +456,14 @@ class ThreadedCompute(ComputeBackend):
                         _executor_authorize_many,
                         storage_backend=self._storage_backend,
                         raw_grants_page=raw_grants_page,
-                        jmespath_data_entries=jmespath_data_entries
+                        jmespath_data_entries=jmespath_data_entries,
+                        deadline=deadline,
+                        budget=budget,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        effect=GrantEffect.DENY,
+                        grant_usage_recorder=self._grant_usage_recorder,
+                        rng=self._rng
                     )
                 )
             )
@@ -398,6 +483,8 @@ class ThreadedCompute(ComputeBackend):
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            next_page_ref = raw_grants_page.next_page_reference
+            check_deadline(deadline=deadline, phase="storage retrieval")
             allow_futures.append(
                 loop.run_in_executor(
                     self._thread_pool,
@@ -405,7 +492,14 @@ class ThreadedCompute(ComputeBackend):
                         _executor_authorize_many,
                         storage_backend=self._storage_backend,
                         raw_grants_page=raw_grants_page,
-                        jmespath_data_entries=jmespath_data_entries
+                        jmespath_data_entries=jmespath_data_entries,
+                        deadline=deadline,
+                        budget=budget,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        effect=GrantEffect.ALLOW,
+                        grant_usage_recorder=self._grant_usage_recorder,
+                        rng=self._rng
                     )
                 )
             )
@@ -421,7 +515,10 @@ class ThreadedCompute(ComputeBackend):
             allow_results: List[List[bool]] = await asyncio.gather(*allow_futures)
             for result_set in allow_results:
                 for i, result in zip(results, result_set):
-                    if result is True:
+                    if (
+                        result is True
+                        and results[i] is None
+                    ):
                         results[i] = True
         
         return [val is True for val in list(results.values())]
@@ -481,6 +578,7 @@ class ThreadedCompute(ComputeBackend):
         )
 
 
+    @gc.compute_errors
     async def get_matching_grants_page_async(
         self, 
         effect: GrantEffect,
@@ -524,23 +622,26 @@ class ThreadedCompute(ComputeBackend):
         """
         loop = asyncio.get_running_loop()
         futures: List[asyncio.Future] = []
-        next_page_ref = None
+        next_page_ref = next_page_reference
         did_once = False
         worker_num = 0
         while (
             worker_num < self._max_workers
-            and did_once is not True
-            or next_page_ref is not None
+            and (
+                did_once is not True
+                or next_page_ref is not None
+            )
         ):
             worker_num += 1
             did_once = True
             raw_grants_page = await self._storage_backend.get_raw_grants_page_async(
-                effect=GrantEffect.ALLOW,
+                effect=effect,
                 resource_type=resource_type,
                 resource_action=resource_action,
                 page_size=page_size,
                 next_page_reference=next_page_ref
             )
+            next_page_ref = raw_grants_page.next_page_reference
             futures.append(
                 loop.run_in_executor(
                     self._thread_pool,
@@ -548,7 +649,10 @@ class ThreadedCompute(ComputeBackend):
                         _executor_matching_grants,
                         storage_backend=self._storage_backend,
                         raw_grants_page=raw_grants_page,
-                        jmespath_data=jmespath_data
+                        jmespath_data=jmespath_data,
+                        jmespath_error_severity=self._jmespath_error_severity,
+                        max_search_result_size=self._max_search_result_size,
+                        rng=self._rng
                     )
                 )
             )
@@ -557,7 +661,7 @@ class ThreadedCompute(ComputeBackend):
         
         return GrantsPage(
             grants=[grant for grants_list in results for grant in grants_list],
-            next_page_reference=next_page_reference
+            next_page_reference=next_page_ref
         )
         
 
@@ -583,7 +687,13 @@ def _executor_authorize_deny(
     storage_backend: StorageBackend,
     raw_grants_page: RawGrantsPage,
     jmespath_data: Dict[str, Any],
-    cancel_event: Dict[str, bool]
+    cancel_event: Dict[str, bool],
+    deadline: Optional[Deadline],
+    budget: Optional[EvaluationBudget],
+    jmespath_error_severity: ErrorSeverity,
+    max_search_result_size: Optional[int],
+    grant_usage_recorder: Optional[GrantUsageRecorder],
+    rng: Rng
 ) -> bool:
     options_var = "authzee_jmespath_options_t_{}".format(
         threading.get_ident()
@@ -591,11 +701,17 @@ def _executor_authorize_deny(
     jmespath_options = globals()[options_var]
     grants_page = storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)    
     for grant in grants_page.grants:
+        check_deadline(deadline=deadline, phase="evaluation")
+        spend_budget(budget=budget, grant=grant)
         if gc.grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=jmespath_options
+            jmespath_options=jmespath_options,
+            jmespath_error_severity=jmespath_error_severity,
+            max_search_result_size=max_search_result_size,
+            rng=rng
         ) is True:
+            record_grant_usage(recorder=grant_usage_recorder, effect=GrantEffect.DENY, grant=grant)
             cancel_event['set'] = True
 
             return True
@@ -611,7 +727,13 @@ def _executor_authorize_allow(
     raw_grants_page: RawGrantsPage,
     jmespath_data: Dict[str, Any],
     cancel_event: Dict[str, bool],
-    allow_match_event: Dict[str, bool]
+    allow_match_event: Dict[str, bool],
+    deadline: Optional[Deadline],
+    budget: Optional[EvaluationBudget],
+    jmespath_error_severity: ErrorSeverity,
+    max_search_result_size: Optional[int],
+    grant_usage_recorder: Optional[GrantUsageRecorder],
+    rng: Rng
 ) -> bool:
     options_var = "authzee_jmespath_options_t_{}".format(
         threading.get_ident()
@@ -619,11 +741,17 @@ def _executor_authorize_allow(
     jmespath_options = globals()[options_var]
     grants_page = storage_backend.normalize_raw_grants_page(raw_grants_page=raw_grants_page)
     for grant in grants_page.grants:
+        check_deadline(deadline=deadline, phase="evaluation")
+        spend_budget(budget=budget, grant=grant)
         if gc.grant_matches(
             grant=grant,
             jmespath_data=jmespath_data,
-            jmespath_options=jmespath_options
+            jmespath_options=jmespath_options,
+            jmespath_error_severity=jmespath_error_severity,
+            max_search_result_size=max_search_result_size,
+            rng=rng
         ) is True:
+            record_grant_usage(recorder=grant_usage_recorder, effect=GrantEffect.ALLOW, grant=grant)
             allow_match_event['set'] = True
 
             return True
@@ -640,7 +768,14 @@ def _executor_authorize_allow(
 def _executor_authorize_many(
     storage_backend: StorageBackend,
     raw_grants_page: RawGrantsPage,
-    jmespath_data_entries: List[Dict[str, Any]]
+    jmespath_data_entries: List[Dict[str, Any]],
+    deadline: Optional[Deadline],
+    budget: Optional[EvaluationBudget],
+    jmespath_error_severity: ErrorSeverity,
+    max_search_result_size: Optional[int],
+    effect: GrantEffect,
+    grant_usage_recorder: Optional[GrantUsageRecorder],
+    rng: Rng
 ) -> List[bool]:
     options_var = "authzee_jmespath_options_t_{}".format(
         threading.get_ident()
@@ -651,14 +786,24 @@ def _executor_authorize_many(
     return gc.authorize_many_grants(
         grants_page=grants_page,
         jmespath_data_entries=jmespath_data_entries,
-        jmespath_options=jmespath_options
+        jmespath_options=jmespath_options,
+        deadline=deadline,
+        budget=budget,
+        jmespath_error_severity=jmespath_error_severity,
+        max_search_result_size=max_search_result_size,
+        effect=effect,
+        grant_usage_recorder=grant_usage_recorder,
+        rng=rng
     )
 
 
 def _executor_matching_grants(
     storage_backend: StorageBackend,
     raw_grants_page: RawGrantsPage,
-    jmespath_data: Dict[str, Any]
+    jmespath_data: Dict[str, Any],
+    jmespath_error_severity: ErrorSeverity,
+    max_search_result_size: Optional[int],
+    rng: Rng
 ) -> List[Grant]:
     options_var = "authzee_jmespath_options_t_{}".format(
         threading.get_ident()
@@ -669,5 +814,8 @@ def _executor_matching_grants(
     return gc.compute_matching_grants(
         grants_page=grants_page,
         jmespath_data=jmespath_data,
-        jmespath_options=jmespath_options
+        jmespath_options=jmespath_options,
+        jmespath_error_severity=jmespath_error_severity,
+        max_search_result_size=max_search_result_size,
+        rng=rng
     )
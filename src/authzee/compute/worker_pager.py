@@ -0,0 +1,126 @@
+
+import asyncio
+from concurrent.futures import ThreadPoolExecutor
+from functools import partial
+import multiprocessing as mp
+from multiprocessing.connection import Connection
+from typing import List, Optional, Tuple, Type
+
+from pydantic import BaseModel
+
+from authzee.grant_effect import GrantEffect
+from authzee.resource_action import ResourceAction
+from authzee.storage.storage_backend import StorageBackend
+
+
+class WorkerPager:
+    """Hands out pages of grants to worker processes.
+
+    How pages are handed out depends on the storage backend:
+
+        - ``supports_parallel_pagination`` - The page references are listed first, and every worker fetches its own page.
+        - ``supports_uuid_pages`` - Pages of grant UUIDs are fetched here, and workers fetch the grants by UUID.
+        - Otherwise a worker fetches its page and sends back the next page reference through a pipe,
+          before the next page is handed out.
+
+    Parameters
+    ----------
+    storage_backend : StorageBackend
+        Storage backend of the main process.
+    thread_pool : ThreadPoolExecutor
+        Thread pool for blocking storage and pipe calls.
+    effect : GrantEffect
+        Effect of the grants.
+    resource_type : Type[BaseModel]
+        Resource type of the grants.
+    resource_action : ResourceAction
+        Resource action of the grants.
+    page_size : Optional[int]
+        The page size to use for the storage backend.
+    """
+
+
+    def __init__(
+        self,
+        storage_backend: StorageBackend,
+        thread_pool: ThreadPoolExecutor,
+        effect: GrantEffect,
+        resource_type: Type[BaseModel],
+        resource_action: ResourceAction,
+        page_size: Optional[int]
+    ):
+        self._storage_backend = storage_backend
+        self._thread_pool = thread_pool
+        self._effect = effect
+        self._resource_type = resource_type
+        self._resource_action = resource_action
+        self._page_size = page_size
+        self._page_refs: Optional[List[Optional[str]]] = None
+        self._next_page_ref: Optional[str] = None
+        self._recv_conn: Optional[Connection] = None
+        self._did_once = False
+
+
+    async def start(self) -> None:
+        if self._storage_backend.supports_parallel_pagination is True:
+            # The first page is always requested with no reference
+            self._page_refs = (await self._call_storage("list_page_tokens"))[1:]
+
+
+    def has_next(self) -> bool:
+        return self._did_once is False or self._next_page_ref is not None
+
+
+    async def next_page(self) -> Tuple[Optional[str], Optional[List[str]], Optional[Connection]]:
+        """Get the next page to hand out.
+
+        Returns
+        -------
+        Tuple[Optional[str], Optional[List[str]], Optional[Connection]]
+            The next page reference, the grant UUIDs, and the pipe to send the next page reference back through.
+            Workers fetch the grants by UUID if UUIDs are given.
+        """
+        self._did_once = True
+        next_page_ref = self._next_page_ref
+        if self._page_refs is not None:
+            self._next_page_ref = self._page_refs.pop(0) if len(self._page_refs) > 0 else None
+
+            return next_page_ref, None, None
+
+        if self._storage_backend.supports_uuid_pages is True:
+            uuids_page = await self._call_storage("get_grant_uuids_page", next_page_reference=next_page_ref)
+            self._next_page_ref = uuids_page.next_page_reference
+
+            return None, uuids_page.uuids, None
+
+        self._recv_conn, send_conn = mp.Pipe(duplex=False)
+
+        return next_page_ref, None, send_conn
+
+
+    async def page_sent(self) -> None:
+        """Wait for the next page reference from the worker, if it is sent back through a pipe.
+        """
+        if self._recv_conn is not None:
+            self._next_page_ref = await asyncio.get_running_loop().run_in_executor(
+                self._thread_pool,
+                self._recv_conn.recv
+            )
+            self._recv_conn = None
+
+
+    async def _call_storage(self, method_name: str, **kwargs):
+        kwargs = {
+            "effect": self._effect,
+            "resource_type": self._resource_type,
+            "resource_action": self._resource_action,
+            "page_size": self._page_size,
+            **kwargs
+        }
+        if self._storage_backend.async_enabled is True:
+            return await getattr(self._storage_backend, "{}_async".format(method_name))(**kwargs)
+
+        return await asyncio.get_running_loop().run_in_executor(
+            self._thread_pool,
+            partial(getattr(self._storage_backend, method_name), **kwargs)
+        )
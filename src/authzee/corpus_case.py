@@ -0,0 +1,21 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+from authzee.authz_request import AuthzRequest
+
+
+class CorpusCase(BaseModel):
+    """A request with its expected decision, in a decision corpus for ``Authzee.run_corpus()`` .
+
+    - ``name`` - Optional name of the case, to find it in the report.
+    - ``request`` - The request to authorize.
+    - ``expected_decision`` - ``True`` if the request should be allowed, ``False`` if denied.
+
+    Corpora are stored as JSON with ``Authzee.corpus_to_json()`` and loaded with ``Authzee.corpus_from_json()`` .
+    """
+
+    name: Optional[str] = None
+    request: AuthzRequest
+    expected_decision: bool
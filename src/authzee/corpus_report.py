@@ -0,0 +1,56 @@
+
+import math
+from typing import List
+
+from pydantic import BaseModel
+
+
+class CorpusReport(BaseModel):
+    """Results of ``Authzee.run_corpus()`` .
+
+    - ``total_cases`` - Number of cases that were run.
+    - ``passed`` - Number of cases where the decision was the expected decision.
+    - ``failed_cases`` - Indexes of the cases where the decision was not the expected decision.
+    - ``decisions`` - The decision for each case, in order.
+    - ``mean_latency`` , ``p50_latency`` , ``p95_latency`` and ``max_latency`` - Seconds to authorize a case.
+    """
+
+    total_cases: int
+    passed: int
+    failed_cases: List[int]
+    decisions: List[bool]
+    mean_latency: float
+    p50_latency: float
+    p95_latency: float
+    max_latency: float
+
+
+    @property
+    def all_passed(self) -> bool:
+        """Every case had the expected decision.
+        """
+        return len(self.failed_cases) == 0
+
+
+def latency_percentile(latencies: List[float], percent: float) -> float:
+    """Nearest rank percentile of latencies.
+
+    Parameters
+    ----------
+    latencies : List[float]
+        Latencies in seconds.
+    percent : float
+        The percentile, from 0 to 100.
+
+    Returns
+    -------
+    float
+        The percentile, or ``0.0`` if there are no latencies.
+    """
+    if len(latencies) == 0:
+        return 0.0
+
+    sorted_latencies = sorted(latencies)
+    rank = max(math.ceil(percent / 100 * len(sorted_latencies)), 1)
+
+    return sorted_latencies[rank - 1]
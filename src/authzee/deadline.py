@@ -0,0 +1,93 @@
+
+from typing import Optional
+
+from authzee import exceptions
+from authzee.clock import Clock
+
+
+class Deadline:
+    """A point in time that an authorization call must finish by.
+
+    Compute backends check the deadline between storage pages and between grants,
+    and raise ``authzee.exceptions.DeadlineExceededError`` once it has passed.
+    The deadline is wall clock time so it can be shared with worker processes.
+
+    Parameters
+    ----------
+    timeout : float
+        Seconds from now until the deadline.
+    clock : Optional[Clock], optional
+        Time source for the deadline.
+        By default the system clock is used.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import Deadline
+
+        authorized = authzee_app.authorize(
+            resource=my_balloon,
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=identities,
+            deadline=Deadline(timeout=0.05)
+        )
+
+    """
+
+    def __init__(self, timeout: float, clock: Optional[Clock] = None):
+        self.clock = clock if clock is not None else Clock()
+        self.expires_at = self.clock.time() + timeout
+
+
+    def remaining(self) -> float:
+        """Seconds left until the deadline, or ``0`` if it has passed.
+        """
+        return max(self.expires_at - self.clock.time(), 0.0)
+
+
+    def expired(self) -> bool:
+        """``True`` if the deadline has passed.
+        """
+        return self.clock.time() >= self.expires_at
+
+
+    def check(self, phase: str) -> None:
+        """Raise if the deadline has passed.
+
+        Parameters
+        ----------
+        phase : str
+            The phase of the call that is being checked, used in the error message.
+
+        Raises
+        ------
+        authzee.exceptions.DeadlineExceededError
+            The deadline has passed.
+        """
+        if self.expired() is True:
+            raise exceptions.DeadlineExceededError(
+                "The deadline was exceeded during {}.".format(phase)
+            )
+
+
+def check_deadline(deadline: Optional[Deadline], phase: str) -> None:
+    """Raise if the optional ``deadline`` has passed.
+
+    Parameters
+    ----------
+    deadline : Optional[Deadline]
+        The deadline, or ``None`` for no deadline.
+    phase : str
+        The phase of the call that is being checked, used in the error message.
+
+    Raises
+    ------
+    authzee.exceptions.DeadlineExceededError
+        The deadline has passed.
+    """
+    if deadline is not None:
+        deadline.check(phase=phase)
+
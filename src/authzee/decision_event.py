@@ -0,0 +1,26 @@
+
+import datetime
+from typing import Any, Dict, List
+
+from pydantic import BaseModel
+
+
+class DecisionEvent(BaseModel):
+    """Metadata of an authorization decision, checked by ``AnomalyRule`` s.
+
+    - ``resource_type`` - Name of the resource type.
+    - ``resource`` - The canonical resource, without ``ResourceAuthz.hidden_resource_fields`` .
+    - ``resource_action`` - The resource action, like ``"BalloonAction.CreateBalloon"`` .
+    - ``identities`` - The request identities by identity type name.
+    - ``allowed`` - ``True`` if the request was authorized.
+    - ``overridden`` - ``True`` if the decision was forced by a ``DecisionOverride`` .
+    - ``timestamp`` - When the decision was made, in UTC.
+    """
+
+    resource_type: str
+    resource: Dict[str, Any]
+    resource_action: str
+    identities: Dict[str, List[Dict[str, Any]]]
+    allowed: bool
+    overridden: bool
+    timestamp: datetime.datetime
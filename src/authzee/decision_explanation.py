@@ -0,0 +1,23 @@
+
+from typing import List, Optional
+
+from pydantic import BaseModel
+
+from authzee.grant_explanation import GrantExplanation
+
+
+class DecisionExplanation(BaseModel):
+    """Why a request was allowed or denied, from ``Authzee.explain()`` .
+
+    - ``decision`` - ``True`` if the request was allowed, ``False`` if denied.
+    - ``override_decision`` - The decision forced by a ``DecisionOverride`` , or ``None`` .
+    - ``deciding_grant_uuid`` - UUID of the grant that decided the request, which may be an inherited grant of a parent resource.
+      ``None`` if no grant matched or an override or the ``FailPolicy`` decided the request.
+      ``None`` if no grant matched, so the request was implicitly denied.
+    - ``grants`` - How every grant for the resource type was evaluated, deny grants first.
+    """
+
+    decision: bool
+    override_decision: Optional[bool] = None
+    deciding_grant_uuid: Optional[str] = None
+    grants: List[GrantExplanation]
@@ -0,0 +1,73 @@
+
+import datetime
+from typing import Any, Dict, Optional, Set, Type
+
+from pydantic import BaseModel, validator
+
+from authzee.grant_effect import GrantEffect
+from authzee.resource_action import ResourceAction
+
+
+class DecisionOverride(BaseModel):
+    """Force a decision for an identity and resource pattern, before any grants are computed.
+
+    For emergency interventions and deterministic integration tests.
+    Overrides are held in memory by the ``Authzee`` app and are not stored with the storage backend.
+
+    - ``effect`` - ``GrantEffect.ALLOW`` to force allow, ``GrantEffect.DENY`` to force deny.
+    - ``reason`` - Why the override exists. Must not be empty.
+    - ``identity`` - The override applies when this identity is one of the request identities.
+    - ``resource_type`` - The override applies to resources of this type.
+    - ``resource_actions`` - The override applies to these actions.
+    - ``resource_match`` - Canonical resource fields and the values they must equal.
+      By default all resources of ``resource_type`` match.
+    - ``expires_at`` - The override is ignored at and after this time.
+
+    When more than one override applies, ``GrantEffect.DENY`` overrides win.
+    """
+
+    effect: GrantEffect
+    reason: str
+    identity: BaseModel
+    resource_type: Type[BaseModel]
+    resource_actions: Set[Any]
+    resource_match: Dict[str, Any] = {}
+    expires_at: datetime.datetime
+    uuid: Optional[str] = None
+
+
+    @validator("reason")
+    def validate_reason(cls, v):
+        if v.strip() == "":
+            raise ValueError("'reason' must not be empty")
+
+        return v
+
+
+    @validator("resource_actions")
+    def validate_actions(cls, v):
+        for value in v:
+            if isinstance(value, ResourceAction) != True:
+                raise ValueError("'resource_actions' must come from a child class of ResourceAction")
+
+        return v
+
+
+    def is_expired(self, now: Optional[datetime.datetime] = None) -> bool:
+        """``True`` if the override has expired.
+
+        Parameters
+        ----------
+        now : Optional[datetime.datetime], optional
+            Timezone aware current time, ex. from an ``authzee.Clock`` .
+            By default the system time is used.
+            Naive ``expires_at`` times are compared in local time.
+        """
+        if now is None:
+            now = datetime.datetime.now(datetime.timezone.utc)
+
+        expires_at = self.expires_at
+        if expires_at.tzinfo is None:
+            return now.astimezone().replace(tzinfo=None) >= expires_at
+
+        return now >= expires_at
@@ -0,0 +1,47 @@
+
+from collections import deque
+import datetime
+import json
+import threading
+from typing import Deque, Dict, Optional
+
+from authzee.anomaly_rule import AnomalyRule
+from authzee.decision_event import DecisionEvent
+
+
+class DenyBurstRule(AnomalyRule):
+    """Flag denies once the same identities are denied more than ``max_denies`` times within ``window`` seconds.
+
+    Parameters
+    ----------
+    max_denies : int
+        Max number of denies for the same identities within the window.
+    window : float
+        Length of the window in seconds.
+    """
+
+    def __init__(self, max_denies: int, window: float):
+        self.max_denies = max_denies
+        self.window = window
+        self._denies: Dict[str, Deque[datetime.datetime]] = {}
+        self._lock = threading.Lock()
+
+
+    def check(self, event: DecisionEvent) -> Optional[str]:
+        if event.allowed is True:
+            return None
+
+        identities_key = json.dumps(event.identities, sort_keys=True)
+        window_start = event.timestamp - datetime.timedelta(seconds=self.window)
+        with self._lock:
+            denies = self._denies.setdefault(identities_key, deque())
+            denies.append(event.timestamp)
+            while denies[0] < window_start:
+                denies.popleft()
+
+            deny_count = len(denies)
+
+        if deny_count > self.max_denies:
+            return "The identities were denied {} times in {} seconds.".format(deny_count, self.window)
+
+        return None
@@ -0,0 +1,22 @@
+
+__all__ = [
+    "allow_network_zones",
+    "allow_service_accounts",
+    "deny_noncompliant_devices",
+    "Device",
+    "environment_identities",
+    "NetworkZone",
+    "register_environment_identities",
+    "ServiceAccount"
+]
+
+from authzee.environment.device import Device
+from authzee.environment.network_zone import NetworkZone
+from authzee.environment.service_account import ServiceAccount
+from authzee.environment.environment_grants import (
+    allow_network_zones,
+    allow_service_accounts,
+    deny_noncompliant_devices,
+    environment_identities,
+    register_environment_identities
+)
@@ -0,0 +1,25 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class Device(BaseModel):
+    """Identity of the device a request is made from, with its posture.
+
+    - ``device_id`` - Unique ID of the device.
+    - ``platform`` - Optional platform, like ``"macos"`` or ``"android"`` .
+    - ``os_version`` - Optional operating system version.
+    - ``managed`` - The device is managed by the organization.
+    - ``compliant`` - The device meets the organization's posture policy, like disk encryption and patch level.
+    - ``posture_score`` - Optional posture score from a device management system.
+
+    Available in the grant JMESPath data under ``identities.Device`` .
+    """
+
+    device_id: str
+    platform: Optional[str] = None
+    os_version: Optional[str] = None
+    managed: bool = False
+    compliant: bool = False
+    posture_score: Optional[float] = None
@@ -0,0 +1,159 @@
+
+import json
+from typing import List, Optional, Set, Type
+
+from pydantic import BaseModel
+
+from authzee.authzee import Authzee
+from authzee.environment.device import Device
+from authzee.environment.network_zone import NetworkZone
+from authzee.environment.service_account import ServiceAccount
+from authzee.grant import Grant
+from authzee.grant_match_mode import GrantMatchMode
+from authzee.resource_action import ResourceAction
+
+
+def register_environment_identities(authzee_app: Authzee) -> None:
+    """Register the ``ServiceAccount`` , ``Device`` and ``NetworkZone`` identity types,
+    with at most one of each in an authorization request.
+
+    Parameters
+    ----------
+    authzee_app : Authzee
+        The app to register the identity types with.
+    """
+    for identity_type in (ServiceAccount, Device, NetworkZone):
+        authzee_app.register_identity_type(identity_type, max_count=1)
+
+
+def environment_identities(
+    service_account: Optional[ServiceAccount] = None,
+    device: Optional[Device] = None,
+    network_zone: Optional[NetworkZone] = None
+) -> List[BaseModel]:
+    """Build the environment identities of a request, to add to the other request identities.
+
+    Parameters
+    ----------
+    service_account : Optional[ServiceAccount], optional
+        The service account making the request.
+    device : Optional[Device], optional
+        The device the request is made from.
+    network_zone : Optional[NetworkZone], optional
+        The network the request is made from.
+
+    Returns
+    -------
+    List[BaseModel]
+        The given identities.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee.environment import Device, environment_identities, NetworkZone
+
+        authzee_app.authorize(
+            resource=my_balloon,
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=[user] + environment_identities(
+                device=Device(device_id="laptop-42", managed=True, compliant=True),
+                network_zone=NetworkZone(zone="corporate", trusted=True)
+            )
+        )
+
+    """
+    return [
+        identity
+        for identity in (service_account, device, network_zone)
+        if identity is not None
+    ]
+
+
+def deny_noncompliant_devices(resource_type: Type[BaseModel], resource_actions: Set[ResourceAction]) -> Grant:
+    """Example ``GrantEffect.DENY`` grant that denies requests without a compliant ``Device`` .
+
+    Parameters
+    ----------
+    resource_type : Type[BaseModel]
+        Resource type of the grant.
+    resource_actions : Set[ResourceAction]
+        Resource actions of the grant.
+
+    Returns
+    -------
+    Grant
+        The grant, to add with ``effect=GrantEffect.DENY`` .
+    """
+    return Grant(
+        name="Deny non-compliant devices",
+        description="Deny requests that are not made from a compliant device.",
+        resource_type=resource_type,
+        resource_actions=resource_actions,
+        jmespath_expression="length(identities.Device[?compliant]) == `0`",
+        result_match=True
+    )
+
+
+def allow_network_zones(
+    resource_type: Type[BaseModel],
+    resource_actions: Set[ResourceAction],
+    zones: List[str]
+) -> Grant:
+    """Example ``GrantEffect.ALLOW`` grant that allows requests from a ``NetworkZone`` in ``zones`` .
+
+    Parameters
+    ----------
+    resource_type : Type[BaseModel]
+        Resource type of the grant.
+    resource_actions : Set[ResourceAction]
+        Resource actions of the grant.
+    zones : List[str]
+        Names of the allowed network zones.
+
+    Returns
+    -------
+    Grant
+        The grant, to add with ``effect=GrantEffect.ALLOW`` .
+    """
+    return Grant(
+        name="Allow network zones",
+        description="Allow requests from the {} network zones.".format(", ".join(zones)),
+        resource_type=resource_type,
+        resource_actions=resource_actions,
+        jmespath_expression="identities.NetworkZone[?contains(`{}`, zone)]".format(json.dumps(zones)),
+        match_mode=GrantMatchMode.TRUTHY
+    )
+
+
+def allow_service_accounts(
+    resource_type: Type[BaseModel],
+    resource_actions: Set[ResourceAction],
+    names: List[str]
+) -> Grant:
+    """Example ``GrantEffect.ALLOW`` grant that allows requests from a ``ServiceAccount`` in ``names`` .
+
+    Parameters
+    ----------
+    resource_type : Type[BaseModel]
+        Resource type of the grant.
+    resource_actions : Set[ResourceAction]
+        Resource actions of the grant.
+    names : List[str]
+        Names of the allowed service accounts.
+
+    Returns
+    -------
+    Grant
+        The grant, to add with ``effect=GrantEffect.ALLOW`` .
+    """
+    return Grant(
+        name="Allow service accounts",
+        description="Allow requests from the {} service accounts.".format(", ".join(names)),
+        resource_type=resource_type,
+        resource_actions=resource_actions,
+        jmespath_expression="identities.ServiceAccount[?contains(`{}`, name)]".format(json.dumps(names)),
+        match_mode=GrantMatchMode.TRUTHY
+    )
@@ -0,0 +1,19 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class NetworkZone(BaseModel):
+    """Identity of the network a request is made from.
+
+    - ``zone`` - Name of the network zone, like ``"corporate"`` or ``"internet"`` .
+    - ``ip_address`` - Optional source IP address.
+    - ``trusted`` - The zone is trusted by the organization.
+
+    Available in the grant JMESPath data under ``identities.NetworkZone`` .
+    """
+
+    zone: str
+    ip_address: Optional[str] = None
+    trusted: bool = False
@@ -0,0 +1,19 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class ServiceAccount(BaseModel):
+    """Identity of a non-human service principal, like a workload or a CI job.
+
+    - ``name`` - Name of the service account.
+    - ``namespace`` - Optional namespace, like a cluster namespace or a cloud project.
+    - ``workload_id`` - Optional workload identity, like a SPIFFE ID.
+
+    Available in the grant JMESPath data under ``identities.ServiceAccount`` .
+    """
+
+    name: str
+    namespace: Optional[str] = None
+    workload_id: Optional[str] = None
@@ -0,0 +1,36 @@
+
+from enum import Enum
+
+
+class ErrorCode(Enum):
+    """Stable error codes for ``authzee`` exceptions.
+
+    Every ``authzee.exceptions.AuthzeeError`` has a ``code`` so callers can handle errors
+    programmatically instead of matching on messages.
+
+    - ``AZ0xxx`` - General errors.
+    - ``AZ1xxx`` - Registration and initialization errors.
+    - ``AZ2xxx`` - Input verification errors.
+    - ``AZ3xxx`` - Grant and storage errors.
+    - ``AZ4xxx`` - Backend errors.
+    """
+
+    AUTHZEE_ERROR = "AZ0001"
+    IDENTITY_REGISTRATION_ERROR = "AZ1001"
+    RESOURCE_AUTHZ_REGISTRATION_ERROR = "AZ1002"
+    INITIALIZATION_ERROR = "AZ1003"
+    INPUT_VERIFICATION_ERROR = "AZ2001"
+    REQUEST_LIMIT_ERROR = "AZ2002"
+    GRANT_DOES_NOT_EXIST_ERROR = "AZ3001"
+    GRANT_UUID_ERROR = "AZ3002"
+    GRANT_EVALUATION_ERROR = "AZ3003"
+    OVERRIDE_DOES_NOT_EXIST_ERROR = "AZ3004"
+    RELATION_DOES_NOT_EXIST_ERROR = "AZ3005"
+    ASYNC_NOT_AVAILABLE_ERROR = "AZ4001"
+    BACKEND_LOCALITY_INCOMPATIBILITY = "AZ4002"
+    METHOD_NOT_IMPLEMENTED_ERROR = "AZ4003"
+    DEADLINE_EXCEEDED_ERROR = "AZ4004"
+    EVALUATION_BUDGET_EXCEEDED_ERROR = "AZ4005"
+    STORAGE_BACKEND_ERROR = "AZ4006"
+    COMPUTE_BACKEND_ERROR = "AZ4007"
+
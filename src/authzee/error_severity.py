@@ -0,0 +1,13 @@
+
+from enum import Enum
+
+
+class ErrorSeverity(Enum):
+    """How an error while computing a grant is handled.
+
+    - ``IGNORE`` - The grant is treated as not matching and the error is logged at debug level.
+    - ``CRITICAL`` - The error is raised and the authorization call fails.
+    """
+
+    IGNORE = "IGNORE"
+    CRITICAL = "CRITICAL"
@@ -0,0 +1,97 @@
+
+from typing import Optional
+
+from authzee import exceptions
+from authzee.grant import Grant
+
+
+DATA_SIZE_UNIT = 1024
+
+
+class EvaluationBudget:
+    """Max cost of the grant evaluations for one authorization call.
+
+    Each time a grant is evaluated its cost is spent from the budget.
+    The cost of a grant is the length of its JMESPath expression,
+    times one more than the number of KiB of the request JMESPath data as JSON.
+    Compute backends raise ``authzee.exceptions.EvaluationBudgetExceededError`` once the budget is spent.
+
+    ``Authzee`` creates a budget for each call when ``RequestLimits.max_evaluation_cost`` is set,
+    and spends it for the grants of the resource and the inherited grants of parent resources.
+    Compute backends without ``supports_evaluation_budget`` , like ``MultiprocessCompute`` , can not be used with a budget.
+
+    Parameters
+    ----------
+    max_cost : int
+        The max total cost of grant evaluations.
+    data_size : int, optional
+        Size of the request JMESPath data as JSON, by default 0.
+    """
+
+    def __init__(self, max_cost: int, data_size: int = 0):
+        self.max_cost = max_cost
+        self.data_size = data_size
+        self.spent = 0
+
+
+    def grant_cost(self, grant: Grant) -> int:
+        """Cost of evaluating a grant once.
+
+        Parameters
+        ----------
+        grant : Grant
+            The grant.
+
+        Returns
+        -------
+        int
+            The cost.
+        """
+        return len(grant.jmespath_expression) * (1 + self.data_size // DATA_SIZE_UNIT)
+
+
+    def spend(self, grant: Grant, evaluations: int = 1) -> None:
+        """Spend the cost of evaluating a grant, and raise if the budget is spent.
+
+        Parameters
+        ----------
+        grant : Grant
+            The grant that is being evaluated.
+        evaluations : int, optional
+            Number of times the grant is evaluated, ex. once per resource for ``authorize_many`` , by default 1.
+
+        Raises
+        ------
+        authzee.exceptions.EvaluationBudgetExceededError
+            The budget is spent.
+        """
+        self.spent += self.grant_cost(grant=grant) * evaluations
+        if self.spent > self.max_cost:
+            raise exceptions.EvaluationBudgetExceededError(
+                "The evaluation cost {} is over the budget of {} at grant '{}'.".format(
+                    self.spent,
+                    self.max_cost,
+                    grant.uuid
+                )
+            )
+
+
+def spend_budget(budget: Optional[EvaluationBudget], grant: Grant, evaluations: int = 1) -> None:
+    """Spend the cost of evaluating a grant from the optional ``budget`` .
+
+    Parameters
+    ----------
+    budget : Optional[EvaluationBudget]
+        The budget, or ``None`` for no budget.
+    grant : Grant
+        The grant that is being evaluated.
+    evaluations : int, optional
+        Number of times the grant is evaluated, by default 1.
+
+    Raises
+    ------
+    authzee.exceptions.EvaluationBudgetExceededError
+        The budget is spent.
+    """
+    if budget is not None:
+        budget.spend(grant=grant, evaluations=evaluations)
@@ -0,0 +1,128 @@
+
+import datetime
+from typing import Dict, List
+
+from pydantic import BaseModel
+
+from authzee.authz_request import AuthzRequest
+from authzee.grant import Grant
+from authzee.grant_effect import GrantEffect
+from authzee.grant_index import GrantIndex
+from authzee.resource_action import ResourceAction
+
+
+class EvaluationSession:
+    """Evaluate a burst of requests against a consistent snapshot of the grants.
+
+    Create a session with ``Authzee.evaluation_session()`` .
+    All grants are retrieved from storage once when the session is created,
+    so every request in the session is decided with the same grants, even if grants are added or deleted in the meantime.
+    The grants are held in a ``GrantIndex`` , so each request only evaluates the grants for its resource type and action.
+    Requests are evaluated in the main process, regardless of the compute backend.
+
+    Overrides, relationship resolution, grant inheritance, the evaluation budget,
+    anomaly rules and grant usage tracking apply like ``Authzee.authorize()`` .
+
+    Parameters
+    ----------
+    authzee_app : Authzee
+        The app the session was created from.
+    grants : Dict[GrantEffect, List[Grant]]
+        The snapshot of the grants by effect.
+    created_at : datetime.datetime
+        When the snapshot was taken.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        session = authzee_app.evaluation_session()
+        decisions = session.authorize_many(requests=requests)
+
+    """
+
+
+    def __init__(
+        self,
+        authzee_app: "Authzee",
+        grants: Dict[GrantEffect, List[Grant]],
+        created_at: datetime.datetime
+    ):
+        self._authzee_app = authzee_app
+        self._grant_indexes = {effect: GrantIndex(grants=effect_grants) for effect, effect_grants in grants.items()}
+        self.created_at = created_at
+
+
+    @property
+    def grant_count(self) -> int:
+        """Number of grants in the snapshot.
+        """
+        return sum(len(grant_index) for grant_index in self._grant_indexes.values())
+
+
+    def authorize(
+        self,
+        resource: BaseModel,
+        resource_action: ResourceAction,
+        parent_resources: List[BaseModel],
+        child_resources: List[BaseModel],
+        identities: List[BaseModel]
+    ) -> bool:
+        """Authorize a request against the snapshot of the grants.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource model to authorize against.
+        resource_action : ResourceAction
+            The resource action to authorize against.
+        parent_resources : List[BaseModel]
+            The resource's parent resource models to authorize against.
+        child_resources : List[BaseModel]
+            The resource's child resource models to authorize against.
+        identities : List[BaseModel]
+            The entities identities to authorize.
+
+        Returns
+        -------
+        bool
+            ``True`` if authorized, ``False`` if denied.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs were not verified with the ``Authzee`` configuration.
+        """
+        request = AuthzRequest(
+            resource=resource,
+            resource_action=resource_action,
+            parent_resources=parent_resources,
+            child_resources=child_resources,
+            identities=identities
+        )
+
+        return self._authzee_app._authorize_with_grants(request=request, grant_indexes=self._grant_indexes)
+
+
+    def authorize_many(self, requests: List[AuthzRequest]) -> List[bool]:
+        """Authorize many requests against the snapshot of the grants.
+
+        Parameters
+        ----------
+        requests : List[AuthzRequest]
+            The requests to authorize.
+
+        Returns
+        -------
+        List[bool]
+            Decisions in the order of ``requests`` . ``True`` if authorized, ``False`` if denied.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The inputs of a request were not verified with the ``Authzee`` configuration.
+        """
+        return [
+            self._authzee_app._authorize_with_grants(request=request, grant_indexes=self._grant_indexes)
+            for request in requests
+        ]
@@ -1,17 +1,21 @@
 """Module for Authzee Exceptions
 """
 
+from authzee.error_code import ErrorCode
+
 
 class AuthzeeError(Exception):
     """Base Authzee Exception.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.AUTHZEE_ERROR
 
 
 class AsyncNotAvailableError(AuthzeeError):
     """Async is not available in a class or method
     """
-    pass
+
+    code: ErrorCode = ErrorCode.ASYNC_NOT_AVAILABLE_ERROR
 
 
 class BackendLocalityIncompatibility(AuthzeeError):
@@ -19,50 +23,126 @@ class BackendLocalityIncompatibility(AuthzeeError):
 
     See ``authzee.backend_locality.BackendLocality`` for more info.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.BACKEND_LOCALITY_INCOMPATIBILITY
+
+
+class ComputeBackendError(AuthzeeError):
+    """The compute backend failed, like when a worker process or pipe is lost.
+
+    Decided by the ``FailPolicy.compute_failure`` in authorization calls.
+    """
+
+    code: ErrorCode = ErrorCode.COMPUTE_BACKEND_ERROR
+
+
+class DeadlineExceededError(AuthzeeError):
+    """The deadline for an authorization call passed before it finished.
+
+    The call did not decide, which is not the same as a deny.
+    """
+
+    code: ErrorCode = ErrorCode.DEADLINE_EXCEEDED_ERROR
+
+
+class EvaluationBudgetExceededError(AuthzeeError):
+    """The grant evaluations of an authorization call cost more than the ``RequestLimits.max_evaluation_cost`` .
+
+    The call did not decide, which is not the same as a deny.
+    """
+
+    code: ErrorCode = ErrorCode.EVALUATION_BUDGET_EXCEEDED_ERROR
 
 
 class GrantDoesNotExistError(AuthzeeError):
     """The Grant Does not exist.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.GRANT_DOES_NOT_EXIST_ERROR
+
+
+class GrantEvaluationError(AuthzeeError):
+    """There was an error when computing a grant's JMESPath expression.
+
+    Only raised when the JMESPath error severity is ``ErrorSeverity.CRITICAL`` .
+    """
+
+    code: ErrorCode = ErrorCode.GRANT_EVALUATION_ERROR
 
 
 class GrantUUIDError(AuthzeeError):
     """There was an error associated with a grant UUID
     """
-    pass
+
+    code: ErrorCode = ErrorCode.GRANT_UUID_ERROR
 
 
 class IdentityRegistrationError(AuthzeeError):
     """There was an error when registering the Identity Type.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.IDENTITY_REGISTRATION_ERROR
 
 
 class InitializationError(AuthzeeError):
     """There was an error during initialization of the Authzee App.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.INITIALIZATION_ERROR
 
 
 class InputVerificationError(AuthzeeError):
     """The given inputs could not be verified.
     """
-    pass
+
+    code: ErrorCode = ErrorCode.INPUT_VERIFICATION_ERROR
 
 
 class MethodNotImplementedError(AuthzeeError):
     """The given method is not implemented for this class.
     """
 
+    code: ErrorCode = ErrorCode.METHOD_NOT_IMPLEMENTED_ERROR
+
+
     def __init__(self, msg: str = "This method is not implemented.", *args, **kwargs):
         super().__init__(msg, *args, **kwargs)
 
 
+class OverrideDoesNotExistError(AuthzeeError):
+    """The decision override does not exist.
+    """
+
+    code: ErrorCode = ErrorCode.OVERRIDE_DOES_NOT_EXIST_ERROR
+
+
+class RelationDoesNotExistError(AuthzeeError):
+    """The relation tuple does not exist.
+    """
+
+    code: ErrorCode = ErrorCode.RELATION_DOES_NOT_EXIST_ERROR
+
+
+class RequestLimitError(InputVerificationError):
+    """The authorization request is over the configured ``RequestLimits`` .
+    """
+
+    code: ErrorCode = ErrorCode.REQUEST_LIMIT_ERROR
+
+
 class ResourceAuthzRegistrationError(AuthzeeError):
     """There was an error when registering the ResourceAuthz.
     """
-    pass
 
+    code: ErrorCode = ErrorCode.RESOURCE_AUTHZ_REGISTRATION_ERROR
+
+
+class StorageBackendError(AuthzeeError):
+    """The storage backend failed, like when storage is unavailable.
+
+    Storage backends raise it instead of the errors of their storage clients.
+    Decided by the ``FailPolicy.storage_failure`` in authorization calls.
+    """
+
+    code: ErrorCode = ErrorCode.STORAGE_BACKEND_ERROR
 
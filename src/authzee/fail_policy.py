@@ -0,0 +1,42 @@
+
+from typing import Set
+
+from pydantic import BaseModel
+
+from authzee.failure_decision import FailureDecision
+
+
+class FailPolicy(BaseModel):
+    """How infrastructure failures in ``authorize`` and ``authorize_many`` map to decisions.
+
+    Only ``authzee.exceptions.StorageBackendError`` s and ``authzee.exceptions.ComputeBackendError`` s
+    from the backends are covered.
+    Other errors, like input verification errors, exceeded deadlines and programming errors, are always raised.
+    By default all failures are raised.
+
+    - ``storage_failure`` - Decision when the storage backend fails, like when storage is unavailable.
+      See ``authzee.exceptions.StorageBackendError`` .
+    - ``compute_failure`` - Decision when a compute worker is lost, like a broken process pool or pipe.
+      See ``authzee.exceptions.ComputeBackendError`` .
+    - ``low_risk_actions`` - Full names of actions that are allowed with ``FailureDecision.ALLOW_LOW_RISK`` ,
+      like ``"BalloonAction.ReadBalloon"`` .
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import AuthzeeConfig, FailPolicy, FailureDecision
+
+        config = AuthzeeConfig(
+            fail_policy=FailPolicy(
+                storage_failure=FailureDecision.ALLOW_LOW_RISK,
+                compute_failure=FailureDecision.DENY,
+                low_risk_actions={str(BalloonAction.ReadBalloon)}
+            )
+        )
+
+    """
+
+    storage_failure: FailureDecision = FailureDecision.RAISE
+    compute_failure: FailureDecision = FailureDecision.RAISE
+    low_risk_actions: Set[str] = set()
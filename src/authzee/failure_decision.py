@@ -0,0 +1,16 @@
+
+from enum import Enum
+
+
+class FailureDecision(Enum):
+    """How an infrastructure failure during an authorization call is decided.
+
+    - ``RAISE`` - The error is raised and the authorization call fails.
+    - ``DENY`` - The request is denied (fail closed).
+    - ``ALLOW_LOW_RISK`` - The request is allowed if the action is one of the ``FailPolicy.low_risk_actions`` ,
+      otherwise it is denied.
+    """
+
+    RAISE = "RAISE"
+    DENY = "DENY"
+    ALLOW_LOW_RISK = "ALLOW_LOW_RISK"
@@ -0,0 +1,60 @@
+
+import datetime
+
+from authzee.clock import Clock
+
+
+class FixedClock(Clock):
+    """Clock that only moves when it is told to, for deterministic tests and replay runs.
+
+    Parameters
+    ----------
+    now : datetime.datetime
+        The starting time. Naive times are treated as UTC.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import FixedClock
+
+        clock = FixedClock(now=datetime.datetime(2024, 1, 1, tzinfo=datetime.timezone.utc))
+        authzee_app = Authzee(compute_backend=compute, storage_backend=storage, clock=clock)
+        clock.advance(seconds=3600)
+
+    """
+
+    def __init__(self, now: datetime.datetime):
+        if now.tzinfo is None:
+            now = now.replace(tzinfo=datetime.timezone.utc)
+
+        self._now = now
+
+
+    def now(self) -> datetime.datetime:
+        return self._now
+
+
+    def set(self, now: datetime.datetime) -> None:
+        """Set the current time.
+
+        Parameters
+        ----------
+        now : datetime.datetime
+            The new time. Naive times are treated as UTC.
+        """
+        if now.tzinfo is None:
+            now = now.replace(tzinfo=datetime.timezone.utc)
+
+        self._now = now
+
+
+    def advance(self, seconds: float) -> None:
+        """Move the current time forward.
+
+        Parameters
+        ----------
+        seconds : float
+            Seconds to move forward.
+        """
+        self._now += datetime.timedelta(seconds=seconds)
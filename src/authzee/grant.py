@@ -1,8 +1,17 @@
 
-from typing import Any, Optional, Set, Type, Union
+import datetime
+import hashlib
+import json
+import math
+from typing import Any, Dict, List, Optional, Set, Type, Union
 
-from pydantic import BaseModel, validator
+import jmespath
+import jmespath.exceptions
+from pydantic import BaseModel, root_validator, validator
 
+from authzee.grant_match_mode import GrantMatchMode
+from authzee.grant_provenance import GrantProvenance
+from authzee.grant_sort import GrantSort
 from authzee.resource_action import ResourceAction
 
 
@@ -10,6 +19,27 @@ class Grant(BaseModel):
     """Model for creating a grant.
     
     fill in the model
+
+    Comparison options for the JMESPath result and ``result_match`` :
+
+    - ``order_insensitive_arrays`` - Arrays are equal if they have the same elements in any order.
+    - ``numeric_tolerance`` - Numbers are equal if they are within this absolute tolerance. It must be finite and not negative.
+    - ``match_mode`` - How the result is compared to ``result_match`` . See ``authzee.GrantMatchMode`` .
+      ``result_match`` is not used with ``GrantMatchMode.TRUTHY`` , so it may be left out and must be ``None`` .
+
+    With ``AuthzeeConfig.grant_inheritance`` enabled, ``applies_to_descendants`` grants also apply to
+    requests for child resources of the ``resource_type`` . See ``ResourceAuthz.inherited_actions`` .
+
+    ``provenance`` optionally records where the grant came from. See ``authzee.GrantProvenance`` .
+
+    ``tags`` are optional key value labels for the grant, like an owner or a ticket, to find and audit grants.
+
+    ``created_at`` is when the grant was added. ``Authzee.add_grant`` sets it from the ``Clock`` when it is not given.
+
+    ``canary_sample_rate`` makes the grant a canary. Canary grants never affect decisions.
+    They are evaluated for this fraction of the requests, from ``0.0`` to ``1.0`` ,
+    and whether they matched is logged, to try a new grant on live traffic before it is promoted.
+    Promote a canary grant by adding it again without ``canary_sample_rate`` .
     """
 
     name: str
@@ -18,10 +48,29 @@ class Grant(BaseModel):
     resource_actions: Set[Any] 
     jmespath_expression: str
     result_match: Union[bool, dict, float, int, list, None, str] # store as json string
+    order_insensitive_arrays: bool = False
+    numeric_tolerance: Optional[float] = None
+    match_mode: GrantMatchMode = GrantMatchMode.EQUAL
+    applies_to_descendants: bool = False
+    provenance: Optional[GrantProvenance] = None
+    tags: Dict[str, str] = {}
+    canary_sample_rate: Optional[float] = None
+    created_at: Optional[datetime.datetime] = None
     storage_id: Optional[str] = None # Leave as a string so storage can decide what it wants
     uuid: Optional[str] = None
 
 
+    @root_validator(pre=True)
+    def default_truthy_result_match(cls, values):
+        if (
+            "result_match" not in values
+            and values.get("match_mode") in (GrantMatchMode.TRUTHY, GrantMatchMode.TRUTHY.value)
+        ):
+            values = {**values, "result_match": None}
+
+        return values
+
+
     @validator("resource_actions")
     def validate_actions(cls, v):
         for value in v:
@@ -29,5 +78,207 @@ class Grant(BaseModel):
                 raise ValueError("'resource_actions' must come from a child class of ResourceAction")
 
         return v
-    
 
+
+    @validator("numeric_tolerance")
+    def validate_numeric_tolerance(cls, v):
+        if v is not None and (math.isfinite(v) is False or v < 0.0):
+            raise ValueError("'numeric_tolerance' must be a finite number that is not negative")
+
+        return v
+
+
+    @validator("canary_sample_rate")
+    def validate_canary_sample_rate(cls, v):
+        if v is not None and (v < 0.0 or v > 1.0):
+            raise ValueError("'canary_sample_rate' must be from 0.0 to 1.0")
+
+        return v
+
+
+    @validator("match_mode")
+    def validate_match_mode(cls, v, values):
+        if (
+            v is GrantMatchMode.ONE_OF
+            and isinstance(values.get("result_match"), list) is False
+        ):
+            raise ValueError("'result_match' must be a list when 'match_mode' is ONE_OF")
+
+        if (
+            v is GrantMatchMode.TRUTHY
+            and values.get("result_match") is not None
+        ):
+            raise ValueError("'result_match' must be None when 'match_mode' is TRUTHY, because it is not used")
+
+        return v
+
+
+    def canonical_hash(self) -> str:
+        """Hash of the parts of the grant that decide what it matches.
+
+        The hash is stable over whitespace in the JMESPath expression, the order of resource actions,
+        and the key order of ``result_match`` .
+        ``name`` , ``description`` , ``provenance`` , ``tags`` , ``created_at`` , ``storage_id`` , and ``uuid`` are not included.
+
+        Returns
+        -------
+        str
+            SHA-256 hex digest of the canonical grant.
+        """
+        return self._canonical_hash(include_actions=True)
+
+
+    def _canonical_hash(self, include_actions: bool) -> str:
+        try:
+            expression = jmespath.compile(self.jmespath_expression).parsed
+        except jmespath.exceptions.JMESPathError:
+            expression = " ".join(self.jmespath_expression.split())
+
+        canonical = {
+            "resource_type": "{}.{}".format(self.resource_type.__module__, self.resource_type.__qualname__),
+            "resource_actions": sorted(str(action) for action in self.resource_actions) if include_actions is True else None,
+            "jmespath_expression": expression,
+            "result_match": self.result_match,
+            "order_insensitive_arrays": self.order_insensitive_arrays,
+            "numeric_tolerance": self.numeric_tolerance,
+            "match_mode": self.match_mode.value,
+            "applies_to_descendants": self.applies_to_descendants,
+            "canary_sample_rate": self.canary_sample_rate
+        }
+
+        return hashlib.sha256(
+            json.dumps(canonical, sort_keys=True, separators=(",", ":")).encode()
+        ).hexdigest()
+
+
+def dedupe_grants(grants: List[Grant]) -> List[Grant]:
+    """Remove grants that are semantically the same as an earlier grant in the list.
+
+    Grants are compared with ``Grant.canonical_hash()`` .
+
+    Parameters
+    ----------
+    grants : List[Grant]
+        Grants to dedupe. They should all have the same effect.
+
+    Returns
+    -------
+    List[Grant]
+        The first grant for each canonical hash, in the original order.
+    """
+    seen_hashes: Set[str] = set()
+    unique_grants: List[Grant] = []
+    for grant in grants:
+        grant_hash = grant.canonical_hash()
+        if grant_hash not in seen_hashes:
+            seen_hashes.add(grant_hash)
+            unique_grants.append(grant)
+
+    return unique_grants
+
+
+def sort_grants(grants: List[Grant], sort_by: GrantSort, descending: bool = False) -> List[Grant]:
+    """Sort grants.
+
+    The sort is stable, so grants that are equal for ``sort_by`` keep their original order.
+    Grants without ``created_at`` are last when sorting by ``GrantSort.CREATED`` , in both orders.
+
+    Parameters
+    ----------
+    grants : List[Grant]
+        Grants to sort.
+    sort_by : GrantSort
+        How to sort the grants.
+    descending : bool, optional
+        Reverse the order, by default False.
+
+    Returns
+    -------
+    List[Grant]
+        The sorted grants.
+    """
+    if sort_by is GrantSort.SPECIFICITY:
+        key = lambda grant: (len(grant.resource_actions), grant.name)
+    elif sort_by is GrantSort.CREATED:
+        dated_grants = [grant for grant in grants if grant.created_at is not None]
+        undated_grants = [grant for grant in grants if grant.created_at is None]
+        dated_key = lambda grant: (grant.created_at.timestamp(), grant.name)
+        undated_key = lambda grant: grant.name
+
+        return (
+            sorted(dated_grants, key=dated_key, reverse=descending)
+            + sorted(undated_grants, key=undated_key, reverse=descending)
+        )
+    else:
+        key = lambda grant: (grant.name, grant.uuid or "")
+
+    return sorted(grants, key=key, reverse=descending)
+
+
+def compact_grants(grants: List[Grant]) -> "GrantCompaction":
+    """Merge grants that are semantically the same except for their resource actions.
+
+    Grants are only merged when they also have the same ``tags`` and ``provenance`` , so no metadata is lost.
+    Merged grants have the union of the resource actions, and the name and description of the first grant.
+    They do not have a UUID or storage ID, so they can be added in place of the original grants.
+    Grants that are not merged are returned unchanged.
+
+    Parameters
+    ----------
+    grants : List[Grant]
+        Grants to compact. They should all have the same effect.
+
+    Returns
+    -------
+    GrantCompaction
+        The compacted grants, in the order of the first grant of each, and the reduction.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee.grant import compact_grants
+
+        compaction = compact_grants(list(authzee_app.list_grants(effect=GrantEffect.ALLOW)))
+        print(compaction.original_count - compaction.compacted_count)
+
+    """
+    from authzee.grant_compaction import GrantCompaction
+    merged: Dict[str, List[Grant]] = {}
+    for grant in grants:
+        merge_key = "{}:{}:{}".format(
+            grant._canonical_hash(include_actions=False),
+            json.dumps(grant.tags, sort_keys=True),
+            grant.provenance.json() if grant.provenance is not None else None
+        )
+        merged.setdefault(merge_key, []).append(grant)
+
+    compacted_grants: List[Grant] = []
+    merged_uuids: List[List[str]] = []
+    for same_grants in merged.values():
+        if len(same_grants) == 1:
+            compacted_grants.append(same_grants[0])
+            continue
+
+        resource_actions = set()
+        for grant in same_grants:
+            resource_actions.update(grant.resource_actions)
+
+        compacted_grants.append(
+            same_grants[0].model_copy(
+                update={
+                    "resource_actions": resource_actions,
+                    "storage_id": None,
+                    "uuid": None
+                },
+                deep=True
+            )
+        )
+        merged_uuids.append([grant.uuid for grant in same_grants if grant.uuid is not None])
+
+    return GrantCompaction(
+        grants=compacted_grants,
+        original_count=len(grants),
+        compacted_count=len(compacted_grants),
+        merged_uuids=merged_uuids
+    )
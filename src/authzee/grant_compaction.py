@@ -0,0 +1,21 @@
+
+from typing import List
+
+from pydantic import BaseModel
+
+from authzee.grant import Grant
+
+
+class GrantCompaction(BaseModel):
+    """The result of merging grants that only differ by their resource actions.
+
+    - ``grants`` - The compacted grants.
+    - ``original_count`` - Number of grants before compaction.
+    - ``compacted_count`` - Number of grants after compaction.
+    - ``merged_uuids`` - UUIDs of the original grants for each merged grant, to delete once the merged grant is added.
+    """
+
+    grants: List[Grant]
+    original_count: int
+    compacted_count: int
+    merged_uuids: List[List[str]]
@@ -0,0 +1,30 @@
+
+from typing import Any, Dict, Optional
+
+from pydantic import BaseModel
+
+
+class GrantExplanation(BaseModel):
+    """How a grant was evaluated for a request, from ``Authzee.explain()`` .
+
+    - ``effect`` - The ``GrantEffect`` value of the grant.
+    - ``name`` , ``uuid`` and ``tags`` - Identify the grant.
+    - ``action_matched`` - The request resource action is one of the grant's resource actions.
+      The JMESPath expression is only searched if the action matched.
+    - ``search_result`` - The result of the JMESPath expression, or ``None`` if it was not searched or failed.
+    - ``search_error`` - Why the JMESPath search failed, or ``None`` .
+    - ``result_match`` - The ``result_match`` of the grant that the search result is compared with.
+    - ``matched`` - The grant matched the request.
+    - ``canary`` - The grant is a canary grant, that never decides the request.
+    """
+
+    effect: str
+    name: str
+    uuid: Optional[str] = None
+    tags: Dict[str, str] = {}
+    action_matched: bool
+    search_result: Any = None
+    search_error: Optional[str] = None
+    result_match: Any = None
+    matched: bool
+    canary: bool = False
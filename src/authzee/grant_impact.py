@@ -0,0 +1,16 @@
+
+from typing import List
+
+from pydantic import BaseModel
+
+
+class GrantImpact(BaseModel):
+    """The decisions a new grant would change for a corpus of requests.
+
+    ``allow_to_deny`` and ``deny_to_allow`` are indexes into the given requests.
+    """
+
+    total_requests: int
+    allow_to_deny: List[int]
+    deny_to_allow: List[int]
+
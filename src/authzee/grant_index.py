@@ -0,0 +1,144 @@
+
+import itertools
+from typing import Dict, List, Optional, Tuple, Type
+
+from pydantic import BaseModel
+
+from authzee.grant import Grant
+from authzee.resource_action import ResourceAction
+
+
+class GrantIndex:
+    """Grants bucketed by resource type and resource action.
+
+    Build the index once, and look up only the grants that could apply to a request,
+    instead of filtering every grant for each request.
+    Grants are returned in the order they were added.
+
+    Parameters
+    ----------
+    grants : Optional[List[Grant]], optional
+        Grants to add to the index, by default none.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import GrantIndex
+
+        grant_index = GrantIndex(grants=list(authzee_app.list_grants(effect=GrantEffect.ALLOW)))
+        grants = grant_index.grants(resource_type=Balloon, resource_action=BalloonAction.CreateBalloon)
+
+    """
+
+
+    def __init__(self, grants: Optional[List[Grant]] = None):
+        self._sequence = itertools.count()
+        self._grants: Dict[int, Grant] = {}
+        self._uuid_lookup: Dict[str, int] = {}
+        self._type_buckets: Dict[Type[BaseModel], Dict[int, Grant]] = {}
+        self._action_buckets: Dict[Tuple[Type[BaseModel], ResourceAction], Dict[int, Grant]] = {}
+        for grant in grants if grants is not None else []:
+            self.add(grant=grant)
+
+
+    def __len__(self) -> int:
+        return len(self._grants)
+
+
+    def add(self, grant: Grant) -> None:
+        """Add a grant to the index.
+
+        Parameters
+        ----------
+        grant : Grant
+            The grant. A grant with the same UUID is replaced.
+        """
+        if grant.uuid is not None:
+            self.remove(uuid=grant.uuid)
+
+        key = next(self._sequence)
+        self._grants[key] = grant
+        if grant.uuid is not None:
+            self._uuid_lookup[grant.uuid] = key
+
+        self._type_buckets.setdefault(grant.resource_type, {})[key] = grant
+        for resource_action in grant.resource_actions:
+            self._action_buckets.setdefault((grant.resource_type, resource_action), {})[key] = grant
+
+
+    def remove(self, uuid: str) -> Optional[Grant]:
+        """Remove a grant from the index.
+
+        Parameters
+        ----------
+        uuid : str
+            UUID of the grant.
+
+        Returns
+        -------
+        Optional[Grant]
+            The removed grant, or ``None`` if it is not in the index.
+        """
+        key = self._uuid_lookup.pop(uuid, None)
+        if key is None:
+            return None
+
+        grant = self._grants.pop(key)
+        self._type_buckets[grant.resource_type].pop(key)
+        for resource_action in grant.resource_actions:
+            self._action_buckets[(grant.resource_type, resource_action)].pop(key)
+
+        return grant
+
+
+    def get(self, uuid: str) -> Optional[Grant]:
+        """Get a grant by UUID.
+
+        Parameters
+        ----------
+        uuid : str
+            UUID of the grant.
+
+        Returns
+        -------
+        Optional[Grant]
+            The grant, or ``None`` if it is not in the index.
+        """
+        key = self._uuid_lookup.get(uuid)
+
+        return self._grants[key] if key is not None else None
+
+
+    def grants(
+        self,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None
+    ) -> List[Grant]:
+        """Look up the grants for a resource type and action.
+
+        Parameters
+        ----------
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by ``ResourceAction`` .
+            By default no filter is applied.
+
+        Returns
+        -------
+        List[Grant]
+            The grants, in the order they were added.
+        """
+        if resource_type is not None and resource_action is not None:
+            return list(self._action_buckets.get((resource_type, resource_action), {}).values())
+
+        if resource_type is not None:
+            return list(self._type_buckets.get(resource_type, {}).values())
+
+        grants = list(self._grants.values())
+        if resource_action is not None:
+            grants = [grant for grant in grants if resource_action in grant.resource_actions]
+
+        return grants
@@ -0,0 +1,19 @@
+
+import datetime
+from typing import Dict, Optional
+
+from pydantic import BaseModel
+
+
+class GrantLifecyclePolicy(BaseModel):
+    """Which grants to move to the archive with ``Authzee.apply_lifecycle_policy()`` .
+
+    A grant is archived if it matches any of the set rules.
+
+    - ``archive_tags`` - Archive grants that have all of these tags, like ``{"status": "retired"}`` .
+    - ``archive_unused_for`` - Archive grants that were never used, or not used for this long.
+      Requires ``AuthzeeConfig.track_grant_usage`` .
+    """
+
+    archive_tags: Dict[str, str] = {}
+    archive_unused_for: Optional[datetime.timedelta] = None
@@ -0,0 +1,50 @@
+
+from typing import Any, Dict, Optional
+
+from pydantic import BaseModel
+
+
+class GrantLimits(BaseModel):
+    """Limits on the size and complexity of grants.
+
+    By default no limits are applied.
+
+    Checked when grants are verified:
+
+    - ``max_expression_length`` - Max number of characters in ``Grant.jmespath_expression`` .
+    - ``max_expression_depth`` - Max nesting depth of the parsed ``Grant.jmespath_expression`` .
+    - ``max_result_match_size`` - Max size of ``Grant.result_match`` as a JSON string.
+
+    Checked when grants are computed:
+
+    - ``max_search_result_size`` - Max size of the JMESPath search result as a JSON string.
+      Larger results are handled like JMESPath errors, with the ``jmespath_error_severity`` of the ``Authzee`` app.
+    """
+
+    max_expression_length: Optional[int] = None
+    max_expression_depth: Optional[int] = None
+    max_result_match_size: Optional[int] = None
+    max_search_result_size: Optional[int] = None
+
+
+def expression_depth(parsed: Dict[str, Any]) -> int:
+    """Get the nesting depth of a parsed JMESPath expression.
+
+    Parameters
+    ----------
+    parsed : Dict[str, Any]
+        The AST from ``jmespath.compile(expression).parsed`` .
+
+    Returns
+    -------
+    int
+        The depth of the deepest node in the AST.
+    """
+    child_depths = [
+        expression_depth(parsed=child)
+        for child in parsed.get("children", [])
+        if isinstance(child, dict)
+    ]
+
+    return 1 + max(child_depths, default=0)
+
@@ -0,0 +1,17 @@
+
+from enum import Enum
+
+
+class GrantMatchMode(Enum):
+    """How the JMESPath result of a grant is compared to ``Grant.result_match`` .
+
+    - ``EQUAL`` - The result must equal ``result_match`` .
+    - ``ONE_OF`` - ``result_match`` is a list and the result must equal one of the values in it.
+    - ``TRUTHY`` - The result must be truthy by JMESPath rules, ``result_match`` is not used and must be ``None`` .
+      ``null`` , ``false`` , ``""`` , ``[]`` and ``{}`` are not truthy.
+    """
+
+    EQUAL = "EQUAL"
+    ONE_OF = "ONE_OF"
+    TRUTHY = "TRUTHY"
+
@@ -0,0 +1,22 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class GrantProvenance(BaseModel):
+    """Where a grant came from, to trace a surprising decision back to its origin.
+
+    Provenance is stored with the grant and returned with it by ``list_grants`` and ``list_matching_grants`` .
+    It does not affect what the grant matches.
+
+    - ``source`` - Name of the bundle, file or system the grant was defined in.
+    - ``importer`` - The tool or job that imported the grant.
+    - ``caller`` - The API caller that added the grant.
+    - ``commit_sha`` - The commit of the grant definition in source control.
+    """
+
+    source: Optional[str] = None
+    importer: Optional[str] = None
+    caller: Optional[str] = None
+    commit_sha: Optional[str] = None
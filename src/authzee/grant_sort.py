@@ -0,0 +1,21 @@
+
+from enum import Enum
+
+
+class GrantSort(Enum):
+    """How matching grants are sorted.
+
+    - ``SPECIFICITY`` - Grants with fewer resource actions first, then by name.
+    - ``NAME`` - Grants sorted by name, then by UUID.
+    - ``CREATED`` - Grants sorted by ``Grant.created_at`` , oldest first, then by name.
+      Grants without a creation time are last, also when sorted descending.
+
+    Grants are not sorted by effect, because matching grants are listed for one effect at a time.
+    Grants have no priority to sort by, because any matching deny grant denies a request,
+    and any matching allow grant allows it otherwise.
+    """
+
+    SPECIFICITY = "SPECIFICITY"
+    NAME = "NAME"
+    CREATED = "CREATED"
+
@@ -0,0 +1,23 @@
+
+from typing import Dict
+
+from pydantic import BaseModel
+
+
+class GrantStats(BaseModel):
+    """Statistics about the stored grants.
+
+    - ``total_grants`` - Number of stored grants.
+    - ``grants_by_effect`` - Number of grants for each ``GrantEffect`` value.
+    - ``grants_by_resource_type`` - Number of grants for each registered resource type name.
+      Resource types with ``0`` are not covered by any grant.
+    - ``grants_by_action`` - Number of grants that include each registered resource action, like ``"BalloonAction.CreateBalloon"`` .
+      Actions with ``0`` are not covered by any grant.
+    - ``average_grant_size`` - Average number of characters in the JMESPath expression and ``result_match`` as JSON.
+    """
+
+    total_grants: int
+    grants_by_effect: Dict[str, int]
+    grants_by_resource_type: Dict[str, int]
+    grants_by_action: Dict[str, int]
+    average_grant_size: float
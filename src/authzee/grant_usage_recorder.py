@@ -0,0 +1,147 @@
+
+import datetime
+import threading
+from typing import Dict, Optional
+
+from loguru import logger
+
+from authzee.clock import Clock
+from authzee.grant import Grant
+from authzee.grant_effect import GrantEffect
+from authzee.storage.storage_backend import StorageBackend
+
+
+class GrantUsageRecorder:
+    """Record when grants decide or match a request, in batches, with the storage backend.
+
+    ``Authzee`` creates a recorder when ``AuthzeeConfig.track_grant_usage`` is ``True`` ,
+    and passes it to the compute backend.
+    Recording only holds the usage in memory.
+    ``Authzee`` writes full batches after each decision, with ``flush_full`` ,
+    so storage errors while recording usage never change a decision.
+    ``MultiprocessCompute`` workers record with their own storage backend and do not batch.
+    They write at the end of each task, so no worker usage is left unwritten at shutdown.
+
+    Parameters
+    ----------
+    storage_backend : StorageBackend
+        Storage backend to record grant usage with.
+    batch_size : int, optional
+        Number of used grants to hold before they are written to storage, by default 1.
+    clock : Optional[Clock], optional
+        Time source for the last used times.
+        By default the system clock is used.
+    """
+
+    def __init__(
+        self,
+        storage_backend: StorageBackend,
+        batch_size: int = 1,
+        clock: Optional[Clock] = None
+    ):
+        self.storage_backend = storage_backend
+        self.batch_size = batch_size
+        self.clock = clock if clock is not None else Clock()
+        self._pending: Dict[GrantEffect, Dict[str, datetime.datetime]] = {effect: {} for effect in GrantEffect}
+        self._lock = threading.Lock()
+
+
+    def record(self, effect: GrantEffect, grant: Grant) -> None:
+        """Record that a grant was used now.
+
+        The usage is held until the next flush.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        grant : Grant
+            The grant that decided or matched a request.
+        """
+        if grant.uuid is None:
+            return
+
+        with self._lock:
+            self._pending[effect][grant.uuid] = self.clock.now()
+
+
+    def is_full(self) -> bool:
+        """Check if a batch of grant usage is pending.
+
+        Returns
+        -------
+        bool
+            ``True`` if at least ``batch_size`` used grants are pending.
+        """
+        with self._lock:
+            pending_count = sum(len(pending) for pending in self._pending.values())
+
+        return pending_count >= self.batch_size
+
+
+    def flush(self) -> None:
+        """Write the pending grant usage to storage.
+        """
+        for effect, last_used in self._take_pending().items():
+            if len(last_used) > 0:
+                self.storage_backend.record_grant_usage(effect=effect, last_used=last_used)
+
+
+    async def flush_async(self) -> None:
+        """Write the pending grant usage to storage.
+        """
+        for effect, last_used in self._take_pending().items():
+            if len(last_used) > 0:
+                await self.storage_backend.record_grant_usage_async(effect=effect, last_used=last_used)
+
+
+    def flush_full(self) -> None:
+        """Write the pending grant usage to storage if a batch is full.
+
+        Errors are logged instead of raised, and the batch is dropped.
+        """
+        if self.is_full() is False:
+            return
+
+        try:
+            self.flush()
+        except Exception as error:
+            logger.warning("Failed to record grant usage: {!r}".format(error))
+
+
+    async def flush_full_async(self) -> None:
+        """Write the pending grant usage to storage if a batch is full.
+
+        Errors are logged instead of raised, and the batch is dropped.
+        """
+        if self.is_full() is False:
+            return
+
+        try:
+            await self.flush_async()
+        except Exception as error:
+            logger.warning("Failed to record grant usage: {!r}".format(error))
+
+
+    def _take_pending(self) -> Dict[GrantEffect, Dict[str, datetime.datetime]]:
+        with self._lock:
+            pending = self._pending
+            self._pending = {effect: {} for effect in GrantEffect}
+
+        return pending
+
+
+def record_grant_usage(recorder: Optional[GrantUsageRecorder], effect: GrantEffect, grant: Grant) -> None:
+    """Record that a grant was used with the optional ``recorder`` .
+
+    Parameters
+    ----------
+    recorder : Optional[GrantUsageRecorder]
+        The recorder, or ``None`` when grant usage is not tracked.
+    effect : GrantEffect
+        The effect of the grant.
+    grant : Grant
+        The grant that decided or matched a request.
+    """
+    if recorder is not None:
+        recorder.record(effect=effect, grant=grant)
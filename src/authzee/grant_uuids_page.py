@@ -0,0 +1,10 @@
+
+from typing import List, Union
+
+from pydantic import BaseModel
+
+
+class GrantUUIDsPage(BaseModel):
+
+    uuids: List[str]
+    next_page_reference: Union[str, None]
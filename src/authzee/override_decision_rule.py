@@ -0,0 +1,28 @@
+
+from typing import Optional
+
+from authzee.anomaly_rule import AnomalyRule
+from authzee.decision_event import DecisionEvent
+
+
+class OverrideDecisionRule(AnomalyRule):
+    """Flag decisions that were forced by a ``DecisionOverride`` , like break-glass access.
+
+    Parameters
+    ----------
+    allowed_only : bool, optional
+        Only flag overrides that allowed the request, by default True.
+    """
+
+    def __init__(self, allowed_only: bool = True):
+        self.allowed_only = allowed_only
+
+
+    def check(self, event: DecisionEvent) -> Optional[str]:
+        if event.overridden is False:
+            return None
+
+        if self.allowed_only is True and event.allowed is False:
+            return None
+
+        return "The request was {} by a decision override.".format("allowed" if event.allowed is True else "denied")
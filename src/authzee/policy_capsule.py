@@ -0,0 +1,114 @@
+
+import datetime
+import io
+import json
+from typing import Any, Dict, List, Optional, Set
+import zipfile
+
+from pydantic import BaseModel
+
+from authzee.grant import Grant
+
+
+REDACTED = "[REDACTED]"
+
+
+class PolicyCapsule(BaseModel):
+    """Everything needed to reproduce a single authorization decision, for bug reports and support.
+
+    - ``created_at`` - When the capsule was captured.
+    - ``request`` - The JMESPath data of the request, with redacted fields replaced by ``"[REDACTED]"`` .
+    - ``decision`` - ``True`` if the request was allowed, ``False`` if denied.
+    - ``override_decision`` - The decision forced by a ``DecisionOverride`` , or ``None`` .
+    - ``grants`` - The grants that were evaluated for the request, by effect.
+    - ``trace`` - Whether each evaluated grant matched the request, in evaluation order.
+    - ``definitions`` - The registered identity types and resource authzs.
+    - ``config`` - The ``AuthzeeConfig`` of the app.
+    """
+
+    created_at: datetime.datetime
+    request: Dict[str, Any]
+    decision: bool
+    override_decision: Optional[bool] = None
+    grants: Dict[str, List[Dict[str, Any]]]
+    trace: List[Dict[str, Any]]
+    definitions: Dict[str, Any]
+    config: Dict[str, Any]
+
+
+    def to_archive(self) -> bytes:
+        """Package the capsule as a zip archive.
+
+        Each part of the capsule is written as a JSON file:
+        ``request.json`` , ``decision.json`` , ``grants.json`` , ``trace.json`` , ``definitions.json`` and ``config.json`` .
+
+        Returns
+        -------
+        bytes
+            The zip archive.
+        """
+        parts = {
+            "request.json": self.request,
+            "decision.json": {
+                "created_at": self.created_at.isoformat(),
+                "decision": self.decision,
+                "override_decision": self.override_decision
+            },
+            "grants.json": self.grants,
+            "trace.json": self.trace,
+            "definitions.json": self.definitions,
+            "config.json": self.config
+        }
+        archive = io.BytesIO()
+        with zipfile.ZipFile(archive, mode="w", compression=zipfile.ZIP_DEFLATED) as zip_file:
+            for file_name, data in parts.items():
+                zip_file.writestr(file_name, json.dumps(data, indent=4, sort_keys=True))
+
+        return archive.getvalue()
+
+
+def grant_capsule_data(grant: Grant) -> Dict[str, Any]:
+    """Convert a grant to JSON compatible data for a capsule.
+
+    Parameters
+    ----------
+    grant : Grant
+        The grant.
+
+    Returns
+    -------
+    Dict[str, Any]
+        The grant with the resource type name and sorted resource action values.
+    """
+    grant_data = json.loads(grant.json(exclude={"resource_type", "resource_actions"}))
+    grant_data['resource_type'] = grant.resource_type.__name__
+    grant_data['resource_actions'] = sorted(str(action) for action in grant.resource_actions)
+
+    return grant_data
+
+
+def redact_fields(data: Any, fields: Set[str]) -> Any:
+    """Replace the values of object fields with ``"[REDACTED]"`` , at any depth.
+
+    Parameters
+    ----------
+    data : Any
+        JSON compatible data.
+    fields : Set[str]
+        Names of the fields to redact.
+
+    Returns
+    -------
+    Any
+        A redacted copy of the data.
+    """
+    if isinstance(data, dict):
+        return {
+            key: REDACTED if key in fields else redact_fields(data=value, fields=fields)
+            for key, value in data.items()
+        }
+
+    if isinstance(data, list):
+        return [redact_fields(data=value, fields=fields) for value in data]
+
+    return data
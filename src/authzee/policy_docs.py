@@ -0,0 +1,135 @@
+
+import json
+from typing import Any, Dict, List, Optional, Tuple, Type
+
+from pydantic import BaseModel
+
+from authzee.grant import Grant
+from authzee.grant_effect import GrantEffect
+from authzee.resource_authz import ResourceAuthz
+
+
+def render_policy_markdown(
+    identity_types: Dict[Type[BaseModel], Tuple[int, Optional[int]]],
+    resource_authzs: List[ResourceAuthz],
+    grants: Dict[GrantEffect, List[Grant]]
+) -> str:
+    """Render identity types, resource authzs and grants as a Markdown policy report.
+
+    Everything is sorted by name so the report is stable between runs.
+
+    Parameters
+    ----------
+    identity_types : Dict[Type[BaseModel], Tuple[int, Optional[int]]]
+        Identity types with their min and max count in a request.
+    resource_authzs : List[ResourceAuthz]
+        Resource authz instances.
+    grants : Dict[GrantEffect, List[Grant]]
+        Grants by effect.
+
+    Returns
+    -------
+    str
+        The Markdown report.
+    """
+    lines = ["# Authorization Policy", ""]
+    lines.extend(["## Identity Types", ""])
+    for identity_type, (min_count, max_count) in sorted(identity_types.items(), key=lambda item: item[0].__name__):
+        lines.extend([
+            "### {}".format(identity_type.__name__),
+            "",
+            "Count in a request: {} to {}".format(min_count, "any" if max_count is None else max_count),
+            ""
+        ])
+        lines.extend(_model_table(model_type=identity_type))
+
+    lines.extend(["## Resources", ""])
+    for resource_authz in sorted(resource_authzs, key=lambda authz: type(authz).__name__):
+        lines.extend([
+            "### {}".format(type(resource_authz).__name__),
+            "",
+            "- Resource type: `{}`".format(resource_authz.resource_type.__name__),
+            "- Actions: {}".format(_code_list(action.value for action in resource_authz.resource_action_type)),
+            "- Parent resource authzs: {}".format(_code_list(resource_authz.parent_authz_names)),
+            "- Child resource authzs: {}".format(_code_list(resource_authz.child_authz_names))
+        ])
+        if len(resource_authz.hidden_resource_fields) > 0:
+            lines.append("- Hidden resource fields: {}".format(_code_list(resource_authz.hidden_resource_fields)))
+
+        lines.append("")
+        lines.extend(_model_table(model_type=resource_authz.resource_type))
+
+    lines.extend(["## Grants", ""])
+    for effect in GrantEffect:
+        effect_grants = sorted(grants.get(effect, []), key=lambda grant: (grant.name, grant.uuid or ""))
+        lines.extend(["### {} ({})".format(effect.value, len(effect_grants)), ""])
+        if len(effect_grants) == 0:
+            lines.extend(["No grants.", ""])
+            continue
+
+        lines.extend([
+            "| Name | Description | Resource Type | Actions | JMESPath Expression | Result Match | Tags | UUID |",
+            "| --- | --- | --- | --- | --- | --- | --- | --- |"
+        ])
+        for grant in effect_grants:
+            lines.append(
+                "| {} | {} | {} | {} | {} | {} | {} | {} |".format(
+                    _cell(grant.name),
+                    _cell(grant.description),
+                    _cell(grant.resource_type.__name__),
+                    _cell(", ".join(sorted(str(action.value) for action in grant.resource_actions))),
+                    _cell(_code(grant.jmespath_expression)),
+                    _cell(_code(json.dumps(grant.result_match))),
+                    _cell(", ".join("{}={}".format(key, value) for key, value in sorted(grant.tags.items()))),
+                    _cell(grant.uuid or "")
+                )
+            )
+
+        lines.append("")
+
+    return "\n".join(lines)
+
+
+def _model_table(model_type: Type[BaseModel]) -> List[str]:
+    lines = [
+        "| Field | Type | Required |",
+        "| --- | --- | --- |"
+    ]
+    for field_name, field_info in model_type.model_fields.items():
+        lines.append(
+            "| {} | {} | {} |".format(
+                _cell(field_name),
+                _cell(_type_name(field_info.annotation)),
+                "yes" if field_info.is_required() is True else "no"
+            )
+        )
+
+    lines.append("")
+
+    return lines
+
+
+def _type_name(annotation: Any) -> str:
+    if isinstance(annotation, type):
+        return annotation.__name__
+
+    return str(annotation).replace("typing.", "")
+
+
+def _code_list(values) -> str:
+    values = sorted(values)
+    if len(values) == 0:
+        return "none"
+
+    return ", ".join(_code(str(value)) for value in values)
+
+
+def _code(value: str) -> str:
+    if "`" in value:
+        return "`` {} ``".format(value)
+
+    return "`{}`".format(value)
+
+
+def _cell(value: str) -> str:
+    return value.replace("|", "\\|").replace("\n", " ")
@@ -0,0 +1,19 @@
+
+from pydantic import BaseModel
+
+
+class RelationTuple(BaseModel):
+    """A relationship between a subject and an object, like ``user:alice`` is a ``viewer`` of ``folder:reports`` .
+
+    Relation tuples are kept in a ``RelationStore`` and are added to the grant JMESPath data under ``relations``
+    for the resource and its parent and child resources.
+    The object of a resource is set by ``ResourceAuthz.relation_object()`` .
+
+    - ``subject`` - The subject of the relationship, ex. ``"user:alice"`` or ``"group:admins"`` .
+    - ``relation`` - The name of the relationship, ex. ``"viewer"`` or ``"owner"`` .
+    - ``object`` - The object of the relationship, ex. ``"folder:reports"`` .
+    """
+
+    subject: str
+    relation: str
+    object: str
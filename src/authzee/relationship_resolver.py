@@ -0,0 +1,162 @@
+
+from collections import OrderedDict
+from typing import List, Tuple
+
+from pydantic import BaseModel
+
+from authzee import exceptions
+
+
+class RelationshipResolver:
+    """Base class for fetching the parent and child resources of a resource, ex. from the application database.
+
+    When a resolver is given to ``Authzee`` , ``authorize`` and ``authorize_many`` calls add the resolved
+    parent and child resources to the ones that were passed in, so callers don't have to assemble them for every request.
+    The passed in arguments are verified before the resolver is called, and the resolved resources are verified after.
+
+    Sub-classes must implement:
+
+        - ``fetch_parent_resources`` - Fetch the parent resources of a resource.
+        - ``fetch_child_resources`` - Fetch the child resources of a resource.
+
+    Results are cached by the resource type and value, when ``cache_size`` is greater than ``0`` .
+
+    Parameters
+    ----------
+    cache_size : int, optional
+        Max number of resources to cache relationships for, by default 0 (no caching).
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import RelationshipResolver
+
+        class BalloonResolver(RelationshipResolver):
+
+            def fetch_parent_resources(self, resource: BaseModel) -> List[BaseModel]:
+                return []
+
+            def fetch_child_resources(self, resource: BaseModel) -> List[BaseModel]:
+                return [BalloonString(**row) for row in db.strings_for_balloon(resource.color)]
+
+
+        authzee_app = Authzee(
+            compute_backend=compute,
+            storage_backend=storage,
+            relationship_resolver=BalloonResolver(cache_size=1000)
+        )
+
+    """
+
+    def __init__(self, cache_size: int = 0):
+        self.cache_size = cache_size
+        self._parent_cache: OrderedDict = OrderedDict()
+        self._child_cache: OrderedDict = OrderedDict()
+
+
+    def fetch_parent_resources(self, resource: BaseModel) -> List[BaseModel]:
+        """Fetch the parent resources of a resource.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource model.
+
+        Returns
+        -------
+        List[BaseModel]
+            The parent resource models.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def fetch_child_resources(self, resource: BaseModel) -> List[BaseModel]:
+        """Fetch the child resources of a resource.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource model.
+
+        Returns
+        -------
+        List[BaseModel]
+            The child resource models.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def get_parent_resources(self, resource: BaseModel) -> List[BaseModel]:
+        """Get the parent resources of a resource, from the cache if possible.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource model.
+
+        Returns
+        -------
+        List[BaseModel]
+            The parent resource models.
+        """
+        return self._cached(
+            cache=self._parent_cache,
+            resource=resource,
+            fetch=self.fetch_parent_resources
+        )
+
+
+    def get_child_resources(self, resource: BaseModel) -> List[BaseModel]:
+        """Get the child resources of a resource, from the cache if possible.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            The resource model.
+
+        Returns
+        -------
+        List[BaseModel]
+            The child resource models.
+        """
+        return self._cached(
+            cache=self._child_cache,
+            resource=resource,
+            fetch=self.fetch_child_resources
+        )
+
+
+    def clear_cache(self) -> None:
+        """Clear all cached relationships.
+        """
+        self._parent_cache.clear()
+        self._child_cache.clear()
+
+
+    def _cached(self, cache: OrderedDict, resource: BaseModel, fetch) -> List[BaseModel]:
+        if self.cache_size < 1:
+            return fetch(resource)
+
+        key: Tuple[str, str] = (type(resource).__qualname__, resource.json())
+        if key in cache:
+            cache.move_to_end(key)
+
+            return cache[key]
+
+        resources = fetch(resource)
+        cache[key] = resources
+        if len(cache) > self.cache_size:
+            cache.popitem(last=False)
+
+        return resources
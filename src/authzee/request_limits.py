@@ -0,0 +1,26 @@
+
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class RequestLimits(BaseModel):
+    """Limits on the size of authorization requests that are checked before the request is verified.
+
+    By default no limits are applied.
+
+    - ``max_resources`` - Max number of resources in a single ``authorize_many`` call.
+    - ``max_parent_resources`` - Max number of parent resources.
+    - ``max_child_resources`` - Max number of child resources.
+    - ``max_identities_per_type`` - Max number of identities of any one identity type.
+    - ``max_evaluation_cost`` - Max cost of the grant evaluations for one call. See ``authzee.EvaluationBudget`` .
+      Going over the budget raises ``authzee.exceptions.EvaluationBudgetExceededError`` instead of returning a decision,
+      and the ``FailPolicy`` does not apply.
+      It can not be set with a compute backend that does not support evaluation budgets, like ``MultiprocessCompute`` .
+    """
+
+    max_resources: Optional[int] = None
+    max_parent_resources: Optional[int] = None
+    max_child_resources: Optional[int] = None
+    max_identities_per_type: Optional[int] = None
+    max_evaluation_cost: Optional[int] = None
@@ -1,18 +1,152 @@
 
 from enum import Enum
+from typing import List, Set, Type
+
+from authzee import exceptions
+
+
+NAMESPACE_SEPARATOR = ":"
 
 
 class ResourceAction(Enum):
     """Base class for resource action enums
 
+    Action values may be namespaced with ``:`` to form a hierarchy,
+    like ``"documents:read"`` or ``"admin:users:delete"``.
+    By default the value of an action is the name of the member and has no namespace.
+
+    Namespace segments must not be empty and cannot contain the wildcards ``*`` or ``**`` .
+    This is checked when the ``ResourceAuthz`` is registered with ``Authzee``.
+
     """
 
     @staticmethod
     def _generate_next_value_(
-        name: str, 
-        start: int, 
-        count: int, 
+        name: str,
+        start: int,
+        count: int,
         last_values: list
     ) -> str:
         return name
 
+
+    @property
+    def namespace(self) -> List[str]:
+        """The namespace segments of the action value, not including the final action segment.
+        """
+        return str(self.value).split(NAMESPACE_SEPARATOR)[:-1]
+
+
+    @classmethod
+    def matching(cls, pattern: str) -> Set["ResourceAction"]:
+        """Get the set of actions with values that match a namespace pattern.
+
+        - ``*`` matches exactly one segment.
+        - ``**`` matches one or more segments, and may only be the last segment.
+
+        Useful for the ``resource_actions`` of a ``Grant`` .
+
+        Parameters
+        ----------
+        pattern : str
+            The namespace pattern like ``"documents:*"`` or ``"admin:**"`` .
+
+        Returns
+        -------
+        Set[ResourceAction]
+            Actions that match the pattern.
+
+        Raises
+        ------
+        authzee.exceptions.InputVerificationError
+            The pattern is not valid.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            from authzee import ResourceAction
+
+            class DocumentAction(ResourceAction):
+
+                Read: str = "documents:read"
+                Write: str = "documents:write"
+                DeleteUser: str = "admin:users:delete"
+
+            DocumentAction.matching("documents:*") # {DocumentAction.Read, DocumentAction.Write}
+            DocumentAction.matching("admin:**") # {DocumentAction.DeleteUser}
+
+        """
+        pattern_segments = pattern.split(NAMESPACE_SEPARATOR)
+        for i, segment in enumerate(pattern_segments):
+            if segment == "":
+                raise exceptions.InputVerificationError(
+                    "Action pattern '{}' has an empty segment.".format(pattern)
+                )
+
+            if segment == "**" and i != len(pattern_segments) - 1:
+                raise exceptions.InputVerificationError(
+                    "Action pattern '{}' can only use '**' as the last segment.".format(pattern)
+                )
+
+            if segment not in ("*", "**") and "*" in segment:
+                raise exceptions.InputVerificationError(
+                    "Action pattern '{}' has a partial wildcard segment '{}'.".format(pattern, segment)
+                )
+
+        return {
+            action for action in cls
+            if _segments_match(
+                pattern_segments=pattern_segments,
+                value_segments=str(action.value).split(NAMESPACE_SEPARATOR)
+            ) is True
+        }
+
+
+def verify_action_namespaces(resource_action_type: Type[ResourceAction]) -> None:
+    """Verify the namespaced values of a ``ResourceAction`` type.
+
+    Parameters
+    ----------
+    resource_action_type : Type[ResourceAction]
+        ``ResourceAction`` type to verify.
+
+    Raises
+    ------
+    authzee.exceptions.InputVerificationError
+        An action value is not a valid namespaced action.
+    """
+    for action in resource_action_type:
+        if type(action.value) != str:
+            raise exceptions.InputVerificationError(
+                "The value of action '{}' must be a string.".format(action)
+            )
+
+        for segment in action.value.split(NAMESPACE_SEPARATOR):
+            if segment == "":
+                raise exceptions.InputVerificationError(
+                    "The value '{}' of action '{}' has an empty namespace segment.".format(action.value, action)
+                )
+
+            if "*" in segment:
+                raise exceptions.InputVerificationError(
+                    "The value '{}' of action '{}' cannot contain wildcards.".format(action.value, action)
+                )
+
+
+def _segments_match(pattern_segments: List[str], value_segments: List[str]) -> bool:
+    for i, pattern_segment in enumerate(pattern_segments):
+        if pattern_segment == "**":
+            return len(value_segments) > i
+
+        if i >= len(value_segments):
+            return False
+
+        if (
+            pattern_segment != "*"
+            and pattern_segment != value_segments[i]
+        ):
+            return False
+
+    return len(pattern_segments) == len(value_segments)
+
@@ -1,12 +1,12 @@
 
-from typing import Set, Type
+from typing import Dict, Optional, Set, Type
 
 from pydantic import BaseModel, PrivateAttr
 
 from authzee.resource_action import ResourceAction
 
 
-class ResourceAuthz(BaseModel): 
+class ResourceAuthz(BaseModel):
     """Resource authorization definitions.
 
         - actions are the actions that can be performed on the resource.
@@ -18,7 +18,18 @@ class ResourceAuthz(BaseModel):
             - used in grants to authorize resources with specific parent resources
         - child_resources_authzs - list of resource authz types for child resources
             - used in grants to authorize resources with specific child resources
-        
+        - resource_alias_fields - optional set of resource fields that are alternate identifiers for the resource
+            - ex. ``arn``, ``path``, ``id``
+            - all alias fields must be filled in after ``canonicalize`` is called on a resource
+        - hidden_resource_fields - optional set of resource fields that are not passed to grant JMESPath expressions
+            - the fields are still validated with the resource model
+            - ex. ``ssn``, ``internal_notes``
+        - inherited_actions - optional map of action values of this resource to action values of parent resources
+            - with ``AuthzeeConfig.grant_inheritance`` enabled, parent grants with ``applies_to_descendants`` 
+              for the mapped parent actions also apply to this resource
+            - ex. ``{"documents:read": {"folders:read"}}``
+        - relation_object - optional method to get the object of a resource in ``RelationTuple`` s
+
 
     """
 
@@ -26,8 +37,91 @@ class ResourceAuthz(BaseModel):
     resource_action_type: Type[ResourceAction]
     parent_authz_names: Set[str]
     child_authz_names: Set[str]
+    resource_alias_fields: Set[str] = set()
+    hidden_resource_fields: Set[str] = set()
+    inherited_actions: Dict[str, Set[str]] = {}
     _parent_authz_types: Set[Type["ResourceAuthz"]] = PrivateAttr(default_factory=set)
     _child_authz_types: Set[Type["ResourceAuthz"]] = PrivateAttr(default_factory=set)
     _parent_resource_types: Set[Type[BaseModel]] = PrivateAttr(default_factory=set)
     _child_resource_types: Set[Type[BaseModel]] = PrivateAttr(default_factory=set)
 
+
+    def canonicalize(self, resource: BaseModel) -> BaseModel:
+        """Canonicalize a resource before it is used for authorization.
+
+        Override this to fill in the ``resource_alias_fields`` from whichever identifier was given,
+        so grants written against one alias match requests that use another.
+        By default the resource is returned unchanged.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Resource model of ``resource_type``.
+
+        Returns
+        -------
+        BaseModel
+            The canonical resource model.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            class Bucket(BaseModel):
+
+                name: Optional[str] = None
+                arn: Optional[str] = None
+
+
+            class BucketAuthz(ResourceAuthz):
+
+                resource_type: Type[BaseModel] = Bucket
+                resource_action_type: Type[ResourceAction] = BucketAction
+                parent_authz_names: Set[str] = set()
+                child_authz_names: Set[str] = set()
+                resource_alias_fields: Set[str] = {"name", "arn"}
+
+                def canonicalize(self, resource: Bucket) -> Bucket:
+                    if resource.arn is None:
+                        return Bucket(name=resource.name, arn="arn:aws:s3:::{}".format(resource.name))
+
+                    return Bucket(name=resource.arn.split(":")[-1], arn=resource.arn)
+
+        """
+        return resource
+
+
+    def relation_object(self, resource: BaseModel) -> Optional[str]:
+        """Get the object of a resource in ``RelationTuple`` s.
+
+        Override this to add the relation tuples of resources to the grant JMESPath data under ``relations`` ,
+        when ``Authzee`` has a relation store.
+        By default resources have no object and no relation tuples.
+
+        Parameters
+        ----------
+        resource : BaseModel
+            Canonical resource model of ``resource_type``.
+
+        Returns
+        -------
+        Optional[str]
+            The object of the resource, or ``None`` if it has no relation tuples.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            class FolderAuthz(ResourceAuthz):
+
+                resource_type: Type[BaseModel] = Folder
+                resource_action_type: Type[ResourceAction] = FolderAction
+                parent_authz_names: Set[str] = set()
+                child_authz_names: Set[str] = set()
+
+                def relation_object(self, resource: Folder) -> Optional[str]:
+                    return "folder:{}".format(resource.path)
+
+        """
+        return None
+
@@ -0,0 +1,26 @@
+
+from typing import List
+
+from pydantic import BaseModel
+
+
+class ResourceDescription(BaseModel):
+    """Machine readable description of what is valid in a request for a resource type.
+
+    - ``resource_type`` - Name of the resource type.
+    - ``resource_authz`` - Name of the ``ResourceAuthz`` the resource type is registered with.
+    - ``resource_actions`` - Valid resource actions, like ``"BalloonAction.CreateBalloon"`` .
+    - ``identity_types`` - Names of the registered identity types.
+    - ``parent_resource_types`` - Names of the valid parent resource types.
+    - ``child_resource_types`` - Names of the valid child resource types.
+    - ``jmespath_data_keys`` - JMESPath paths that grant expressions can use for the resource type,
+      like ``"resource.color"`` or ``"identities.ADUser[].cn"`` .
+    """
+
+    resource_type: str
+    resource_authz: str
+    resource_actions: List[str]
+    identity_types: List[str]
+    parent_resource_types: List[str]
+    child_resource_types: List[str]
+    jmespath_data_keys: List[str]
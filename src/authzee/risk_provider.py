@@ -0,0 +1,63 @@
+
+from typing import List, Optional
+
+from pydantic import BaseModel
+
+from authzee import exceptions
+
+
+class RiskProvider:
+    """Base class for scoring the risk of the identities in an authorization request, ex. from a fraud or identity protection service.
+
+    When a risk provider is given to ``Authzee`` , the score is added to the grant JMESPath data under ``risk_score`` ,
+    so grants can express adaptive policies, like denying requests with a ``risk_score`` over ``70`` .
+    Without a risk provider ``risk_score`` is ``null`` .
+
+    Sub-classes must implement:
+
+        - ``risk_score`` - Score the risk of the identities.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import RiskProvider
+
+        class SignInRisk(RiskProvider):
+
+            def risk_score(self, identities: List[BaseModel]) -> Optional[float]:
+                users = [identity for identity in identities if isinstance(identity, ADUser)]
+                if len(users) == 0:
+                    return None
+
+                return risk_service.score(users[0].cn)
+
+
+        authzee_app = Authzee(
+            compute_backend=compute,
+            storage_backend=storage,
+            risk_provider=SignInRisk()
+        )
+
+    """
+
+
+    def risk_score(self, identities: List[BaseModel]) -> Optional[float]:
+        """Score the risk of the identities in an authorization request.
+
+        Parameters
+        ----------
+        identities : List[BaseModel]
+            The identity models of the request.
+
+        Returns
+        -------
+        Optional[float]
+            The risk score, or ``None`` if the risk is unknown.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
@@ -0,0 +1,28 @@
+
+import random
+
+
+class Rng:
+    """Entropy source for ``Authzee`` , used for sampling like canary grants.
+
+    The base class uses the ``random`` module.
+    Sub-class it to supply another entropy source,
+    or use ``authzee.SeededRng`` for deterministic tests and replay runs.
+
+    Sub-classes must implement:
+
+        - ``random`` - A random float from ``0.0`` up to, but not including, ``1.0`` .
+
+    ``MultiprocessCompute`` gives each worker process a copy of the ``Rng`` , so it must be picklable.
+    """
+
+
+    def random(self) -> float:
+        """A random float.
+
+        Returns
+        -------
+        float
+            Random float from ``0.0`` up to, but not including, ``1.0`` .
+        """
+        return random.random()
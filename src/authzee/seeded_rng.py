@@ -0,0 +1,34 @@
+
+import random
+
+from authzee.rng import Rng
+
+
+class SeededRng(Rng):
+    """Rng with a fixed seed, for deterministic tests and replay runs.
+
+    The same seed always gives the same sequence of values.
+    ``MultiprocessCompute`` worker processes each start from the seed.
+
+    Parameters
+    ----------
+    seed : int
+        Seed of the sequence.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import SeededRng
+
+        authzee_app = Authzee(compute_backend=compute, storage_backend=storage, rng=SeededRng(seed=42))
+
+    """
+
+    def __init__(self, seed: int):
+        self.seed = seed
+        self._random = random.Random(seed)
+
+
+    def random(self) -> float:
+        return self._random.random()
@@ -1,16 +1,24 @@
 
 __all__ = [
     "StorageBackend",
-    "MemoryStorage"
+    "MemoryStorage",
+    "FaultInjectingStorage",
+    "RelationStore",
+    "MemoryRelationStore"
 ]
 
 from authzee.storage.storage_backend import StorageBackend
 
 from authzee.storage.memory_storage import MemoryStorage
+from authzee.storage.fault_injecting_storage import FaultInjectingStorage
+from authzee.storage.relation_store import RelationStore
+from authzee.storage.memory_relation_store import MemoryRelationStore
 try:
+    from authzee.storage.sql_relation_store import SQLRelationStore
     from authzee.storage.sql_storage import SQLNextPageRef
     from authzee.storage.sql_storage import SQLStorage
     __all__.append("SQLNextPageRef")
+    __all__.append("SQLRelationStore")
     __all__.append("SQLStorage")
 except ModuleNotFoundError: # pragma: no cover
     pass
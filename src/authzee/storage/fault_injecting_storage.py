@@ -0,0 +1,147 @@
+
+import time
+from typing import Dict, List, Optional, Type
+
+from pydantic import BaseModel
+
+from authzee import exceptions
+from authzee.grant import Grant
+from authzee.grant_effect import GrantEffect
+from authzee.raw_grants_page import RawGrantsPage
+from authzee.resource_action import ResourceAction
+from authzee.storage.memory_storage import MemoryStorage
+
+
+class FaultInjectingStorage(MemoryStorage):
+    """Memory storage backend for tests, that can return scripted pages, add latency and fail on a specific call.
+
+    Use it to deterministically test error handling around ``Authzee`` , like the ``FailPolicy`` and deadlines.
+    Grants that are added are stored in memory like ``MemoryStorage`` .
+    ``async`` methods call the sync methods, so latency blocks the event loop.
+
+    Parameters
+    ----------
+    scripted_pages : Optional[Dict[GrantEffect, List[List[Grant]]]], optional
+        Pages of grants to return for each effect, in order, instead of the stored grants.
+        Pages are not filtered by resource type or action.
+        By default pages of the stored grants are returned like ``MemoryStorage`` .
+    latency : float, optional
+        Seconds to sleep on every call, by default 0.
+    fail_on_call : Optional[int], optional
+        Raise ``error`` on this call number, starting at 1, of all calls that are counted.
+        By default calls don't fail.
+    fail_every_call : bool, optional
+        Raise ``error`` on every call after ``fail_on_call`` as well, by default False.
+    error : Optional[Exception], optional
+        The error to raise, by default an ``authzee.exceptions.StorageBackendError`` .
+        Only ``StorageBackendError`` s are decided by the ``FailPolicy`` .
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import Authzee, AuthzeeConfig, FailPolicy, FailureDecision, MainProcessCompute
+        from authzee.storage import FaultInjectingStorage
+
+        storage = FaultInjectingStorage(fail_on_call=1)
+        authzee_app = Authzee(
+            compute_backend=MainProcessCompute(),
+            storage_backend=storage,
+            identity_types={ADUser},
+            resource_authz_types={BalloonAuthz},
+            config=AuthzeeConfig(fail_policy=FailPolicy(storage_failure=FailureDecision.DENY))
+        )
+        authzee_app.initialize()
+        assert authzee_app.authorize(...) is False
+        assert storage.calls == ["get_raw_grants_page"]
+
+    """
+
+
+    def __init__(
+        self,
+        scripted_pages: Optional[Dict[GrantEffect, List[List[Grant]]]] = None,
+        latency: float = 0.0,
+        fail_on_call: Optional[int] = None,
+        fail_every_call: bool = False,
+        error: Optional[Exception] = None
+    ):
+        super().__init__()
+        self.scripted_pages = scripted_pages
+        self.latency = latency
+        self.fail_on_call = fail_on_call
+        self.fail_every_call = fail_every_call
+        self.error = error if error is not None else exceptions.StorageBackendError("Injected storage failure.")
+        self.calls: List[str] = []
+
+
+    def add_grant(self, effect: GrantEffect, grant: Grant) -> Grant:
+        self._inject(call="add_grant")
+
+        return super().add_grant(effect=effect, grant=grant)
+
+
+    def delete_grant(self, effect: GrantEffect, uuid: str) -> None:
+        self._inject(call="delete_grant")
+
+        return super().delete_grant(effect=effect, uuid=uuid)
+
+
+    def get_raw_grants_page(
+        self, 
+        effect: GrantEffect, 
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> RawGrantsPage:
+        self._inject(call="get_raw_grants_page")
+        if self.scripted_pages is None:
+            return super().get_raw_grants_page(
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size,
+                next_page_reference=next_page_reference
+            )
+
+        pages = self.scripted_pages.get(effect, [])
+        page_index = 0 if next_page_reference is None else int(next_page_reference)
+        next_page_index = page_index + 1
+
+        return RawGrantsPage(
+            raw_grants=list(pages[page_index]) if page_index < len(pages) else [],
+            next_page_reference=str(next_page_index) if next_page_index < len(pages) else None
+        )
+
+
+    async def get_raw_grants_page_async(
+        self, 
+        effect: GrantEffect, 
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> RawGrantsPage:
+        return self.get_raw_grants_page(
+            effect=effect,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            next_page_reference=next_page_reference
+        )
+
+
+    def _inject(self, call: str) -> None:
+        self.calls.append(call)
+        if self.latency > 0:
+            time.sleep(self.latency)
+
+        if (
+            self.fail_on_call is not None
+            and (
+                len(self.calls) == self.fail_on_call
+                or (self.fail_every_call is True and len(self.calls) > self.fail_on_call)
+            )
+        ):
+            raise self.error
@@ -0,0 +1,60 @@
+
+from typing import Dict, List, Set, Tuple
+
+from authzee import exceptions
+from authzee.relation_tuple import RelationTuple
+from authzee.storage.relation_store import RelationStore
+
+
+class MemoryRelationStore(RelationStore):
+    """Relation store for memory. 
+
+    Stores relation tuples in python native data structures.
+
+    Parameters
+    ----------
+    cache_size : int, optional
+        Max number of objects to cache relation tuples for, by default 0 (no caching).
+    """
+
+
+    def __init__(self, cache_size: int = 0):
+        super().__init__(cache_size=cache_size)
+        self._relations: Dict[str, Set[Tuple[str, str]]] = {}
+
+
+    def add_relation(self, relation_tuple: RelationTuple) -> RelationTuple:
+        self._relations.setdefault(relation_tuple.object, set()).add(
+            (relation_tuple.subject, relation_tuple.relation)
+        )
+
+        return relation_tuple.copy()
+
+
+    def teardown(self) -> None:
+        self._relations = {}
+
+
+    def delete_relation(self, relation_tuple: RelationTuple) -> None:
+        object_relations = self._relations.get(relation_tuple.object, set())
+        key = (relation_tuple.subject, relation_tuple.relation)
+        if key not in object_relations:
+            raise exceptions.RelationDoesNotExistError(
+                "Relation '{}' of '{}' to '{}' does not exist.".format(
+                    relation_tuple.relation,
+                    relation_tuple.subject,
+                    relation_tuple.object
+                )
+            )
+
+        object_relations.remove(key)
+        if len(object_relations) == 0:
+            self._relations.pop(relation_tuple.object)
+
+
+    def list_relations(self, objects: List[str]) -> List[RelationTuple]:
+        return [
+            RelationTuple(subject=subject, relation=relation, object=obj)
+            for obj in dict.fromkeys(objects)
+            for subject, relation in sorted(self._relations.get(obj, set()))
+        ]
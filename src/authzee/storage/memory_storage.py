@@ -1,13 +1,18 @@
 
+import asyncio
 import copy
+import datetime
+import time
 from typing import Dict, List, Optional, Set, Type
 
 from pydantic import BaseModel
 
 from authzee import exceptions
+from authzee.archived_grant import ArchivedGrant
 from authzee.backend_locality import BackendLocality
 from authzee.grant import Grant
 from authzee.grant_effect import GrantEffect
+from authzee.grant_index import GrantIndex
 from authzee.grants_page import GrantsPage
 from authzee.raw_grants_page import RawGrantsPage
 from authzee.resource_action import ResourceAction
@@ -19,10 +24,40 @@ class MemoryStorage(StorageBackend):
     """Storage backend for memory. 
 
     Stores grants in python native data structures.
+    Grants are held in a ``GrantIndex`` for each effect,
+    so pages only hold the grants for the resource type and action filters.
+
+    Grants are paginated like a database backed storage, so compute backends can be tested
+    against multiple pages of grants without standing up a database.
+    Page references are offsets into the filtered grants, in the order the grants were added.
+    Grants added or deleted between pages may shift the following pages.
+
+    Parameters
+    ----------
+    default_page_size : int, default: 10
+        The default page size for calls when page size is not specified.
+    page_latency : float, default: 0.0
+        Seconds to sleep on every page retrieval, to simulate storage round trips.
+        ``async`` methods sleep with ``asyncio.sleep`` , so latency doesn't block the event loop.
+
+    Examples
+    --------
+    .. code-block:: python
+
+        from authzee import Authzee, ThreadedCompute
+        from authzee.storage import MemoryStorage
+
+        authzee_app = Authzee(
+            compute_backend=ThreadedCompute(),
+            storage_backend=MemoryStorage(default_page_size=2, page_latency=0.01),
+            identity_types={ADUser},
+            resource_authz_types={BalloonAuthz}
+        )
+
     """
 
 
-    def __init__(self):
+    def __init__(self, default_page_size: int = 10, page_latency: float = 0.0):
         super().__init__(
             async_enabled=True,
             backend_locality=BackendLocality.MAIN_PROCESS,
@@ -31,13 +66,12 @@ class MemoryStorage(StorageBackend):
                 BackendLocality.NETWORK,
                 BackendLocality.SYSTEM
             },
-            default_page_size=10,
+            default_page_size=default_page_size,
+            page_latency=page_latency
         )
-        self._allow_grants: List[Grant] = []
-        self._allow_grants_lookup: Dict[str, Grant] = {}
-        self._deny_grants: List[Grant] = []
-        self._deny_grants_lookup: Dict[str, Grant] = {}
-
+        self._grant_indexes: Dict[GrantEffect, GrantIndex] = {effect: GrantIndex() for effect in GrantEffect}
+        self._grant_usage: Dict[GrantEffect, Dict[str, datetime.datetime]] = {effect: {} for effect in GrantEffect}
+        self._archived_grants: Dict[GrantEffect, List[ArchivedGrant]] = {effect: [] for effect in GrantEffect}
 
 
     def initialize(
@@ -54,20 +88,14 @@ class MemoryStorage(StorageBackend):
     
     
     def teardown(self) -> None:
-        self._allow_grants = []
-        self._allow_grants_lookup = {}
-        self._deny_grants = []
-        self._deny_grants_lookup = {}
+        self._grant_indexes = {effect: GrantIndex() for effect in GrantEffect}
+        self._grant_usage = {effect: {} for effect in GrantEffect}
+        self._archived_grants = {effect: [] for effect in GrantEffect}
 
     
     def add_grant(self, effect: GrantEffect, grant: Grant) -> Grant:
         new_grant = self._check_uuid(grant=grant, generate_uuid=True)
-        if effect is GrantEffect.ALLOW:
-            self._allow_grants.append(new_grant)
-            self._allow_grants_lookup[new_grant.uuid] = new_grant
-        elif effect is GrantEffect.DENY:
-            self._deny_grants.append(new_grant)
-            self._deny_grants_lookup[new_grant.uuid] = new_grant
+        self._grant_indexes[effect].add(grant=new_grant)
 
         return copy.deepcopy(new_grant)
 
@@ -77,15 +105,9 @@ class MemoryStorage(StorageBackend):
 
 
     def delete_grant(self, effect: GrantEffect, uuid: str) -> None:
-        if effect is GrantEffect.ALLOW:
-            if uuid in self._allow_grants_lookup:
-                self._allow_grants_lookup.pop(uuid)
-                return
-        
-        if effect is GrantEffect.DENY:
-            if uuid in self._deny_grants_lookup:
-                self._deny_grants_lookup.pop(uuid)
-                return 
+        if self._grant_indexes[effect].remove(uuid=uuid) is not None:
+            self._grant_usage[effect].pop(uuid, None)
+            return
 
         raise exceptions.GrantDoesNotExistError("{} Grant with UUID '{}' does not exist.".format(effect.value, uuid))
 
@@ -102,35 +124,15 @@ class MemoryStorage(StorageBackend):
         page_size: Optional[int] = None,
         next_page_reference: Optional[str] = None
     ) -> RawGrantsPage:
-        if page_size is None:
-            page_size = self.default_page_size
-        if effect == GrantEffect.ALLOW:
-            grants = self._allow_grants
-        elif effect == GrantEffect.DENY:
-            grants = self._deny_grants
-        
-        if next_page_reference is None:
-            start_index = 0
-        else:
-            start_index = next_page_reference + 1
-        
-        end_index = start_index + page_size
-        next_page_reference = str(end_index)
-        if end_index >= len(grants) - 1:
-            end_index = None
-            next_page_reference = None
+        if self.kwargs['page_latency'] > 0:
+            time.sleep(self.kwargs['page_latency'])
 
-        grants = copy.deepcopy(grants[start_index:end_index])
-        
-        if resource_type is not None:
-            grants = [grant for grant in grants if grant.resource_type == resource_type]
-        
-        if resource_action is not None:
-            grants = [grant for grant in grants if resource_action in grant.resource_actions]
-        
-        return RawGrantsPage(
-            raw_grants=grants,
-            next_page_reference=None
+        return self._raw_grants_page(
+            effect=effect,
+            resource_type=resource_type,
+            resource_action=resource_action,
+            page_size=page_size,
+            next_page_reference=next_page_reference
         )
 
 
@@ -141,13 +143,37 @@ class MemoryStorage(StorageBackend):
         page_size: Optional[int] = None, 
         next_page_reference: Optional[str] = None
     ) -> RawGrantsPage:
-        return self.get_raw_grants_page(
+        if self.kwargs['page_latency'] > 0:
+            await asyncio.sleep(self.kwargs['page_latency'])
+
+        return self._raw_grants_page(
             effect=effect,
             resource_type=resource_type,
             resource_action=resource_action,
             page_size=page_size,
             next_page_reference=next_page_reference
         )
+
+
+    def _raw_grants_page(
+        self, 
+        effect: GrantEffect, 
+        resource_type: Optional[Type[BaseModel]],
+        resource_action: Optional[ResourceAction],
+        page_size: Optional[int],
+        next_page_reference: Optional[str]
+    ) -> RawGrantsPage:
+        grants = self._grant_indexes[effect].grants(
+            resource_type=resource_type,
+            resource_action=resource_action
+        )
+        start = 0 if next_page_reference is None else int(next_page_reference)
+        end = start + self._real_page_size(page_size=page_size)
+
+        return RawGrantsPage(
+            raw_grants=copy.deepcopy(grants[start:end]),
+            next_page_reference=str(end) if end < len(grants) else None
+        )
     
 
     def normalize_raw_grants_page(
@@ -167,3 +193,45 @@ class MemoryStorage(StorageBackend):
         return self.normalize_raw_grants_page(
             raw_grants_page=raw_grants_page
         )
+
+
+    def record_grant_usage(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        grant_usage = self._grant_usage[effect]
+        for uuid, used_at in last_used.items():
+            if uuid not in grant_usage or used_at > grant_usage[uuid]:
+                grant_usage[uuid] = used_at
+
+
+    async def record_grant_usage_async(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        return self.record_grant_usage(effect=effect, last_used=last_used)
+
+
+    def get_grant_usage(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        return dict(self._grant_usage[effect])
+
+
+    async def get_grant_usage_async(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        return self.get_grant_usage(effect=effect)
+
+
+    def archive_grant(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        grant = self._grant_indexes[effect].remove(uuid=uuid)
+        if grant is None:
+            raise exceptions.GrantDoesNotExistError("{} Grant with UUID '{}' does not exist.".format(effect.value, uuid))
+
+        archived_grant = ArchivedGrant(grant=grant, archived_at=archived_at)
+        self._archived_grants[effect].append(archived_grant)
+
+        return copy.deepcopy(archived_grant)
+
+
+    async def archive_grant_async(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        return self.archive_grant(effect=effect, uuid=uuid, archived_at=archived_at)
+
+
+    def list_archived_grants(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        return copy.deepcopy(self._archived_grants[effect])
+
+
+    async def list_archived_grants_async(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        return self.list_archived_grants(effect=effect)
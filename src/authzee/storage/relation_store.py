@@ -0,0 +1,261 @@
+
+from collections import OrderedDict
+from typing import List, Optional, Set, Tuple
+
+from authzee import exceptions
+from authzee.relation_tuple import RelationTuple
+
+
+USERSET_SEPARATOR = "#"
+
+
+class RelationStore:
+    """Base class for ``RelationTuple`` storage.
+
+    Sub-classes must implement:
+
+        - ``add_relation`` - Add a relation tuple to storage.
+        - ``delete_relation`` - Delete a relation tuple from storage.
+        - ``list_relations`` - List the relation tuples for objects.
+
+    Sub-classes may also implement the lifecycle methods ``initialize`` , ``shutdown`` , ``setup`` and ``teardown`` ,
+    which are called by the ``Authzee`` methods of the same name.
+
+    Relation tuples are cached by object for ``get_relations`` , ``check`` and ``expand`` ,
+    when ``cache_size`` is greater than ``0`` .
+    ``Authzee.add_relation`` and ``Authzee.delete_relation`` clear the cache for the object of the relation tuple.
+    Call ``clear_cache`` when relation tuples are changed in another way, like by another process.
+
+    Subjects can be a set of subjects with a relation to another object,
+    written as ``<object>#<relation>`` , like ``"group:admins#member"`` .
+    These are followed by ``check`` and ``expand`` .
+
+    Parameters
+    ----------
+    cache_size : int, optional
+        Max number of objects to cache relation tuples for, by default 0 (no caching).
+    """
+
+    def __init__(self, cache_size: int = 0):
+        self.cache_size = cache_size
+        self._cache: OrderedDict = OrderedDict()
+
+
+    def initialize(self) -> None:
+        """Initialize the relation store.
+
+        All creation of connections to external storage should take place here.
+        """
+        pass
+
+
+    def shutdown(self) -> None:
+        """Early clean up of relation store resources.
+        """
+        pass
+
+
+    def setup(self) -> None:
+        """One time setup for relation store resources.
+        """
+        pass
+
+
+    def teardown(self) -> None:
+        """Teardown and delete the results of ``setup()`` .
+        """
+        pass
+
+
+    def add_relation(self, relation_tuple: RelationTuple) -> RelationTuple:
+        """Add a relation tuple.
+
+        Adding a relation tuple that is already stored should not raise an error.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            The relation tuple.
+
+        Returns
+        -------
+        RelationTuple
+            The relation tuple that has been added.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def delete_relation(self, relation_tuple: RelationTuple) -> None:
+        """Delete a relation tuple.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            The relation tuple to delete.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        authzee.exceptions.RelationDoesNotExistError
+            The relation tuple is not stored.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def list_relations(self, objects: List[str]) -> List[RelationTuple]:
+        """List the relation tuples for objects.
+
+        Parameters
+        ----------
+        objects : List[str]
+            The objects to list relation tuples for.
+
+        Returns
+        -------
+        List[RelationTuple]
+            Relation tuples with an ``object`` in ``objects`` .
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes must implement this method.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def get_relations(self, objects: List[str]) -> List[RelationTuple]:
+        """Get the relation tuples for objects, from the cache if possible.
+
+        Parameters
+        ----------
+        objects : List[str]
+            The objects to get relation tuples for.
+
+        Returns
+        -------
+        List[RelationTuple]
+            Relation tuples with an ``object`` in ``objects`` .
+        """
+        if self.cache_size < 1:
+            return self.list_relations(objects=objects)
+
+        objects = list(dict.fromkeys(objects))
+        uncached_objects = [obj for obj in objects if obj not in self._cache]
+        if len(uncached_objects) > 0:
+            fetched = {obj: [] for obj in uncached_objects}
+            for relation_tuple in self.list_relations(objects=uncached_objects):
+                fetched[relation_tuple.object].append(relation_tuple)
+
+            for obj, relation_tuples in fetched.items():
+                self._cache[obj] = relation_tuples
+
+        relation_tuples = []
+        for obj in objects:
+            if obj in self._cache:
+                self._cache.move_to_end(obj)
+                relation_tuples.extend(self._cache[obj])
+            else:
+                relation_tuples.extend(fetched[obj])
+
+        while len(self._cache) > self.cache_size:
+            self._cache.popitem(last=False)
+
+        return relation_tuples
+
+
+    def clear_cache(self, objects: Optional[List[str]] = None) -> None:
+        """Clear cached relation tuples.
+
+        Parameters
+        ----------
+        objects : Optional[List[str]], optional
+            Only clear the cache for these objects.
+            By default, the whole cache is cleared.
+        """
+        if objects is None:
+            self._cache.clear()
+
+            return
+
+        for obj in objects:
+            self._cache.pop(obj, None)
+
+
+    def check(self, subject: str, relation: str, object: str) -> bool:
+        """Check if a subject has a relation to an object, directly or through subject sets.
+
+        Parameters
+        ----------
+        subject : str
+            The subject, like ``"user:alice"`` .
+        relation : str
+            The relation, like ``"viewer"`` .
+        object : str
+            The object, like ``"folder:reports"`` .
+
+        Returns
+        -------
+        bool
+            ``True`` if the subject has the relation to the object.
+
+        Examples
+        --------
+        .. code-block:: python
+
+            store.add_relation(RelationTuple(subject="group:admins#member", relation="viewer", object="folder:reports"))
+            store.add_relation(RelationTuple(subject="user:alice", relation="member", object="group:admins"))
+            store.check(subject="user:alice", relation="viewer", object="folder:reports") # True
+
+        """
+        return subject in self._expand(object=object, relation=relation, leaves_only=False)
+
+
+    def expand(self, object: str, relation: str) -> Set[str]:
+        """Expand all of the subjects that have a relation to an object, following subject sets.
+
+        Subject sets themselves are not included in the results.
+
+        Parameters
+        ----------
+        object : str
+            The object, like ``"folder:reports"`` .
+        relation : str
+            The relation, like ``"viewer"`` .
+
+        Returns
+        -------
+        Set[str]
+            The subjects with the relation to the object.
+        """
+        return self._expand(object=object, relation=relation, leaves_only=True)
+
+
+    def _expand(self, object: str, relation: str, leaves_only: bool) -> Set[str]:
+        subjects: Set[str] = set()
+        visited: Set[Tuple[str, str]] = set()
+        to_visit: List[Tuple[str, str]] = [(object, relation)]
+        while len(to_visit) > 0:
+            current = to_visit.pop()
+            if current in visited:
+                continue
+
+            visited.add(current)
+            for relation_tuple in self.get_relations(objects=[current[0]]):
+                if relation_tuple.relation != current[1]:
+                    continue
+
+                if USERSET_SEPARATOR in relation_tuple.subject:
+                    userset_object, userset_relation = relation_tuple.subject.rsplit(USERSET_SEPARATOR, 1)
+                    to_visit.append((userset_object, userset_relation))
+                    if leaves_only is True:
+                        continue
+
+                subjects.add(relation_tuple.subject)
+
+        return subjects
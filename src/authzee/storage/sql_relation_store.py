@@ -0,0 +1,178 @@
+
+import asyncio
+from typing import Any, Dict, List
+
+from sqlalchemy import select
+from sqlalchemy.ext.asyncio import async_sessionmaker, AsyncSession, create_async_engine
+
+from authzee import exceptions
+from authzee.relation_tuple import RelationTuple
+from authzee.storage.relation_store import RelationStore
+from authzee.storage.sql_storage_models import Base, RelationTupleDB
+
+
+class SQLRelationStore(RelationStore):
+    """Store relation tuples in SQL RDBMS. 
+
+    Parameters
+    ----------
+    sqlalchemy_async_engine_kwargs : Dict[str, Any]
+        SQLAlchemy Async Engine keyword args. 
+        https://docs.sqlalchemy.org/en/20/orm/extensions/asyncio.html#sqlalchemy.ext.asyncio.create_async_engine
+
+    cache_size : int, optional
+        Max number of objects to cache relation tuples for, by default 0 (no caching).
+    """
+
+
+    def __init__(
+        self,
+        *,
+        sqlalchemy_async_engine_kwargs: Dict[str, Any],
+        cache_size: int = 0
+    ):
+        super().__init__(cache_size=cache_size)
+        self._sqlalchemy_async_engine_kwargs = sqlalchemy_async_engine_kwargs
+
+
+    def initialize(self) -> None:
+        """Initialize the SQL relation store. 
+
+        Should only be called by the ``Authzee`` app.
+        """
+        self._engine = create_async_engine(**self._sqlalchemy_async_engine_kwargs)
+        self._async_sessionmaker: async_sessionmaker[AsyncSession] = async_sessionmaker(
+            bind=self._engine, 
+            expire_on_commit=False
+        )
+
+
+    def shutdown(self) -> None:
+        """Early clean up of relation store resources.
+
+        Disposes of SQLAlchemy engine.
+        """
+        loop = asyncio.get_event_loop()
+        loop.run_until_complete(self._engine.dispose())
+
+
+    def setup(self) -> None:
+        """Create the relation tuple table.
+
+        Only run this once per configuration.
+        """
+        loop = asyncio.get_event_loop()
+        loop.run_until_complete(self.create_tables_async())
+
+
+    async def create_tables_async(self) -> None:
+        """Create the relation tuple table.
+        """
+        async with self._engine.begin() as conn:
+            await conn.run_sync(Base.metadata.create_all, tables=[RelationTupleDB.__table__])
+
+
+    def add_relation(self, relation_tuple: RelationTuple) -> RelationTuple:
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(self.add_relation_async(relation_tuple=relation_tuple))
+
+
+    async def add_relation_async(self, relation_tuple: RelationTuple) -> RelationTuple:
+        """Add a relation tuple.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            The relation tuple.
+
+        Returns
+        -------
+        RelationTuple
+            The relation tuple that has been added.
+        """
+        async with self._async_sessionmaker() as session:
+            await session.merge(
+                RelationTupleDB(
+                    subject=relation_tuple.subject,
+                    relation=relation_tuple.relation,
+                    object=relation_tuple.object
+                )
+            )
+            await session.commit()
+
+        return relation_tuple.copy()
+
+
+    def delete_relation(self, relation_tuple: RelationTuple) -> None:
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(self.delete_relation_async(relation_tuple=relation_tuple))
+
+
+    async def delete_relation_async(self, relation_tuple: RelationTuple) -> None:
+        """Delete a relation tuple.
+
+        Parameters
+        ----------
+        relation_tuple : RelationTuple
+            The relation tuple to delete.
+
+        Raises
+        ------
+        authzee.exceptions.RelationDoesNotExistError
+            The relation tuple is not stored.
+        """
+        async with self._async_sessionmaker() as session:
+            db_relation = await session.get(
+                RelationTupleDB,
+                (relation_tuple.subject, relation_tuple.relation, relation_tuple.object)
+            )
+            if db_relation is None:
+                raise exceptions.RelationDoesNotExistError(
+                    "Relation '{}' of '{}' to '{}' does not exist.".format(
+                        relation_tuple.relation,
+                        relation_tuple.subject,
+                        relation_tuple.object
+                    )
+                )
+
+            await session.delete(db_relation)
+            await session.commit()
+
+
+    def list_relations(self, objects: List[str]) -> List[RelationTuple]:
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(self.list_relations_async(objects=objects))
+
+
+    async def list_relations_async(self, objects: List[str]) -> List[RelationTuple]:
+        """List the relation tuples for objects.
+
+        Parameters
+        ----------
+        objects : List[str]
+            The objects to list relation tuples for.
+
+        Returns
+        -------
+        List[RelationTuple]
+            Relation tuples with an ``object`` in ``objects`` .
+        """
+        async with self._async_sessionmaker() as session:
+            result = await session.execute(
+                select(RelationTupleDB).where(
+                    RelationTupleDB.object.in_(set(objects))
+                ).order_by(
+                    RelationTupleDB.object,
+                    RelationTupleDB.subject,
+                    RelationTupleDB.relation
+                )
+            )
+
+            return [
+                RelationTuple(
+                    subject=db_relation.subject,
+                    relation=db_relation.relation,
+                    object=db_relation.object
+                )
+                for db_relation in result.scalars().fetchall()
+            ]
@@ -1,24 +1,33 @@
 
 import asyncio
+import contextlib
+import datetime
 import json
-from typing import Any, Dict, List, Optional, Set, Type, Union
+from typing import Any, AsyncIterator, Dict, List, Optional, Set, Type, Union
 
 from pydantic import BaseModel
-from sqlalchemy import event, select
+from sqlalchemy import delete, event, func, select
+from sqlalchemy.exc import SQLAlchemyError
 from sqlalchemy.ext.asyncio import async_sessionmaker, AsyncSession, create_async_engine
 
 from authzee import exceptions
+from authzee.archived_grant import ArchivedGrant
 from authzee.backend_locality import BackendLocality
 from authzee.grant import Grant
 from authzee.grant_effect import GrantEffect
+from authzee.grant_uuids_page import GrantUUIDsPage
+from authzee.grant_match_mode import GrantMatchMode
+from authzee.grant_provenance import GrantProvenance
 from authzee.grants_page import GrantsPage
 from authzee.raw_grants_page import RawGrantsPage
 from authzee.resource_action import ResourceAction
 from authzee.resource_authz import ResourceAuthz
 from authzee.storage.sql_storage_models import (
     AllowGrantDB, 
+    ArchivedGrantDB, 
     Base, 
     DenyGrantDB, 
+    GrantUsageDB, 
     ResourceActionDB, 
     ResourceTypeDB
 )
@@ -28,6 +37,7 @@ from authzee.storage.storage_backend import StorageBackend
 class SQLNextPageRef(BaseModel):
 
     next_token: int
+    snapshot_storage_id: Optional[int] = None
 
 
 class SQLStorage(StorageBackend):
@@ -41,6 +51,9 @@ class SQLStorage(StorageBackend):
 
     default_page_size : int, default: 1000
         The default page size when for calls when page size is not specified.
+
+    **NOTE** - ``setup()`` only creates missing tables. It does not add new columns to existing tables.
+    See the changelog for the schema changes to apply to existing databases when upgrading.
     """
 
 
@@ -79,6 +92,9 @@ class SQLStorage(StorageBackend):
             backend_locality=locality,
             compatible_localities=compute_localities,
             default_page_size=default_page_size,
+            supports_parallel_pagination=True,
+            supports_uuid_pages=True,
+            supports_snapshots=True,
             sqlalchemy_async_engine_kwargs=sqlalchemy_async_engine_kwargs
         )
         self._sqlalchemy_async_engine_kwargs = sqlalchemy_async_engine_kwargs
@@ -154,7 +170,7 @@ class SQLStorage(StorageBackend):
         async with self._engine.begin() as conn:
             await conn.run_sync(Base.metadata.create_all)
  
-        async with self._async_sessionmaker() as session:
+        async with self._session() as session:
             for rt_str in self._resource_type_lookup:
                 session.add(ResourceTypeDB(resource_type=rt_str))
             
@@ -199,7 +215,7 @@ class SQLStorage(StorageBackend):
             The grant that has been added with additional information for the specific backend.
         """
         grant = self._check_uuid(grant=grant, generate_uuid=True)
-        async with self._async_sessionmaker() as session:
+        async with self._session() as session:
             resource_action_strs = {str(action) for action in grant.resource_actions}
             result = await session.execute(
                 select(ResourceActionDB).where(
@@ -214,7 +230,19 @@ class SQLStorage(StorageBackend):
                 "resource_type": grant.resource_type.__name__,
                 "resource_actions": re_actions,
                 "jmespath_expression": grant.jmespath_expression,
-                "result_match": json.dumps(grant.result_match)
+                "result_match": json.dumps(grant.result_match),
+                "order_insensitive_arrays": grant.order_insensitive_arrays,
+                "numeric_tolerance": grant.numeric_tolerance,
+                "match_mode": grant.match_mode.value,
+                "applies_to_descendants": grant.applies_to_descendants,
+                "provenance": grant.provenance.json() if grant.provenance is not None else None,
+                "tags": json.dumps(grant.tags),
+                "canary_sample_rate": grant.canary_sample_rate,
+                # stored as naive UTC, because not every database keeps time zones
+                "created_at": (
+                    grant.created_at.astimezone(datetime.timezone.utc).replace(tzinfo=None)
+                    if grant.created_at is not None else None
+                )
             }
             if effect is GrantEffect.ALLOW:
                 db_grant = AllowGrantDB(**grant_kwargs)
@@ -257,7 +285,7 @@ class SQLStorage(StorageBackend):
         uuid : str
             UUID of grant to delete.
         """
-        async with self._async_sessionmaker() as session:
+        async with self._session() as session:
             if effect is GrantEffect.ALLOW:
                 grant_table = AllowGrantDB
             else:
@@ -276,6 +304,12 @@ class SQLStorage(StorageBackend):
                 )
 
             await session.delete(db_grant)
+            await session.execute(
+                delete(GrantUsageDB).where(
+                    GrantUsageDB.effect == effect.value,
+                    GrantUsageDB.uuid == uuid
+                )
+            )
             await session.commit()
 
 
@@ -372,40 +406,37 @@ class SQLStorage(StorageBackend):
             The page of raw grants.
         """
         page_size = self._real_page_size(page_size=page_size)
-        async with self._async_sessionmaker() as session:
-            if effect is GrantEffect.ALLOW:
-                grant_table = AllowGrantDB
-            else:
-                grant_table = DenyGrantDB
-
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
             query = select(grant_table)
-            filters = []
-            if resource_type is not None:
-                filters.append(
-                    grant_table.resource_type == resource_type.__name__
-                )
-            
-            if resource_action is not None:
-                filters.append(
-                    grant_table.resource_actions.any(
-                        ResourceActionDB.resource_action == str(resource_action)
-                    )
-                )
-
+            filters = self._grant_filters(
+                grant_table=grant_table,
+                resource_type=resource_type,
+                resource_action=resource_action
+            )
+            sql_next_page = None
             if next_page_reference is not None:
                 sql_next_page = SQLNextPageRef(**json.loads(next_page_reference))
                 filters.append(
                     grant_table.storage_id > sql_next_page.next_token
                 )
+                if sql_next_page.snapshot_storage_id is not None:
+                    filters.append(
+                        grant_table.storage_id <= sql_next_page.snapshot_storage_id
+                    )
             
             query = query.where(*filters)
+            query = query.order_by(grant_table.storage_id)
             query = query.limit(page_size)
 
             result = await session.execute(query)
             db_grants = result.scalars().unique().all()
             next_page_ref = None
             if len(db_grants) >= page_size:
-                next_page_ref = SQLNextPageRef(next_token=db_grants[-1].storage_id).model_dump_json()
+                next_page_ref = SQLNextPageRef(
+                    next_token=db_grants[-1].storage_id,
+                    snapshot_storage_id=sql_next_page.snapshot_storage_id if sql_next_page is not None else None
+                ).model_dump_json()
 
         return RawGrantsPage(
             raw_grants=db_grants,
@@ -413,6 +444,96 @@ class SQLStorage(StorageBackend):
         )
 
 
+    def list_page_tokens(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None
+    ) -> List[Optional[str]]:
+        """List the next page references for every page of raw grants matching the filters.
+
+        Only the storage IDs of the grants are queried.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The page size. 
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[Optional[str]]
+            The next page reference for every page, starting with ``None`` for the first page.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.list_page_tokens_async(
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size
+            )
+        )
+
+
+    async def list_page_tokens_async(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None
+    ) -> List[Optional[str]]:
+        """List the next page references for every page of raw grants matching the filters.
+
+        Only the storage IDs of the grants are queried.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The page size. 
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[Optional[str]]
+            The next page reference for every page, starting with ``None`` for the first page.
+        """
+        page_size = self._real_page_size(page_size=page_size)
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
+            query = select(grant_table.storage_id).where(
+                *self._grant_filters(
+                    grant_table=grant_table,
+                    resource_type=resource_type,
+                    resource_action=resource_action
+                )
+            ).order_by(grant_table.storage_id)
+            result = await session.execute(query)
+            storage_ids = result.scalars().all()
+
+        return [None] + [
+            SQLNextPageRef(next_token=storage_ids[i - 1]).model_dump_json()
+            for i in range(page_size, len(storage_ids), page_size)
+        ]
+
+
     def normalize_raw_grants_page(
         self,
         raw_grants_page: RawGrantsPage
@@ -442,6 +563,20 @@ class SQLStorage(StorageBackend):
                     },
                     jmespath_expression=db_grant.jmespath_expression,
                     result_match=json.loads(db_grant.result_match),
+                    order_insensitive_arrays=db_grant.order_insensitive_arrays,
+                    numeric_tolerance=db_grant.numeric_tolerance,
+                    match_mode=GrantMatchMode(db_grant.match_mode),
+                    applies_to_descendants=db_grant.applies_to_descendants,
+                    provenance=(
+                        GrantProvenance.parse_raw(db_grant.provenance)
+                        if db_grant.provenance is not None else None
+                    ),
+                    tags=json.loads(db_grant.tags),
+                    canary_sample_rate=db_grant.canary_sample_rate,
+                    created_at=(
+                        db_grant.created_at.replace(tzinfo=datetime.timezone.utc)
+                        if db_grant.created_at is not None else None
+                    ),
                     storage_id=str(db_grant.storage_id),
                     uuid=db_grant.uuid
                 )
@@ -461,3 +596,492 @@ class SQLStorage(StorageBackend):
             raw_grants_page=raw_grants_page
         )
 
+
+    def get_grant_uuids_page(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantUUIDsPage:
+        """Retrieve a page of the UUIDs of grants matching the filters.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size to return. 
+            The default is set on the storage backend. 
+        next_page_reference : Optional[str], optional
+            The reference to the next page that is returned in ``GrantUUIDsPage``.
+            By default this will return the first page.
+
+        Returns
+        -------
+        GrantUUIDsPage
+            The page of grant UUIDs.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.get_grant_uuids_page_async(
+                effect=effect,
+                resource_type=resource_type,
+                resource_action=resource_action,
+                page_size=page_size,
+                next_page_reference=next_page_reference
+            )
+        )
+
+
+    async def get_grant_uuids_page_async(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantUUIDsPage:
+        """Retrieve a page of the UUIDs of grants matching the filters.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size to return. 
+            The default is set on the storage backend. 
+        next_page_reference : Optional[str], optional
+            The reference to the next page that is returned in ``GrantUUIDsPage``.
+            By default this will return the first page.
+
+        Returns
+        -------
+        GrantUUIDsPage
+            The page of grant UUIDs.
+        """
+        page_size = self._real_page_size(page_size=page_size)
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
+            filters = self._grant_filters(
+                grant_table=grant_table,
+                resource_type=resource_type,
+                resource_action=resource_action
+            )
+            sql_next_page = None
+            if next_page_reference is not None:
+                sql_next_page = SQLNextPageRef(**json.loads(next_page_reference))
+                filters.append(
+                    grant_table.storage_id > sql_next_page.next_token
+                )
+                if sql_next_page.snapshot_storage_id is not None:
+                    filters.append(
+                        grant_table.storage_id <= sql_next_page.snapshot_storage_id
+                    )
+
+            query = select(grant_table.storage_id, grant_table.uuid).where(*filters)
+            query = query.order_by(grant_table.storage_id).limit(page_size)
+            result = await session.execute(query)
+            rows = result.all()
+            next_page_ref = None
+            if len(rows) >= page_size:
+                next_page_ref = SQLNextPageRef(
+                    next_token=rows[-1].storage_id,
+                    snapshot_storage_id=sql_next_page.snapshot_storage_id if sql_next_page is not None else None
+                ).model_dump_json()
+
+        return GrantUUIDsPage(
+            uuids=[row.uuid for row in rows],
+            next_page_reference=next_page_ref
+        )
+
+
+    def get_grants_by_uuids(self, effect: GrantEffect, uuids: List[str]) -> List[Grant]:
+        """Retrieve grants by UUID.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        uuids : List[str]
+            UUIDs of the grants to retrieve.
+
+        Returns
+        -------
+        List[Grant]
+            The grants in the order of ``uuids`` .
+            Grants that do not exist are left out.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(self.get_grants_by_uuids_async(effect=effect, uuids=uuids))
+
+
+    async def get_grants_by_uuids_async(self, effect: GrantEffect, uuids: List[str]) -> List[Grant]:
+        """Retrieve grants by UUID.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        uuids : List[str]
+            UUIDs of the grants to retrieve.
+
+        Returns
+        -------
+        List[Grant]
+            The grants in the order of ``uuids`` .
+            Grants that do not exist are left out.
+        """
+        if len(uuids) == 0:
+            return []
+
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
+            result = await session.execute(
+                select(grant_table).where(grant_table.uuid.in_(uuids))
+            )
+            db_grants = result.scalars().unique().all()
+
+        grants_page = self.normalize_raw_grants_page(
+            raw_grants_page=RawGrantsPage(raw_grants=db_grants, next_page_reference=None)
+        )
+        grants_lookup = {grant.uuid: grant for grant in grants_page.grants}
+
+        return [grants_lookup[uuid] for uuid in uuids if uuid in grants_lookup]
+
+
+    def create_snapshot(self, effect: GrantEffect) -> str:
+        """Create a snapshot of the grants currently in storage.
+
+        The snapshot is a page reference for the first page.
+        Pass it as ``next_page_reference`` to ``get_raw_grants_page`` or ``get_grant_uuids_page`` ,
+        and every following page reference stays in the snapshot,
+        so grants that are added while the pages are read are not returned by any page.
+
+        **NOTE** - Grants that are deleted while the pages are read are not returned by the following pages.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        str
+            The page reference for the first page of the snapshot.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.create_snapshot_async(effect=effect)
+        )
+
+
+    async def create_snapshot_async(self, effect: GrantEffect) -> str:
+        """Create a snapshot of the grants currently in storage.
+
+        The snapshot is a page reference for the first page.
+        Pass it as ``next_page_reference`` to ``get_raw_grants_page_async`` or ``get_grant_uuids_page_async`` ,
+        and every following page reference stays in the snapshot,
+        so grants that are added while the pages are read are not returned by any page.
+
+        **NOTE** - Grants that are deleted while the pages are read are not returned by the following pages.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        str
+            The page reference for the first page of the snapshot.
+        """
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
+            result = await session.execute(select(func.max(grant_table.storage_id)))
+            max_storage_id = result.scalar()
+
+        # Storage IDs only increase, the grant tables are AUTOINCREMENT on SQLite so the IDs of deleted grants are not reused.
+        # Grants added after the snapshot are above the max.
+        return SQLNextPageRef(
+            next_token=0,
+            snapshot_storage_id=max_storage_id if max_storage_id is not None else 0
+        ).model_dump_json()
+
+
+    def record_grant_usage(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        """Store when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        last_used : Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.record_grant_usage_async(
+                effect=effect,
+                last_used=last_used
+            )
+        )
+
+
+    async def record_grant_usage_async(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        """Store when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        last_used : Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+        """
+        async with self._session() as session:
+            result = await session.execute(
+                select(GrantUsageDB).where(
+                    GrantUsageDB.effect == effect.value,
+                    GrantUsageDB.uuid.in_(list(last_used))
+                )
+            )
+            db_usages = {db_usage.uuid: db_usage for db_usage in result.scalars().fetchall()}
+            for uuid, used_at in last_used.items():
+                # stored as naive UTC, because not every database keeps time zones
+                used_at = used_at.astimezone(datetime.timezone.utc).replace(tzinfo=None)
+                if uuid not in db_usages:
+                    session.add(GrantUsageDB(effect=effect.value, uuid=uuid, last_used=used_at))
+                elif used_at > db_usages[uuid].last_used:
+                    db_usages[uuid].last_used = used_at
+
+            await session.commit()
+
+
+    def get_grant_usage(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID. Grants that were never used are not included.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(self.get_grant_usage_async(effect=effect))
+
+
+    async def get_grant_usage_async(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID. Grants that were never used are not included.
+        """
+        async with self._session() as session:
+            result = await session.execute(
+                select(GrantUsageDB).where(GrantUsageDB.effect == effect.value)
+            )
+
+            return {
+                db_usage.uuid: db_usage.last_used.replace(tzinfo=datetime.timezone.utc)
+                for db_usage in result.scalars().fetchall()
+            }
+
+
+    def archive_grant(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        """Move a grant to the archive table.
+
+        The grant is no longer returned by the grant pages, but is kept for ``list_archived_grants`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        uuid : str
+            UUID of the grant to archive.
+        archived_at : datetime.datetime
+            When the grant was archived.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.archive_grant_async(effect=effect, uuid=uuid, archived_at=archived_at)
+        )
+
+
+    async def archive_grant_async(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        """Move a grant to the archive table.
+
+        The grant is no longer returned by the grant pages, but is kept for ``list_archived_grants_async`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        uuid : str
+            UUID of the grant to archive.
+        archived_at : datetime.datetime
+            When the grant was archived.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+        """
+        async with self._session() as session:
+            grant_table = self._grant_table(effect=effect)
+            result = await session.execute(
+                select(grant_table).where(grant_table.uuid == uuid)
+            )
+            db_grant = result.scalars().unique().one_or_none()
+            if db_grant is None:
+                raise exceptions.GrantDoesNotExistError(
+                    "{} Grant with UUID: '{}' does not exist.".format(
+                        effect.value,
+                        uuid
+                    )
+                )
+
+            grant = self.normalize_raw_grants_page(
+                raw_grants_page=RawGrantsPage(raw_grants=[db_grant], next_page_reference=None)
+            ).grants[0]
+            grant_data = json.loads(grant.json(exclude={"resource_type", "resource_actions"}))
+            grant_data['resource_type'] = grant.resource_type.__name__
+            grant_data['resource_actions'] = sorted(str(action) for action in grant.resource_actions)
+            session.add(
+                ArchivedGrantDB(
+                    effect=effect.value,
+                    uuid=uuid,
+                    grant=json.dumps(grant_data),
+                    # stored as naive UTC, because not every database keeps time zones
+                    archived_at=archived_at.astimezone(datetime.timezone.utc).replace(tzinfo=None)
+                )
+            )
+            await session.delete(db_grant)
+            await session.commit()
+
+        return ArchivedGrant(grant=grant, archived_at=archived_at)
+
+
+    def list_archived_grants(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+        """
+        loop = asyncio.get_event_loop()
+        return loop.run_until_complete(
+            self.list_archived_grants_async(effect=effect)
+        )
+
+
+    async def list_archived_grants_async(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+        """
+        async with self._session() as session:
+            result = await session.execute(
+                select(ArchivedGrantDB).where(
+                    ArchivedGrantDB.effect == effect.value
+                ).order_by(ArchivedGrantDB.storage_id)
+            )
+            db_archived_grants = result.scalars().fetchall()
+
+        archived_grants = []
+        for db_archived_grant in db_archived_grants:
+            grant_data = json.loads(db_archived_grant.grant)
+            grant_data['resource_type'] = self._resource_type_lookup[grant_data['resource_type']]
+            grant_data['resource_actions'] = {
+                self._resource_action_lookup[action] for action in grant_data['resource_actions']
+            }
+            archived_grants.append(
+                ArchivedGrant(
+                    grant=Grant(**grant_data),
+                    archived_at=db_archived_grant.archived_at.replace(tzinfo=datetime.timezone.utc)
+                )
+            )
+
+        return archived_grants
+
+
+    @contextlib.asynccontextmanager
+    async def _session(self) -> AsyncIterator[AsyncSession]:
+        """Open a session, and raise SQLAlchemy errors as ``authzee.exceptions.StorageBackendError`` .
+        """
+        try:
+            async with self._async_sessionmaker() as session:
+                yield session
+        except SQLAlchemyError as error:
+            raise exceptions.StorageBackendError("The SQL storage failed: {!r}".format(error)) from error
+
+
+    def _grant_table(self, effect: GrantEffect) -> Type[Union[AllowGrantDB, DenyGrantDB]]:
+        if effect is GrantEffect.ALLOW:
+            return AllowGrantDB
+
+        return DenyGrantDB
+
+
+    def _grant_filters(
+        self,
+        grant_table: Type[Union[AllowGrantDB, DenyGrantDB]],
+        resource_type: Optional[Type[BaseModel]],
+        resource_action: Optional[ResourceAction]
+    ) -> List[Any]:
+        filters = []
+        if resource_type is not None:
+            filters.append(
+                grant_table.resource_type == resource_type.__name__
+            )
+        
+        if resource_action is not None:
+            filters.append(
+                grant_table.resource_actions.any(
+                    ResourceActionDB.resource_action == str(resource_action)
+                )
+            )
+
+        return filters
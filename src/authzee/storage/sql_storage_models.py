@@ -1,7 +1,8 @@
 
-from typing import Set
+import datetime
+from typing import Optional, Set
 
-from sqlalchemy import Column, ForeignKey, Table
+from sqlalchemy import Column, ForeignKey, Table, false
 from sqlalchemy.ext.asyncio import AsyncAttrs
 from sqlalchemy.orm import DeclarativeBase, Mapped, mapped_column, relationship
 
@@ -35,6 +36,8 @@ allow_grant_action_association = Table(
 class AllowGrantDB(Base):
 
     __tablename__ = "allow_grant"
+    # Never reuse the storage ID of a deleted grant, snapshots rely on storage IDs only increasing
+    __table_args__ = {"sqlite_autoincrement": True}
 
     storage_id: Mapped[int] = mapped_column(primary_key=True, nullable=False)
     uuid: Mapped[str] = mapped_column(unique=True, nullable=False)
@@ -49,6 +52,15 @@ class AllowGrantDB(Base):
     )
     jmespath_expression: Mapped[str] = mapped_column(nullable=False)
     result_match: Mapped[str] = mapped_column(nullable=False)
+    # Columns added after 0.1.0a2 have server defaults or are nullable, so they can be added to existing tables
+    order_insensitive_arrays: Mapped[bool] = mapped_column(nullable=False, default=False, server_default=false())
+    numeric_tolerance: Mapped[Optional[float]] = mapped_column(nullable=True)
+    match_mode: Mapped[str] = mapped_column(nullable=False, default="EQUAL", server_default="EQUAL")
+    applies_to_descendants: Mapped[bool] = mapped_column(nullable=False, default=False, server_default=false())
+    provenance: Mapped[Optional[str]] = mapped_column(nullable=True)
+    tags: Mapped[str] = mapped_column(nullable=False, default="{}", server_default="{}")
+    canary_sample_rate: Mapped[Optional[float]] = mapped_column(nullable=True)
+    created_at: Mapped[Optional[datetime.datetime]] = mapped_column(nullable=True)
 
 
 deny_grant_action_association = Table(
@@ -62,6 +74,8 @@ deny_grant_action_association = Table(
 class DenyGrantDB(Base):
 
     __tablename__ = "deny_grant"
+    # Never reuse the storage ID of a deleted grant, snapshots rely on storage IDs only increasing
+    __table_args__ = {"sqlite_autoincrement": True}
 
     storage_id: Mapped[int] = mapped_column(primary_key=True, nullable=False)
     uuid: Mapped[str] = mapped_column(unique=True, nullable=False)
@@ -76,4 +90,41 @@ class DenyGrantDB(Base):
     )
     jmespath_expression: Mapped[str] = mapped_column(nullable=False)
     result_match: Mapped[str] = mapped_column(nullable=False)
+    # Columns added after 0.1.0a2 have server defaults or are nullable, so they can be added to existing tables
+    order_insensitive_arrays: Mapped[bool] = mapped_column(nullable=False, default=False, server_default=false())
+    numeric_tolerance: Mapped[Optional[float]] = mapped_column(nullable=True)
+    match_mode: Mapped[str] = mapped_column(nullable=False, default="EQUAL", server_default="EQUAL")
+    applies_to_descendants: Mapped[bool] = mapped_column(nullable=False, default=False, server_default=false())
+    provenance: Mapped[Optional[str]] = mapped_column(nullable=True)
+    tags: Mapped[str] = mapped_column(nullable=False, default="{}", server_default="{}")
+    canary_sample_rate: Mapped[Optional[float]] = mapped_column(nullable=True)
+    created_at: Mapped[Optional[datetime.datetime]] = mapped_column(nullable=True)
 
+
+class ArchivedGrantDB(Base):
+
+    __tablename__ = "archived_grant"
+
+    storage_id: Mapped[int] = mapped_column(primary_key=True, nullable=False)
+    effect: Mapped[str] = mapped_column(nullable=False, index=True)
+    uuid: Mapped[str] = mapped_column(nullable=False)
+    grant: Mapped[str] = mapped_column(nullable=False) # grant as a json string
+    archived_at: Mapped[datetime.datetime] = mapped_column(nullable=False)
+
+
+class GrantUsageDB(Base):
+
+    __tablename__ = "grant_usage"
+
+    effect: Mapped[str] = mapped_column(primary_key=True)
+    uuid: Mapped[str] = mapped_column(primary_key=True)
+    last_used: Mapped[datetime.datetime] = mapped_column(nullable=False)
+
+
+class RelationTupleDB(Base):
+
+    __tablename__ = "relation_tuple"
+
+    subject: Mapped[str] = mapped_column(primary_key=True)
+    relation: Mapped[str] = mapped_column(primary_key=True)
+    object: Mapped[str] = mapped_column(primary_key=True, index=True)
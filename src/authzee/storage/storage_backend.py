@@ -1,14 +1,17 @@
 
 import copy
-from typing import List, Optional, Set, Type, Union
+import datetime
+from typing import Dict, List, Optional, Set, Type, Union
 import uuid
 
 from pydantic import BaseModel
 
 from authzee import exceptions
+from authzee.archived_grant import ArchivedGrant
 from authzee.backend_locality import BackendLocality
 from authzee.grant import Grant
 from authzee.grant_effect import GrantEffect
+from authzee.grant_uuids_page import GrantUUIDsPage
 from authzee.grants_page import GrantsPage
 from authzee.raw_grants_page import RawGrantsPage
 from authzee.resource_action import ResourceAction
@@ -36,6 +39,33 @@ class StorageBackend:
         - ``get_grants_page_async``
         - ``normalize_raw_grants_page_async`` - Convert the raw storage grants to a list of ``Grant`` models.
 
+    Optionally grant usage may be stored, for ``AuthzeeConfig.track_grant_usage`` .
+
+        - ``record_grant_usage`` and ``record_grant_usage_async`` - Store when grants were last used.
+        - ``get_grant_usage`` and ``get_grant_usage_async`` - Get when grants were last used.
+
+    Optionally grants may be moved to an archive, that is not evaluated but kept for audits.
+
+        - ``archive_grant`` and ``archive_grant_async`` - Move a grant to the archive.
+        - ``list_archived_grants`` and ``list_archived_grants_async`` - List the archived grants.
+
+    Optionally the references to every page may be listed up front, so compute workers can fetch pages concurrently.
+    Set ``supports_parallel_pagination=True`` when they are implemented.
+
+        - ``list_page_tokens`` and ``list_page_tokens_async`` - List the next page references for every page.
+
+    Optionally pages of grant UUIDs may be retrieved, and grants fetched by UUID,
+    so compute workers can fetch the grants themselves.
+    Set ``supports_uuid_pages=True`` when they are implemented.
+
+        - ``get_grant_uuids_page`` and ``get_grant_uuids_page_async`` - Retrieve a page of grant UUIDs.
+        - ``get_grants_by_uuids`` and ``get_grants_by_uuids_async`` - Retrieve grants by UUID.
+
+    Optionally a snapshot of the grants may be created, so every page of a listing is read from one version of the grants.
+    Set ``supports_snapshots=True`` when they are implemented.
+
+        - ``create_snapshot`` and ``create_snapshot_async`` - Create a page reference for the first page of a snapshot.
+
     The sub-class must also set the class vars:
 
         - ``async_enabled`` - The class has all ``async`` methods available.
@@ -43,6 +73,10 @@ class StorageBackend:
 
     No error checking should be needed for validation of resources, resource_types etc. That should all be handled by ``Authzee``.
 
+    When storage fails, like when it is unavailable, raise ``authzee.exceptions.StorageBackendError`` 
+    instead of the errors of the storage client, so the ``FailPolicy`` can decide the request.
+    Other errors are raised to the caller.
+
     Storage backends should store all arguments to the ``__init__`` method in ``self.kwargs``, 
     and all arguments to the ``initialize`` method in ``self.initialize_kwargs``.  
     These should be available if the compute backend needs to instantiate more instances of the storage backend.
@@ -62,6 +96,16 @@ class StorageBackend:
         This parameter should not be exposed on the child class.
     default_page_size : int
         For methods that accept ``page_size``, this will be used as the default.
+    supports_parallel_pagination : bool, optional
+        This instance of the storage backend implements ``list_page_tokens`` , by default ``False`` .
+        This parameter should not be exposed on the child class.
+    supports_uuid_pages : bool, optional
+        This instance of the storage backend implements ``get_grant_uuids_page`` and ``get_grants_by_uuids`` ,
+        by default ``False`` .
+        This parameter should not be exposed on the child class.
+    supports_snapshots : bool, optional
+        This instance of the storage backend implements ``create_snapshot`` , by default ``False`` .
+        This parameter should not be exposed on the child class.
     """
 
 
@@ -72,17 +116,31 @@ class StorageBackend:
         backend_locality: BackendLocality,
         compatible_localities: Set[BackendLocality],
         default_page_size: int, 
+        supports_parallel_pagination: bool = False,
+        supports_uuid_pages: bool = False,
+        supports_snapshots: bool = False,
         **kwargs
     ):
         self.async_enabled = async_enabled
         self.backend_locality = backend_locality
         self.compatible_localities = compatible_localities
+        self.supports_parallel_pagination = supports_parallel_pagination
+        self.supports_uuid_pages = supports_uuid_pages
+        self.supports_snapshots = supports_snapshots
         # Reassign all to a method with a better error
         if async_enabled is False:
             self.add_grant_async = self._async_not_supported
             self.delete_grant_async = self._async_not_supported
             self.get_raw_grants_page_async = self._async_not_supported
             self.normalize_raw_grants_page_async = self._async_not_supported
+            self.record_grant_usage_async = self._async_not_supported
+            self.get_grant_usage_async = self._async_not_supported
+            self.list_page_tokens_async = self._async_not_supported
+            self.get_grant_uuids_page_async = self._async_not_supported
+            self.get_grants_by_uuids_async = self._async_not_supported
+            self.create_snapshot_async = self._async_not_supported
+            self.archive_grant_async = self._async_not_supported
+            self.list_archived_grants_async = self._async_not_supported
 
         self.default_page_size = default_page_size
         self.kwargs = kwargs
@@ -360,6 +418,456 @@ class StorageBackend:
         raise exceptions.MethodNotImplementedError()
 
 
+    def list_page_tokens(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None
+    ) -> List[Optional[str]]:
+        """List the next page references for every page of raw grants matching the filters.
+
+        The first reference is ``None`` for the first page.
+        Each reference can be passed as ``next_page_reference`` to ``get_raw_grants_page`` ,
+        with the same filters and ``page_size`` , to fetch the pages concurrently.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size. 
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[Optional[str]]
+            The next page reference for every page, in storage order.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method and set ``supports_parallel_pagination=True`` .
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def list_page_tokens_async(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None
+    ) -> List[Optional[str]]:
+        """List the next page references for every page of raw grants matching the filters.
+
+        The first reference is ``None`` for the first page.
+        Each reference can be passed as ``next_page_reference`` to ``get_raw_grants_page_async`` ,
+        with the same filters and ``page_size`` , to fetch the pages concurrently.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size. 
+            The default is set on the storage backend. 
+
+        Returns
+        -------
+        List[Optional[str]]
+            The next page reference for every page, in storage order.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and parallel pagination are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def get_grant_uuids_page(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantUUIDsPage:
+        """Retrieve a page of the UUIDs of grants matching the filters.
+
+        Pages are the same as ``get_raw_grants_page`` , without retrieving the grants.
+        Use ``get_grants_by_uuids`` to retrieve the grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size to return. 
+            There is no guarantee of how much data will be returned if any.
+            The default is set on the storage backend. 
+        next_page_reference : Optional[str], optional
+            The reference to the next page that is returned in ``GrantUUIDsPage``.
+            By default this will return the first page.
+
+        Returns
+        -------
+        GrantUUIDsPage
+            The page of grant UUIDs.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method and set ``supports_uuid_pages=True`` .
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def get_grant_uuids_page_async(
+        self,
+        effect: GrantEffect,
+        resource_type: Optional[Type[BaseModel]] = None,
+        resource_action: Optional[ResourceAction] = None,
+        page_size: Optional[int] = None,
+        next_page_reference: Optional[str] = None
+    ) -> GrantUUIDsPage:
+        """Retrieve a page of the UUIDs of grants matching the filters.
+
+        Pages are the same as ``get_raw_grants_page_async`` , without retrieving the grants.
+        Use ``get_grants_by_uuids_async`` to retrieve the grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        resource_type : Optional[Type[BaseModel]], optional
+            Filter by resource type.
+            By default no filter is applied.
+        resource_action : Optional[ResourceAction], optional
+            Filter by `ResourceAction``. 
+            By default no filter is applied.
+        page_size : Optional[int], optional
+            The suggested page size to return. 
+            There is no guarantee of how much data will be returned if any.
+            The default is set on the storage backend. 
+        next_page_reference : Optional[str], optional
+            The reference to the next page that is returned in ``GrantUUIDsPage``.
+            By default this will return the first page.
+
+        Returns
+        -------
+        GrantUUIDsPage
+            The page of grant UUIDs.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and UUID pages are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def get_grants_by_uuids(self, effect: GrantEffect, uuids: List[str]) -> List[Grant]:
+        """Retrieve grants by UUID.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        uuids : List[str]
+            UUIDs of the grants to retrieve.
+
+        Returns
+        -------
+        List[Grant]
+            The grants in the order of ``uuids`` .
+            Grants that do not exist are left out.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method and set ``supports_uuid_pages=True`` .
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def get_grants_by_uuids_async(self, effect: GrantEffect, uuids: List[str]) -> List[Grant]:
+        """Retrieve grants by UUID.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        uuids : List[str]
+            UUIDs of the grants to retrieve.
+
+        Returns
+        -------
+        List[Grant]
+            The grants in the order of ``uuids`` .
+            Grants that do not exist are left out.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and UUID pages are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def create_snapshot(self, effect: GrantEffect) -> str:
+        """Create a snapshot of the grants currently in storage.
+
+        The snapshot is a page reference for the first page.
+        Pass it as ``next_page_reference`` to ``get_raw_grants_page`` or ``get_grant_uuids_page`` ,
+        and every following page reference stays in the snapshot,
+        so grants that are added while the pages are read are not returned by any page.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        str
+            The page reference for the first page of the snapshot.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method and set ``supports_snapshots=True`` .
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def create_snapshot_async(self, effect: GrantEffect) -> str:
+        """Create a snapshot of the grants currently in storage.
+
+        The snapshot is a page reference for the first page.
+        Pass it as ``next_page_reference`` to ``get_raw_grants_page_async`` or ``get_grant_uuids_page_async`` ,
+        and every following page reference stays in the snapshot,
+        so grants that are added while the pages are read are not returned by any page.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        str
+            The page reference for the first page of the snapshot.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and snapshots are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def record_grant_usage(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        """Store when grants were last used to decide or match a request.
+
+        Times older than the stored time for a grant should be ignored.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        last_used : Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method to support grant usage tracking.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def record_grant_usage_async(self, effect: GrantEffect, last_used: Dict[str, datetime.datetime]) -> None:
+        """Store when grants were last used to decide or match a request.
+
+        Times older than the stored time for a grant should be ignored.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+        last_used : Dict[str, datetime.datetime]
+            Last used time by grant UUID.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and grant usage tracking are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def get_grant_usage(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID. Grants that were never used are not included.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method to support grant usage tracking.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def get_grant_usage_async(self, effect: GrantEffect) -> Dict[str, datetime.datetime]:
+        """Get when grants were last used to decide or match a request.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        Dict[str, datetime.datetime]
+            Last used time by grant UUID. Grants that were never used are not included.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and grant usage tracking are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def archive_grant(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        """Move a grant to the archive.
+
+        The grant is no longer returned by the grant pages, but is kept for ``list_archived_grants`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        uuid : str
+            UUID of the grant to archive.
+        archived_at : datetime.datetime
+            When the grant was archived.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+
+        Raises
+        ------
+        authzee.exceptions.GrantDoesNotExistError
+            The grant does not exist.
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method to support grant archival.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def archive_grant_async(self, effect: GrantEffect, uuid: str, archived_at: datetime.datetime) -> ArchivedGrant:
+        """Move a grant to the archive.
+
+        The grant is no longer returned by the grant pages, but is kept for ``list_archived_grants_async`` .
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grant.
+        uuid : str
+            UUID of the grant to archive.
+        archived_at : datetime.datetime
+            When the grant was archived.
+
+        Returns
+        -------
+        ArchivedGrant
+            The archived grant.
+
+        Raises
+        ------
+        authzee.exceptions.GrantDoesNotExistError
+            The grant does not exist.
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and grant archival are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    def list_archived_grants(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes may implement this method to support grant archival.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
+    async def list_archived_grants_async(self, effect: GrantEffect) -> List[ArchivedGrant]:
+        """List the archived grants.
+
+        Parameters
+        ----------
+        effect : GrantEffect
+            The effect of the grants.
+
+        Returns
+        -------
+        List[ArchivedGrant]
+            The archived grants, in the order they were archived.
+
+        Raises
+        ------
+        authzee.exceptions.MethodNotImplementedError
+            Sub-classes *may* implement this method if ``async`` and grant archival are supported.
+        """
+        raise exceptions.MethodNotImplementedError()
+
+
     def _check_uuid(self, grant: Grant, generate_uuid: bool) -> Grant:
         """Check if a UUID is on a grant to add, optionally generate a UUID with UUID 4.
 
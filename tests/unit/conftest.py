@@ -0,0 +1,123 @@
+import asyncio
+from enum import auto
+from typing import Any, Callable, Dict, Iterator, Optional, Set, Type
+
+from pydantic import BaseModel
+import pytest
+
+from authzee import (
+    Authzee,
+    ComputeBackend,
+    Grant,
+    MainProcessCompute,
+    MemoryStorage,
+    ResourceAction,
+    ResourceAuthz,
+    StorageBackend,
+    ThreadedCompute
+)
+
+
+class ADUser(BaseModel):
+
+    cn: str
+
+
+class Balloon(BaseModel):
+
+    color: str
+    size: float
+
+
+class BalloonAction(ResourceAction):
+
+    CreateBalloon: str = auto()
+    DeleteBalloon: str = auto()
+
+
+class BalloonAuthz(ResourceAuthz):
+
+    resource_action_type: Type[ResourceAction] = BalloonAction
+    resource_type: Type[BaseModel] = Balloon
+    parent_authz_names: Set[str] = set()
+    child_authz_names: Set[str] = set()
+
+
+@pytest.fixture(autouse=True)
+def new_event_loop() -> Iterator[asyncio.AbstractEventLoop]:
+    """Set a new event loop for each test, for the sync methods of async compute backends.
+    """
+    loop = asyncio.new_event_loop()
+    asyncio.set_event_loop(loop)
+    yield loop
+    loop.close()
+    asyncio.set_event_loop(None)
+
+
+@pytest.fixture
+def make_authzee() -> Callable[..., Authzee]:
+    """Factory for initialized and set up ``Authzee`` apps with the balloon resource types.
+    """
+    def factory(
+        compute_backend: Optional[ComputeBackend] = None,
+        storage_backend: Optional[StorageBackend] = None,
+        **kwargs
+    ) -> Authzee:
+        authzee_app = Authzee(
+            compute_backend=compute_backend if compute_backend is not None else MainProcessCompute(),
+            storage_backend=storage_backend if storage_backend is not None else MemoryStorage(),
+            identity_types={ADUser},
+            resource_authz_types={BalloonAuthz},
+            **kwargs
+        )
+        authzee_app.initialize()
+        authzee_app.setup()
+
+        return authzee_app
+
+    return factory
+
+
+@pytest.fixture(
+    params=[
+        MainProcessCompute,
+        lambda: ThreadedCompute(max_workers=2)
+    ],
+    ids=["main_process", "threaded"]
+)
+def compute_backend_factory(request) -> Callable[[], ComputeBackend]:
+    """Factory for each compute backend that evaluates grants in the test process.
+    """
+    return request.param
+
+
+@pytest.fixture
+def request_kwargs() -> Dict[str, Any]:
+    """``authorize`` arguments, without the resource, to create a balloon as ``user_1`` .
+    """
+    return {
+        "resource_action": BalloonAction.CreateBalloon,
+        "parent_resources": [],
+        "child_resources": [],
+        "identities": [ADUser(cn="user_1")]
+    }
+
+
+@pytest.fixture
+def make_grant() -> Callable[..., Grant]:
+    """Factory for balloon grants that match every request to create a balloon, unless fields are given.
+    """
+    def factory(name: str = "grant", **kwargs) -> Grant:
+        fields = {
+            "name": name,
+            "description": "",
+            "resource_type": Balloon,
+            "resource_actions": {BalloonAction.CreateBalloon},
+            "jmespath_expression": "`true`",
+            "result_match": True,
+            **kwargs
+        }
+
+        return Grant(**fields)
+
+    return factory
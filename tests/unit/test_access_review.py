@@ -0,0 +1,89 @@
+import asyncio
+import csv
+import io
+import json
+
+import pytest
+
+from authzee import GrantEffect, ThreadedCompute, exceptions
+
+from conftest import ADUser, Balloon
+
+
+PRINCIPALS = {
+    "alice": [ADUser(cn="alice")],
+    "bob": [ADUser(cn="bob")]
+}
+RESOURCES = [Balloon(color="red", size=1), Balloon(color="blue", size=2)]
+
+
+def make_review_authzee(make_authzee, make_grant, compute_backend=None):
+    authzee_app = make_authzee(compute_backend=compute_backend)
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="alice creates", jmespath_expression="contains(identities.ADUser[].cn, 'alice')")
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="no red balloons", jmespath_expression="resource.color == 'red'")
+    )
+
+    return authzee_app
+
+
+def test_access_review_has_an_entry_for_every_principal_resource_and_action(make_authzee, make_grant):
+    review = make_review_authzee(make_authzee, make_grant).access_review(principals=PRINCIPALS, resources=RESOURCES)
+
+    assert len(review.entries) == 2 * 2 * 2
+    assert {
+        (entry.principal, entry.resource["color"], entry.resource_action)
+        for entry in review.entries
+    } == {
+        (principal, color, action)
+        for principal in PRINCIPALS
+        for color in ["red", "blue"]
+        for action in ["CreateBalloon", "DeleteBalloon"]
+    }
+    assert all(entry.resource_type == "Balloon" for entry in review.entries)
+
+
+def test_access_review_allowed_entries(make_authzee, make_grant):
+    review = make_review_authzee(make_authzee, make_grant).access_review(principals=PRINCIPALS, resources=RESOURCES)
+
+    assert [
+        (entry.principal, entry.resource, entry.resource_action)
+        for entry in review.allowed_entries()
+    ] == [("alice", {"color": "blue", "size": 2.0}, "CreateBalloon")]
+
+
+def test_access_review_to_csv(make_authzee, make_grant):
+    review = make_review_authzee(make_authzee, make_grant).access_review(
+        principals={"alice": PRINCIPALS["alice"]},
+        resources=[RESOURCES[1]]
+    )
+
+    rows = list(csv.reader(io.StringIO(review.to_csv())))
+
+    assert rows == [
+        ["principal", "resource_type", "resource", "resource_action", "allowed"],
+        ["alice", "Balloon", '{"color": "blue", "size": 2.0}', "CreateBalloon", "True"],
+        ["alice", "Balloon", '{"color": "blue", "size": 2.0}', "DeleteBalloon", "False"]
+    ]
+
+
+def test_access_review_to_json(make_authzee, make_grant):
+    review = make_review_authzee(make_authzee, make_grant).access_review(principals=PRINCIPALS, resources=RESOURCES)
+
+    assert json.loads(review.to_json()) == [json.loads(entry.json()) for entry in review.entries]
+
+
+def test_access_review_async_matches_sync(make_authzee, make_grant):
+    authzee_app = make_review_authzee(make_authzee, make_grant, compute_backend=ThreadedCompute(max_workers=2))
+    review = authzee_app.access_review(principals=PRINCIPALS, resources=RESOURCES)
+
+    assert asyncio.run(authzee_app.access_review_async(principals=PRINCIPALS, resources=RESOURCES)) == review
+
+
+def test_access_review_rejects_unregistered_resources(make_authzee, make_grant):
+    with pytest.raises(exceptions.InputVerificationError, match="not registered"):
+        make_review_authzee(make_authzee, make_grant).access_review(principals=PRINCIPALS, resources=[ADUser(cn="alice")])
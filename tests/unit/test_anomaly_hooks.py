@@ -0,0 +1,167 @@
+import datetime
+from typing import Optional
+
+from authzee import (
+    AnomalyRule,
+    DecisionEvent,
+    DecisionOverride,
+    DenyBurstRule,
+    FixedClock,
+    GrantEffect,
+    OverrideDecisionRule
+)
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+NOW = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+
+
+class BigBalloonRule(AnomalyRule):
+
+    def check(self, event: DecisionEvent) -> Optional[str]:
+        if event.allowed is True and event.resource["size"] > 100:
+            return "A big balloon was allowed."
+
+        return None
+
+
+def make_anomaly_authzee(make_authzee, make_grant, anomaly_rules):
+    clock = FixedClock(now=NOW)
+    anomalies = []
+    authzee_app = make_authzee(
+        clock=clock,
+        anomaly_rules=anomaly_rules,
+        anomaly_hooks=[anomalies.append]
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+
+    return authzee_app, clock, anomalies
+
+
+def test_hooks_are_called_for_flagged_decisions(make_authzee, make_grant, request_kwargs):
+    authzee_app, _, anomalies = make_anomaly_authzee(make_authzee, make_grant, [BigBalloonRule()])
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=500), **request_kwargs) is False
+    assert anomalies == []
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=500), **request_kwargs) is True
+    assert len(anomalies) == 1
+    assert anomalies[0].rule == "BigBalloonRule"
+    assert anomalies[0].reason == "A big balloon was allowed."
+    assert anomalies[0].event == DecisionEvent(
+        resource_type="Balloon",
+        resource={"color": "red", "size": 500.0},
+        resource_action=str(BalloonAction.CreateBalloon),
+        identities={"ADUser": [{"cn": "user_1"}]},
+        allowed=True,
+        overridden=False,
+        timestamp=NOW
+    )
+
+
+def test_hooks_are_called_for_each_resource_of_authorize_many(make_authzee, make_grant, request_kwargs):
+    authzee_app, _, anomalies = make_anomaly_authzee(make_authzee, make_grant, [BigBalloonRule()])
+
+    authzee_app.authorize_many(
+        resources=[Balloon(color="red", size=500), Balloon(color="red", size=1), Balloon(color="red", size=200)],
+        **request_kwargs
+    )
+
+    assert [anomaly.event.resource["size"] for anomaly in anomalies] == [500.0, 200.0]
+
+
+def test_hook_errors_do_not_change_decisions(make_authzee, make_grant, request_kwargs):
+    def failing_hook(anomaly):
+        raise RuntimeError("alerting is down")
+
+    anomalies = []
+    authzee_app = make_authzee(anomaly_rules=[BigBalloonRule()], anomaly_hooks=[failing_hook, anomalies.append])
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant())
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=500), **request_kwargs) is True
+    assert len(anomalies) == 1
+
+
+def test_deny_burst_rule(make_authzee, make_grant, request_kwargs):
+    authzee_app, clock, anomalies = make_anomaly_authzee(
+        make_authzee,
+        make_grant,
+        [DenyBurstRule(max_denies=2, window=60)]
+    )
+    for _ in range(2):
+        authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs)
+        authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs)
+
+    assert anomalies == []
+
+    authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs)
+
+    assert [anomaly.rule for anomaly in anomalies] == ["DenyBurstRule"]
+    assert anomalies[0].reason == "The identities were denied 3 times in 60 seconds."
+
+    clock.advance(seconds=61)
+    authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs)
+
+    assert len(anomalies) == 1
+
+
+def test_deny_burst_rule_counts_identities_separately(make_authzee, make_grant, request_kwargs):
+    authzee_app, _, anomalies = make_anomaly_authzee(
+        make_authzee,
+        make_grant,
+        [DenyBurstRule(max_denies=1, window=60)]
+    )
+    for cn in ["user_1", "user_2"]:
+        authzee_app.authorize(
+            resource=Balloon(color="blue", size=1),
+            **{**request_kwargs, "identities": [ADUser(cn=cn)]}
+        )
+
+    assert anomalies == []
+
+
+def test_override_decision_rule(make_authzee, make_grant, request_kwargs):
+    authzee_app, _, anomalies = make_anomaly_authzee(make_authzee, make_grant, [OverrideDecisionRule()])
+    for effect, color in [(GrantEffect.ALLOW, "blue"), (GrantEffect.DENY, "red")]:
+        authzee_app.add_override(
+            DecisionOverride(
+                effect=effect,
+                reason="break glass",
+                identity=ADUser(cn="user_1"),
+                resource_type=Balloon,
+                resource_actions={BalloonAction.CreateBalloon},
+                resource_match={"color": color},
+                expires_at=NOW + datetime.timedelta(hours=1)
+            )
+        )
+
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+    assert [anomaly.reason for anomaly in anomalies] == ["The request was allowed by a decision override."]
+    assert anomalies[0].event.overridden is True
+
+
+def test_override_decision_rule_can_flag_denies(make_authzee, make_grant, request_kwargs):
+    authzee_app, _, anomalies = make_anomaly_authzee(
+        make_authzee,
+        make_grant,
+        [OverrideDecisionRule(allowed_only=False)]
+    )
+    authzee_app.add_override(
+        DecisionOverride(
+            effect=GrantEffect.DENY,
+            reason="incident",
+            identity=ADUser(cn="user_1"),
+            resource_type=Balloon,
+            resource_actions={BalloonAction.CreateBalloon},
+            expires_at=NOW + datetime.timedelta(hours=1)
+        )
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+    assert [anomaly.reason for anomaly in anomalies] == ["The request was denied by a decision override."]
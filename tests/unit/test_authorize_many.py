@@ -0,0 +1,65 @@
+import asyncio
+
+from authzee import GrantEffect, ThreadedCompute
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+def add_size_grants(authzee_app, make_grant):
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="deny large balloons", jmespath_expression="resource.size > `100`")
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow user 1", jmespath_expression="contains(identities.ADUser[].cn, 'user_1')")
+    )
+
+
+def test_authorize_many_keeps_deny(make_authzee, make_grant, compute_backend_factory):
+    authzee_app = make_authzee(compute_backend=compute_backend_factory())
+    add_size_grants(authzee_app=authzee_app, make_grant=make_grant)
+    resources = [
+        Balloon(color="red", size=500),
+        Balloon(color="blue", size=5),
+        Balloon(color="green", size=101)
+    ]
+    identities = [ADUser(cn="user_1")]
+
+    single = [
+        authzee_app.authorize(
+            resource=resource,
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=identities
+        )
+        for resource in resources
+    ]
+    many = authzee_app.authorize_many(
+        resources=resources,
+        resource_action=BalloonAction.CreateBalloon,
+        parent_resources=[],
+        child_resources=[],
+        identities=identities
+    )
+
+    assert single == [False, True, False]
+    assert many == single
+
+
+def test_authorize_many_async_keeps_deny(make_authzee, make_grant):
+    authzee_app = make_authzee(compute_backend=ThreadedCompute(max_workers=2))
+    add_size_grants(authzee_app=authzee_app, make_grant=make_grant)
+
+    many = asyncio.run(
+        authzee_app.authorize_many_async(
+            resources=[Balloon(color="red", size=500), Balloon(color="blue", size=5)],
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=[ADUser(cn="user_1")]
+        )
+    )
+
+    assert many == [False, True]
@@ -0,0 +1,64 @@
+import datetime
+
+import pytest
+
+from authzee import GrantMatchMode, GrantProvenance
+from authzee.grant import dedupe_grants
+
+from conftest import BalloonAction
+
+
+def test_canonical_hash_is_stable_over_formatting(make_grant):
+    grant = make_grant(
+        resource_actions={BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon},
+        jmespath_expression="resource.color == 'red'",
+        result_match={"a": 1, "b": [1, 2]}
+    )
+    reformatted = make_grant(
+        resource_actions={BalloonAction.DeleteBalloon, BalloonAction.CreateBalloon},
+        jmespath_expression="  resource.color\n  ==   'red' ",
+        result_match={"b": [1, 2], "a": 1}
+    )
+
+    assert grant.canonical_hash() == reformatted.canonical_hash()
+
+
+def test_canonical_hash_excludes_metadata(make_grant):
+    grant = make_grant()
+    described = make_grant(
+        name="other name",
+        description="other description",
+        provenance=GrantProvenance(source="balloons bundle", commit_sha="abc123"),
+        tags={"owner": "team_a"},
+        created_at=datetime.datetime(2024, 1, 1, tzinfo=datetime.timezone.utc),
+        storage_id="7",
+        uuid="1234"
+    )
+
+    assert grant.canonical_hash() == described.canonical_hash()
+
+
+@pytest.mark.parametrize(
+    "fields",
+    [
+        {"resource_actions": {BalloonAction.DeleteBalloon}},
+        {"jmespath_expression": "`false`"},
+        {"result_match": False},
+        {"order_insensitive_arrays": True},
+        {"numeric_tolerance": 0.1},
+        {"result_match": [True], "match_mode": GrantMatchMode.ONE_OF},
+        {"applies_to_descendants": True},
+        {"canary_sample_rate": 0.5}
+    ]
+)
+def test_canonical_hash_changes_with_what_the_grant_matches(make_grant, fields):
+    assert make_grant().canonical_hash() != make_grant(**fields).canonical_hash()
+
+
+def test_dedupe_grants_keeps_the_first_grant_in_order(make_grant):
+    first = make_grant(name="first")
+    other = make_grant(name="other", jmespath_expression="resource.color == 'red'")
+    duplicate = make_grant(name="duplicate", jmespath_expression=" `true` ")
+
+    assert dedupe_grants([first, other, duplicate]) == [first, other]
+    assert dedupe_grants([]) == []
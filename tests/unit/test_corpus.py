@@ -0,0 +1,115 @@
+from typing import Optional
+
+from authzee import (
+    AnomalyRule,
+    AuthzeeConfig,
+    AuthzRequest,
+    CorpusCase,
+    DecisionEvent,
+    GrantEffect
+)
+from authzee.storage import MemoryStorage
+
+from conftest import ADUser, Balloon, BalloonAction
+from test_grant_inheritance import Document, DocumentAction, Folder, FolderAction, make_inheriting_authzee
+
+
+class EveryDecisionRule(AnomalyRule):
+
+    def check(self, event: DecisionEvent) -> Optional[str]:
+        return "Every decision is flagged."
+
+
+def balloon_request(color: str, size: int) -> AuthzRequest:
+    return AuthzRequest(
+        resource=Balloon(color=color, size=size),
+        resource_action=BalloonAction.CreateBalloon,
+        identities=[ADUser(cn="user_1")]
+    )
+
+
+def make_tracked_authzee(make_authzee, make_grant):
+    storage = MemoryStorage()
+    anomalies = []
+    authzee_app = make_authzee(
+        storage_backend=storage,
+        config=AuthzeeConfig(track_grant_usage=True),
+        anomaly_rules=[EveryDecisionRule()],
+        anomaly_hooks=[anomalies.append]
+    )
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'"))
+    authzee_app.add_grant(effect=GrantEffect.DENY, grant=make_grant(name="deny large", jmespath_expression="resource.size > `100`"))
+
+    return authzee_app, storage, anomalies
+
+
+def test_run_corpus_has_no_side_effects(make_authzee, make_grant):
+    authzee_app, storage, anomalies = make_tracked_authzee(make_authzee, make_grant)
+
+    report = authzee_app.run_corpus(
+        cases=[
+            CorpusCase(request=balloon_request(color="red", size=1), expected_decision=True),
+            CorpusCase(request=balloon_request(color="red", size=500), expected_decision=True),
+            CorpusCase(request=balloon_request(color="blue", size=1), expected_decision=False)
+        ]
+    )
+
+    assert report.decisions == [True, False, False]
+    assert report.failed_cases == [1]
+    assert anomalies == []
+    assert storage.get_grant_usage(effect=GrantEffect.ALLOW) == {}
+    assert storage.get_grant_usage(effect=GrantEffect.DENY) == {}
+
+
+def test_validate_grant_against_corpus_has_no_side_effects(make_authzee, make_grant):
+    authzee_app, storage, anomalies = make_tracked_authzee(make_authzee, make_grant)
+    requests = [
+        balloon_request(color="red", size=1),
+        balloon_request(color="blue", size=1),
+        balloon_request(color="blue", size=500),
+        balloon_request(color="green", size=1)
+    ]
+
+    deny_impact = authzee_app.validate_grant_against_corpus(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="deny small", jmespath_expression="resource.size < `10`"),
+        requests=requests
+    )
+    allow_impact = authzee_app.validate_grant_against_corpus(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow blue", jmespath_expression="resource.color == 'blue'"),
+        requests=requests
+    )
+
+    assert deny_impact.allow_to_deny == [0]
+    assert deny_impact.deny_to_allow == []
+    assert allow_impact.allow_to_deny == []
+    assert allow_impact.deny_to_allow == [1]
+    assert anomalies == []
+    assert storage.get_grant_usage(effect=GrantEffect.ALLOW) == {}
+    assert storage.get_grant_usage(effect=GrantEffect.DENY) == {}
+
+
+def test_validate_grant_against_corpus_inherits_parent_grants(make_grant):
+    authzee_app = make_inheriting_authzee()
+    impact = authzee_app.validate_grant_against_corpus(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(
+            name="public folder readers",
+            resource_type=Folder,
+            resource_actions={FolderAction.Read},
+            jmespath_expression="resource.path == '/public'",
+            applies_to_descendants=True
+        ),
+        requests=[
+            AuthzRequest(
+                resource=Document(name=name),
+                resource_action=DocumentAction.Read,
+                parent_resources=[Folder(path="/public")],
+                identities=[ADUser(cn="bob")]
+            )
+            for name in ("notes", "private")
+        ]
+    )
+
+    assert impact.deny_to_allow == [0]
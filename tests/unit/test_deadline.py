@@ -0,0 +1,132 @@
+import datetime
+
+import pytest
+
+from authzee import Deadline, FixedClock, GrantEffect, exceptions
+from authzee.storage import MemoryStorage
+
+from conftest import ADUser, Balloon
+from test_grant_inheritance import Document, DocumentAction, Folder, make_inheriting_authzee
+
+
+class ClockAdvancingStorage(MemoryStorage):
+    """Memory storage that advances a fixed clock on every page, like a slow storage round trip.
+    """
+
+    def __init__(self, clock: FixedClock, seconds: float):
+        super().__init__(default_page_size=1)
+        self.clock = clock
+        self.seconds = seconds
+
+
+    def get_raw_grants_page(self, *args, **kwargs):
+        self.clock.advance(seconds=self.seconds)
+
+        return super().get_raw_grants_page(*args, **kwargs)
+
+
+    async def get_raw_grants_page_async(self, *args, **kwargs):
+        self.clock.advance(seconds=self.seconds)
+
+        return await super().get_raw_grants_page_async(*args, **kwargs)
+
+
+class FolderClockAdvancingStorage(ClockAdvancingStorage):
+    """Clock advancing storage that only advances the clock for pages of folder grants.
+    """
+
+    def get_raw_grants_page(self, *args, resource_type=None, **kwargs):
+        if resource_type is not Folder:
+            return MemoryStorage.get_raw_grants_page(self, *args, resource_type=resource_type, **kwargs)
+
+        return super().get_raw_grants_page(*args, resource_type=resource_type, **kwargs)
+
+
+    async def get_raw_grants_page_async(self, *args, resource_type=None, **kwargs):
+        if resource_type is not Folder:
+            return await MemoryStorage.get_raw_grants_page_async(self, *args, resource_type=resource_type, **kwargs)
+
+        return await super().get_raw_grants_page_async(*args, resource_type=resource_type, **kwargs)
+
+
+def add_grants(authzee_app, make_grant) -> None:
+    for color in ("blue", "green", "red"):
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(
+                name="allow {}".format(color),
+                jmespath_expression="resource.color == '{}'".format(color)
+            )
+        )
+
+
+def test_expired_deadline_is_raised_before_evaluation(make_authzee, make_grant, request_kwargs):
+    clock = FixedClock(now=datetime.datetime(2026, 1, 1))
+    authzee_app = make_authzee()
+    add_grants(authzee_app, make_grant)
+
+    with pytest.raises(exceptions.DeadlineExceededError, match="verification"):
+        authzee_app.authorize(
+            resource=Balloon(color="red", size=1),
+            deadline=Deadline(timeout=0, clock=clock),
+            **request_kwargs
+        )
+
+
+def test_deadline_is_checked_between_storage_pages(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    compute_backend_factory
+):
+    clock = FixedClock(now=datetime.datetime(2026, 1, 1))
+    authzee_app = make_authzee(
+        compute_backend=compute_backend_factory(),
+        storage_backend=ClockAdvancingStorage(clock=clock, seconds=1)
+    )
+    add_grants(authzee_app, make_grant)
+
+    assert authzee_app.authorize(
+        resource=Balloon(color="red", size=1),
+        deadline=Deadline(timeout=10, clock=clock),
+        **request_kwargs
+    ) is True
+    with pytest.raises(exceptions.DeadlineExceededError):
+        authzee_app.authorize(
+            resource=Balloon(color="red", size=1),
+            deadline=Deadline(timeout=1.5, clock=clock),
+            **request_kwargs
+        )
+
+
+def test_deadline_is_checked_for_inherited_grants(compute_backend_factory):
+    clock = FixedClock(now=datetime.datetime(2026, 1, 1))
+    authzee_app = make_inheriting_authzee(
+        compute_backend=compute_backend_factory(),
+        storage_backend=FolderClockAdvancingStorage(clock=clock, seconds=1)
+    )
+    document_kwargs = {
+        "resource_action": DocumentAction.Read,
+        "parent_resources": [Folder(path="/team")],
+        "child_resources": [],
+        "identities": [ADUser(cn="alice")]
+    }
+
+    assert authzee_app.authorize(
+        resource=Document(name="readme"),
+        deadline=Deadline(timeout=10, clock=clock),
+        **document_kwargs
+    ) is True
+    with pytest.raises(exceptions.DeadlineExceededError, match="storage retrieval"):
+        authzee_app.authorize(
+            resource=Document(name="readme"),
+            deadline=Deadline(timeout=0.5, clock=clock),
+            **document_kwargs
+        )
+
+    with pytest.raises(exceptions.DeadlineExceededError, match="storage retrieval"):
+        authzee_app.authorize_many(
+            resources=[Document(name="readme")],
+            deadline=Deadline(timeout=0.5, clock=clock),
+            **document_kwargs
+        )
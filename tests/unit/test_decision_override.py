@@ -0,0 +1,71 @@
+import datetime
+
+import pytest
+
+from authzee import DecisionOverride, FixedClock, GrantEffect, exceptions
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+NOW = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+
+
+def make_override(effect: GrantEffect, hours: int = 1, **kwargs) -> DecisionOverride:
+    return DecisionOverride(
+        effect=effect,
+        reason="INC-1234",
+        identity=ADUser(cn="user_1"),
+        resource_type=Balloon,
+        resource_actions={BalloonAction.CreateBalloon},
+        expires_at=NOW + datetime.timedelta(hours=hours),
+        **kwargs
+    )
+
+
+def make_overridden_authzee(make_authzee, make_grant):
+    clock = FixedClock(now=NOW)
+    authzee_app = make_authzee(clock=clock)
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+
+    return authzee_app, clock
+
+
+def test_deny_override_is_checked_before_grants(make_authzee, make_grant, request_kwargs):
+    authzee_app, _ = make_overridden_authzee(make_authzee, make_grant)
+    override = authzee_app.add_override(make_override(effect=GrantEffect.DENY))
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+    assert authzee_app.authorize_many(
+        resources=[Balloon(color="red", size=1), Balloon(color="blue", size=1)],
+        **request_kwargs
+    ) == [False, False]
+
+    authzee_app.delete_override(uuid=override.uuid)
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+
+
+def test_deny_override_wins_over_allow_override(make_authzee, make_grant, request_kwargs):
+    authzee_app, _ = make_overridden_authzee(make_authzee, make_grant)
+    authzee_app.add_override(make_override(effect=GrantEffect.ALLOW))
+    authzee_app.add_override(make_override(effect=GrantEffect.DENY, resource_match={"color": "blue"}))
+
+    assert authzee_app.authorize_many(
+        resources=[Balloon(color="green", size=1), Balloon(color="blue", size=1)],
+        **request_kwargs
+    ) == [True, False]
+
+
+def test_expired_overrides_do_not_apply(make_authzee, make_grant, request_kwargs):
+    authzee_app, clock = make_overridden_authzee(make_authzee, make_grant)
+    authzee_app.add_override(make_override(effect=GrantEffect.DENY, hours=1))
+
+    clock.advance(seconds=2 * 60 * 60)
+
+    assert authzee_app.list_overrides() == []
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    with pytest.raises(exceptions.InputVerificationError):
+        authzee_app.add_override(make_override(effect=GrantEffect.DENY, hours=1))
@@ -0,0 +1,123 @@
+import asyncio
+
+import pytest
+
+from authzee import (
+    AuthzeeConfig,
+    EvaluationBudget,
+    GrantEffect,
+    MultiprocessCompute,
+    RequestLimits,
+    ThreadedCompute,
+    exceptions
+)
+
+from conftest import ADUser, Balloon
+from test_grant_inheritance import Document, DocumentAction, Folder, authorize_document, make_inheriting_authzee
+
+
+EXPRESSION = "resource.color == 'red'"
+
+
+def test_grant_cost_scales_with_data_size(make_grant):
+    grant = make_grant(jmespath_expression=EXPRESSION)
+
+    assert EvaluationBudget(max_cost=100).grant_cost(grant=grant) == len(EXPRESSION)
+    assert EvaluationBudget(max_cost=100, data_size=2048).grant_cost(grant=grant) == 3 * len(EXPRESSION)
+
+
+def test_budget_raises_once_spent(make_grant):
+    grant = make_grant(jmespath_expression=EXPRESSION)
+    budget = EvaluationBudget(max_cost=2 * len(EXPRESSION))
+    budget.spend(grant=grant, evaluations=2)
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError, match="over the budget"):
+        budget.spend(grant=grant)
+
+
+def test_authorize_raises_when_grant_evaluations_are_over_budget(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    compute_backend_factory
+):
+    authzee_app = make_authzee(
+        compute_backend=compute_backend_factory(),
+        config=AuthzeeConfig(request_limits=RequestLimits(max_evaluation_cost=2 * len(EXPRESSION)))
+    )
+    for index in range(2):
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(name="grant {}".format(index), jmespath_expression=EXPRESSION, result_match=False)
+        )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="grant 2", jmespath_expression=EXPRESSION, result_match=False)
+    )
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs)
+
+
+def test_no_budget_without_max_evaluation_cost(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee()
+    for index in range(10):
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(name="grant {}".format(index), jmespath_expression=EXPRESSION, result_match=False)
+        )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+
+
+def test_authorize_spends_the_budget_on_inherited_grants(compute_backend_factory):
+    # Only the resource deny grant fits in the budget, the inherited deny grant goes over it
+    limits = RequestLimits(max_evaluation_cost=len("resource.name == 'private'") + 1)
+    not_inheriting_app = make_inheriting_authzee(
+        grant_inheritance=False,
+        compute_backend=compute_backend_factory(),
+        request_limits=limits
+    )
+    inheriting_app = make_inheriting_authzee(compute_backend=compute_backend_factory(), request_limits=limits)
+
+    assert authorize_document(not_inheriting_app, name="readme", path="/secret") is False
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        authorize_document(inheriting_app, name="readme", path="/secret")
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        inheriting_app.authorize_many(
+            resources=[Document(name="readme")],
+            resource_action=DocumentAction.Read,
+            parent_resources=[Folder(path="/secret")],
+            child_resources=[],
+            identities=[ADUser(cn="alice")]
+        )
+
+
+def test_authorize_async_spends_the_budget_on_inherited_grants():
+    authzee_app = make_inheriting_authzee(
+        compute_backend=ThreadedCompute(max_workers=2),
+        request_limits=RequestLimits(max_evaluation_cost=len("resource.name == 'private'") + 1)
+    )
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        asyncio.run(
+            authzee_app.authorize_async(
+                resource=Document(name="readme"),
+                resource_action=DocumentAction.Read,
+                parent_resources=[Folder(path="/secret")],
+                child_resources=[],
+                identities=[ADUser(cn="alice")]
+            )
+        )
+
+
+def test_budget_is_not_allowed_with_multiprocess_compute(make_authzee):
+    with pytest.raises(exceptions.InputVerificationError, match="does not support evaluation budgets"):
+        make_authzee(
+            compute_backend=MultiprocessCompute(max_workers=1),
+            config=AuthzeeConfig(request_limits=RequestLimits(max_evaluation_cost=100))
+        )
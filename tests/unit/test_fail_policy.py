@@ -0,0 +1,99 @@
+import asyncio
+
+import pytest
+
+from authzee import (
+    AuthzeeConfig,
+    FailPolicy,
+    FailureDecision,
+    exceptions
+)
+from authzee.compute import general as gc
+from authzee.compute import ScriptedComputeBackend
+from authzee.storage import FaultInjectingStorage
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+def authorize_balloon(authzee_app, action: BalloonAction = BalloonAction.CreateBalloon) -> bool:
+    return authzee_app.authorize(
+        resource=Balloon(color="red", size=1),
+        resource_action=action,
+        parent_resources=[],
+        child_resources=[],
+        identities=[ADUser(cn="user_1")]
+    )
+
+
+def fail_policy_config(**kwargs) -> AuthzeeConfig:
+    return AuthzeeConfig(fail_policy=FailPolicy(**kwargs))
+
+
+def test_storage_failure_is_raised_by_default(make_authzee):
+    authzee_app = make_authzee(storage_backend=FaultInjectingStorage(fail_on_call=1))
+
+    with pytest.raises(exceptions.StorageBackendError):
+        authorize_balloon(authzee_app)
+
+
+def test_storage_failure_decisions(make_authzee):
+    authzee_app = make_authzee(
+        storage_backend=FaultInjectingStorage(fail_on_call=1, fail_every_call=True),
+        config=fail_policy_config(
+            storage_failure=FailureDecision.ALLOW_LOW_RISK,
+            low_risk_actions={str(BalloonAction.DeleteBalloon)}
+        )
+    )
+
+    assert authorize_balloon(authzee_app, action=BalloonAction.CreateBalloon) is False
+    assert authorize_balloon(authzee_app, action=BalloonAction.DeleteBalloon) is True
+    assert authzee_app.authorize_many(
+        resources=[Balloon(color="red", size=1), Balloon(color="blue", size=1)],
+        resource_action=BalloonAction.DeleteBalloon,
+        parent_resources=[],
+        child_resources=[],
+        identities=[ADUser(cn="user_1")]
+    ) == [True, True]
+
+
+def test_compute_failure_decision(make_authzee):
+    authzee_app = make_authzee(
+        compute_backend=ScriptedComputeBackend(fail_on_call=1),
+        config=fail_policy_config(compute_failure=FailureDecision.DENY, storage_failure=FailureDecision.RAISE)
+    )
+
+    assert authorize_balloon(authzee_app) is False
+
+
+def test_other_errors_are_not_decided(make_authzee):
+    config = fail_policy_config(storage_failure=FailureDecision.DENY, compute_failure=FailureDecision.DENY)
+    storage_app = make_authzee(
+        storage_backend=FaultInjectingStorage(fail_on_call=1, error=ConnectionError("not a storage error")),
+        config=config
+    )
+    compute_app = make_authzee(
+        compute_backend=ScriptedComputeBackend(fail_on_call=1, error=TypeError("a programming error")),
+        config=config
+    )
+
+    with pytest.raises(ConnectionError):
+        authorize_balloon(storage_app)
+
+    with pytest.raises(TypeError):
+        authorize_balloon(compute_app)
+
+
+def test_compute_errors_wraps_lost_workers():
+    @gc.compute_errors
+    async def lost_worker():
+        raise BrokenPipeError("lost")
+
+    @gc.compute_errors
+    async def programming_error():
+        raise KeyError("missing")
+
+    with pytest.raises(exceptions.ComputeBackendError):
+        asyncio.run(lost_worker())
+
+    with pytest.raises(KeyError):
+        asyncio.run(programming_error())
@@ -0,0 +1,99 @@
+import datetime
+
+import pytest
+
+from authzee import FixedClock, GrantEffect, GrantProvenance, GrantSort
+from authzee.grant import compact_grants, sort_grants
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+def test_compact_grants_merges_actions(make_grant):
+    compaction = compact_grants(
+        [
+            make_grant(name="create", resource_actions={BalloonAction.CreateBalloon}, tags={"owner": "team_a"}, uuid="1"),
+            make_grant(name="delete", resource_actions={BalloonAction.DeleteBalloon}, tags={"owner": "team_a"}, uuid="2")
+        ]
+    )
+
+    assert compaction.original_count == 2
+    assert compaction.compacted_count == 1
+    assert compaction.grants[0].resource_actions == {BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon}
+    assert compaction.grants[0].tags == {"owner": "team_a"}
+    assert compaction.grants[0].uuid is None
+    assert compaction.merged_uuids == [["1", "2"]]
+
+
+def test_compact_grants_keeps_different_metadata(make_grant):
+    grants = [
+        make_grant(name="create", resource_actions={BalloonAction.CreateBalloon}, tags={"owner": "team_a"}),
+        make_grant(name="delete", resource_actions={BalloonAction.DeleteBalloon}, tags={"owner": "team_b"}),
+        make_grant(
+            name="delete from bundle",
+            resource_actions={BalloonAction.DeleteBalloon},
+            tags={"owner": "team_a"},
+            provenance=GrantProvenance(source="bundle.json")
+        )
+    ]
+
+    compaction = compact_grants(grants)
+
+    assert compaction.compacted_count == 3
+    assert compaction.grants == grants
+    assert compaction.merged_uuids == []
+
+
+def test_sort_grants(make_grant):
+    january = datetime.datetime(2024, 1, 1, tzinfo=datetime.timezone.utc)
+    february = datetime.datetime(2024, 2, 1, tzinfo=datetime.timezone.utc)
+    new = make_grant(name="a new", resource_actions={BalloonAction.CreateBalloon}, created_at=february)
+    old = make_grant(name="b old", resource_actions={BalloonAction.CreateBalloon}, created_at=january)
+    undated = make_grant(name="c undated", resource_actions={BalloonAction.CreateBalloon})
+    broad = make_grant(name="d broad", resource_actions={BalloonAction.CreateBalloon}, created_at=january)
+    broad.resource_actions = {BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon}
+    grants = [undated, broad, new, old]
+
+    assert sort_grants(grants, sort_by=GrantSort.CREATED) == [old, broad, new, undated]
+    assert sort_grants(grants, sort_by=GrantSort.CREATED, descending=True) == [new, broad, old, undated]
+    assert sort_grants(grants, sort_by=GrantSort.NAME) == [new, old, undated, broad]
+    assert sort_grants(grants, sort_by=GrantSort.SPECIFICITY) == [new, old, undated, broad]
+
+
+@pytest.mark.parametrize("descending", [False, True])
+def test_sort_grants_by_creation_keeps_undated_grants_last(make_grant, descending):
+    dates = [datetime.datetime(2024, month, 1, tzinfo=datetime.timezone.utc) for month in [3, 1, 2]]
+    dated = [make_grant(name="dated {}".format(i), created_at=date) for i, date in enumerate(dates)]
+    undated = [make_grant(name="undated {}".format(i)) for i in range(2)]
+    grants = [undated[0], dated[0], undated[1], dated[1], dated[2]]
+
+    sorted_grants = sort_grants(grants, sort_by=GrantSort.CREATED, descending=descending)
+
+    assert sorted_grants[3:] == (undated[::-1] if descending is True else undated)
+    assert [grant.created_at.month for grant in sorted_grants[:3]] == ([3, 2, 1] if descending is True else [1, 2, 3])
+
+
+def test_list_matching_grants_sorted_by_creation(make_authzee, make_grant):
+    clock = FixedClock(now=datetime.datetime(2024, 3, 1, tzinfo=datetime.timezone.utc))
+    authzee_app = make_authzee(clock=clock)
+    for name in ["first", "second"]:
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(name=name, resource_actions={BalloonAction.CreateBalloon})
+        )
+        clock.set(now=datetime.datetime(2024, 4, 1, tzinfo=datetime.timezone.utc))
+
+    grants = list(
+        authzee_app.list_matching_grants(
+            effect=GrantEffect.ALLOW,
+            resource=Balloon(color="red", size=1),
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=[ADUser(cn="user_1")],
+            sort_by=GrantSort.CREATED,
+            descending=True
+        )
+    )
+
+    assert [grant.name for grant in grants] == ["second", "first"]
+    assert grants[1].created_at == datetime.datetime(2024, 3, 1, tzinfo=datetime.timezone.utc)
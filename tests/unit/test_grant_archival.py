@@ -0,0 +1,123 @@
+import asyncio
+import datetime
+
+import pytest
+
+from authzee import AuthzeeConfig, FixedClock, GrantEffect, GrantLifecyclePolicy, exceptions
+
+from conftest import Balloon
+
+
+NOW = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+
+
+def test_archived_grants_are_not_evaluated(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(clock=FixedClock(now=NOW))
+    allow = authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="allow"))
+    deny = authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="deny red", jmespath_expression="resource.color == 'red'")
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+
+    authzee_app.archive_grant(effect=GrantEffect.DENY, uuid=deny.uuid)
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+
+    archived = authzee_app.archive_grant(effect=GrantEffect.ALLOW, uuid=allow.uuid)
+
+    assert archived.grant == allow
+    assert archived.archived_at == NOW
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+    assert list(authzee_app.list_grants(effect=GrantEffect.ALLOW)) == []
+
+
+def test_archived_grants_are_kept_for_audits(make_authzee, make_grant):
+    authzee_app = make_authzee(clock=FixedClock(now=NOW))
+    grants = [
+        authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="grant {}".format(i)))
+        for i in range(3)
+    ]
+    for grant in [grants[2], grants[0]]:
+        authzee_app.archive_grant(effect=GrantEffect.ALLOW, uuid=grant.uuid)
+
+    assert [archived.grant.name for archived in authzee_app.list_archived_grants(effect=GrantEffect.ALLOW)] == [
+        "grant 2",
+        "grant 0"
+    ]
+    assert authzee_app.list_archived_grants(effect=GrantEffect.DENY) == []
+
+
+def test_archiving_a_missing_grant_raises(make_authzee):
+    with pytest.raises(exceptions.GrantDoesNotExistError):
+        make_authzee().archive_grant(effect=GrantEffect.ALLOW, uuid="missing")
+
+
+@pytest.mark.parametrize(
+    "archive_tags, archived_names",
+    [
+        ({}, []),
+        ({"status": "retired"}, ["retired", "retired by team_a"]),
+        ({"status": "retired", "owner": "team_a"}, ["retired by team_a"]),
+        ({"status": "active"}, [])
+    ]
+)
+def test_lifecycle_policy_archives_grants_with_all_tags(make_authzee, make_grant, archive_tags, archived_names):
+    authzee_app = make_authzee()
+    for name, tags in [
+        ("untagged", {}),
+        ("retired", {"status": "retired"}),
+        ("retired by team_a", {"status": "retired", "owner": "team_a"}),
+        ("owned by team_a", {"owner": "team_a"})
+    ]:
+        authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name=name, tags=tags))
+
+    archived = authzee_app.apply_lifecycle_policy(policy=GrantLifecyclePolicy(archive_tags=archive_tags), page_size=1)
+
+    assert [archived_grant.grant.name for archived_grant in archived] == archived_names
+    assert sorted(grant.name for grant in authzee_app.list_grants(effect=GrantEffect.ALLOW)) == sorted(
+        {"untagged", "retired", "retired by team_a", "owned by team_a"} - set(archived_names)
+    )
+
+
+def test_lifecycle_policy_archives_unused_grants(make_authzee, make_grant, request_kwargs):
+    clock = FixedClock(now=NOW)
+    authzee_app = make_authzee(clock=clock, config=AuthzeeConfig(track_grant_usage=True))
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow blue", jmespath_expression="resource.color == 'blue'")
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow green", jmespath_expression="resource.color == 'green'")
+    )
+    authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs)
+    clock.advance(seconds=10 * 24 * 60 * 60)
+    authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs)
+
+    archived = authzee_app.apply_lifecycle_policy(
+        policy=GrantLifecyclePolicy(archive_unused_for=datetime.timedelta(days=5))
+    )
+
+    assert sorted(archived_grant.grant.name for archived_grant in archived) == ["allow green", "allow red"]
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+
+
+def test_lifecycle_policy_async(make_authzee, make_grant):
+    authzee_app = make_authzee(clock=FixedClock(now=NOW))
+    authzee_app.add_grant(effect=GrantEffect.DENY, grant=make_grant(name="retired", tags={"status": "retired"}))
+    authzee_app.add_grant(effect=GrantEffect.DENY, grant=make_grant(name="active"))
+
+    archived = asyncio.run(
+        authzee_app.apply_lifecycle_policy_async(policy=GrantLifecyclePolicy(archive_tags={"status": "retired"}))
+    )
+
+    assert [archived_grant.grant.name for archived_grant in archived] == ["retired"]
+    assert [archived_grant.archived_at for archived_grant in archived] == [NOW]
+    assert [grant.name for grant in authzee_app.list_grants(effect=GrantEffect.DENY)] == ["active"]
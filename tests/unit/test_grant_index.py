@@ -0,0 +1,60 @@
+from pydantic import BaseModel
+
+from authzee import GrantIndex
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+def uuids(grants) -> list:
+    return [grant.uuid for grant in grants]
+
+
+def test_grants_are_looked_up_by_resource_type_and_action(make_grant):
+    grant_index = GrantIndex(grants=[
+        make_grant(name="create", uuid="create"),
+        make_grant(name="delete", uuid="delete", resource_actions={BalloonAction.DeleteBalloon}),
+        make_grant(name="both", uuid="both", resource_actions={BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon}),
+        make_grant(name="user", uuid="user", resource_type=ADUser)
+    ])
+
+    assert len(grant_index) == 4
+    assert uuids(grant_index.grants(resource_type=Balloon, resource_action=BalloonAction.CreateBalloon)) == [
+        "create",
+        "both"
+    ]
+    assert uuids(grant_index.grants(resource_type=Balloon)) == ["create", "delete", "both"]
+    assert uuids(grant_index.grants(resource_action=BalloonAction.DeleteBalloon)) == ["delete", "both"]
+    assert uuids(grant_index.grants()) == ["create", "delete", "both", "user"]
+
+
+def test_unknown_resource_types_have_no_grants(make_grant):
+    class Kite(BaseModel):
+        color: str
+
+    grant_index = GrantIndex(grants=[make_grant(name="create", uuid="create")])
+
+    assert grant_index.grants(resource_type=Kite) == []
+    assert grant_index.grants(resource_type=Kite, resource_action=BalloonAction.CreateBalloon) == []
+
+
+def test_removed_grants_are_not_looked_up(make_grant):
+    grant_index = GrantIndex(grants=[
+        make_grant(name="create", uuid="create"),
+        make_grant(name="both", uuid="both", resource_actions={BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon})
+    ])
+
+    assert grant_index.remove(uuid="both").uuid == "both"
+    assert grant_index.remove(uuid="both") is None
+    assert grant_index.get(uuid="both") is None
+    assert uuids(grant_index.grants(resource_type=Balloon, resource_action=BalloonAction.DeleteBalloon)) == []
+    assert uuids(grant_index.grants(resource_type=Balloon)) == ["create"]
+    assert len(grant_index) == 1
+
+
+def test_adding_a_grant_with_the_same_uuid_replaces_it(make_grant):
+    grant_index = GrantIndex(grants=[make_grant(name="grant", uuid="grant")])
+    grant_index.add(grant=make_grant(name="grant", uuid="grant", resource_actions={BalloonAction.DeleteBalloon}))
+
+    assert len(grant_index) == 1
+    assert grant_index.grants(resource_type=Balloon, resource_action=BalloonAction.CreateBalloon) == []
+    assert grant_index.get(uuid="grant").resource_actions == {BalloonAction.DeleteBalloon}
@@ -0,0 +1,194 @@
+import asyncio
+from typing import Dict, Optional, Set, Type
+
+from pydantic import BaseModel
+
+from authzee import (
+    Authzee,
+    AuthzeeConfig,
+    Grant,
+    GrantEffect,
+    ComputeBackend,
+    MainProcessCompute,
+    MemoryStorage,
+    ResourceAction,
+    RequestLimits,
+    ResourceAuthz,
+    StorageBackend,
+    ThreadedCompute
+)
+
+from conftest import ADUser
+
+
+class Folder(BaseModel):
+
+    path: str
+
+
+class Document(BaseModel):
+
+    name: str
+
+
+class FolderAction(ResourceAction):
+
+    Read: str = "folders:read"
+
+
+class DocumentAction(ResourceAction):
+
+    Read: str = "documents:read"
+    Write: str = "documents:write"
+
+
+class FolderAuthz(ResourceAuthz):
+
+    resource_action_type: Type[ResourceAction] = FolderAction
+    resource_type: Type[BaseModel] = Folder
+    parent_authz_names: Set[str] = set()
+    child_authz_names: Set[str] = {"DocumentAuthz"}
+
+
+class DocumentAuthz(ResourceAuthz):
+
+    resource_action_type: Type[ResourceAction] = DocumentAction
+    resource_type: Type[BaseModel] = Document
+    parent_authz_names: Set[str] = {"FolderAuthz"}
+    child_authz_names: Set[str] = set()
+    inherited_actions: Dict[str, Set[str]] = {"documents:read": {"folders:read"}}
+
+
+def make_inheriting_authzee(
+    grant_inheritance: bool = True,
+    compute_backend: Optional[ComputeBackend] = None,
+    request_limits: Optional[RequestLimits] = None,
+    storage_backend: Optional[StorageBackend] = None
+) -> Authzee:
+    authzee_app = Authzee(
+        compute_backend=compute_backend if compute_backend is not None else MainProcessCompute(),
+        storage_backend=storage_backend if storage_backend is not None else MemoryStorage(),
+        identity_types={ADUser},
+        resource_authz_types={FolderAuthz, DocumentAuthz},
+        config=AuthzeeConfig(
+            grant_inheritance=grant_inheritance,
+            request_limits=request_limits if request_limits is not None else RequestLimits()
+        )
+    )
+    authzee_app.initialize()
+    authzee_app.setup()
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=Grant(
+            name="team folder readers",
+            description="",
+            resource_type=Folder,
+            resource_actions={FolderAction.Read},
+            jmespath_expression="resource.path == '/team' && contains(identities.ADUser[].cn, 'alice')",
+            result_match=True,
+            applies_to_descendants=True
+        )
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=Grant(
+            name="secret folder",
+            description="",
+            resource_type=Folder,
+            resource_actions={FolderAction.Read},
+            jmespath_expression="resource.path == '/secret'",
+            result_match=True,
+            applies_to_descendants=True
+        )
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=Grant(
+            name="private documents",
+            description="",
+            resource_type=Document,
+            resource_actions={DocumentAction.Read},
+            jmespath_expression="resource.name == 'private'",
+            result_match=True
+        )
+    )
+
+    return authzee_app
+
+
+def authorize_document(authzee_app: Authzee, name: str, path: str, cn: str = "alice", action=DocumentAction.Read) -> bool:
+    return authzee_app.authorize(
+        resource=Document(name=name),
+        resource_action=action,
+        parent_resources=[Folder(path=path)],
+        child_resources=[],
+        identities=[ADUser(cn=cn)]
+    )
+
+
+def test_authorize_inherits_parent_grants():
+    authzee_app = make_inheriting_authzee()
+
+    assert authorize_document(authzee_app, name="notes", path="/team") is True
+    assert authorize_document(authzee_app, name="notes", path="/team", cn="bob") is False
+    assert authorize_document(authzee_app, name="private", path="/team") is False
+    assert authorize_document(authzee_app, name="notes", path="/secret") is False
+    assert authorize_document(authzee_app, name="notes", path="/team", action=DocumentAction.Write) is False
+
+
+def test_authorize_without_inheritance():
+    authzee_app = make_inheriting_authzee(grant_inheritance=False)
+
+    assert authorize_document(authzee_app, name="notes", path="/team") is False
+
+
+def test_authorize_many_inherits_parent_grants():
+    authzee_app = make_inheriting_authzee(compute_backend=ThreadedCompute(max_workers=2))
+    documents = [
+        (Document(name="notes"), Folder(path="/team")),
+        (Document(name="private"), Folder(path="/team")),
+        (Document(name="notes"), Folder(path="/secret")),
+        (Document(name="notes"), Folder(path="/other"))
+    ]
+    identities = [ADUser(cn="alice")]
+
+    single = [
+        authorize_document(authzee_app, name=document.name, path=folder.path)
+        for document, folder in documents
+    ]
+    many = authzee_app.authorize_many(
+        resources=[document for document, _ in documents],
+        resource_action=DocumentAction.Read,
+        parent_resources=[],
+        child_resources=[],
+        identities=identities,
+        per_resource_parents=[[folder] for _, folder in documents]
+    )
+    many_async = asyncio.run(
+        authzee_app.authorize_many_async(
+            resources=[document for document, _ in documents],
+            resource_action=DocumentAction.Read,
+            parent_resources=[],
+            child_resources=[],
+            identities=identities,
+            per_resource_parents=[[folder] for _, folder in documents]
+        )
+    )
+
+    assert single == [True, False, False, False]
+    assert many == single
+    assert many_async == single
+
+
+def test_authorize_many_inherits_shared_parent_grants():
+    authzee_app = make_inheriting_authzee()
+
+    many = authzee_app.authorize_many(
+        resources=[Document(name="notes"), Document(name="private")],
+        resource_action=DocumentAction.Read,
+        parent_resources=[Folder(path="/team")],
+        child_resources=[],
+        identities=[ADUser(cn="alice")]
+    )
+
+    assert many == [True, False]
@@ -0,0 +1,108 @@
+import jmespath
+import pytest
+
+from authzee import AuthzeeConfig, ErrorSeverity, GrantEffect, GrantLimits, exceptions
+from authzee.grant_limits import expression_depth
+
+from conftest import Balloon
+
+
+@pytest.mark.parametrize(
+    "expression, depth",
+    [
+        ("`true`", 1),
+        ("resource.color", 2),
+        ("resource.color == 'red'", 3),
+        ("a && (b || (c && d))", 4)
+    ]
+)
+def test_expression_depth(expression, depth):
+    assert expression_depth(parsed=jmespath.compile(expression).parsed) == depth
+
+
+@pytest.mark.parametrize(
+    "grant_limits, fields, match",
+    [
+        (
+            GrantLimits(max_expression_length=10),
+            {"jmespath_expression": "resource.color == 'red'"},
+            "23 characters, but the max is 10"
+        ),
+        (
+            GrantLimits(max_expression_depth=2),
+            {"jmespath_expression": "resource.color == 'red'"},
+            "depth of 3, but the max is 2"
+        ),
+        (
+            GrantLimits(max_result_match_size=5),
+            {"result_match": "a long string"},
+            "15 characters as JSON, but the max is 5"
+        )
+    ]
+)
+def test_grants_over_the_limits_are_rejected(make_authzee, make_grant, grant_limits, fields, match):
+    authzee_app = make_authzee(config=AuthzeeConfig(grant_limits=grant_limits))
+
+    with pytest.raises(exceptions.InputVerificationError, match=match):
+        authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(**fields))
+
+
+def test_grants_at_the_limits_are_added(make_authzee, make_grant):
+    authzee_app = make_authzee(
+        config=AuthzeeConfig(
+            grant_limits=GrantLimits(
+                max_expression_length=23,
+                max_expression_depth=3,
+                max_result_match_size=4
+            )
+        )
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color == 'red'", result_match=True)
+    )
+
+    assert len(list(authzee_app.list_grants(effect=GrantEffect.ALLOW))) == 1
+
+
+def test_no_limits_by_default(make_authzee, make_grant):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(
+            jmespath_expression=" || ".join("resource.color == '{}'".format(i) for i in range(200)),
+            result_match=True
+        )
+    )
+
+    assert len(list(authzee_app.list_grants(effect=GrantEffect.ALLOW))) == 1
+
+
+def test_search_results_over_the_limit_do_not_match(make_authzee, make_grant, request_kwargs, compute_backend_factory):
+    authzee_app = make_authzee(
+        compute_backend=compute_backend_factory(),
+        config=AuthzeeConfig(grant_limits=GrantLimits(max_search_result_size=5))
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color", result_match="red")
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="reddish", size=1), **request_kwargs) is False
+
+
+def test_search_results_over_the_limit_raise_when_critical(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(
+        config=AuthzeeConfig(
+            grant_limits=GrantLimits(max_search_result_size=5),
+            jmespath_error_severity=ErrorSeverity.CRITICAL
+        )
+    )
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color", result_match="red")
+    )
+
+    with pytest.raises(exceptions.GrantEvaluationError):
+        authzee_app.authorize(resource=Balloon(color="reddish", size=1), **request_kwargs)
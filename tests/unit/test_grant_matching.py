@@ -0,0 +1,111 @@
+import pytest
+
+from authzee import Grant, GrantMatchMode
+from authzee.compute import general as gc
+
+from conftest import Balloon, BalloonAction
+
+
+@pytest.mark.parametrize(
+    "result, expected",
+    [
+        (["a", "b", "c"], True),
+        (["c", "a", "b"], False)
+    ]
+)
+def test_arrays_are_ordered_by_default(make_grant, result, expected):
+    assert gc.grant_result_matches(grant=make_grant(result_match=["a", "b", "c"]), result=result) is expected
+
+
+@pytest.mark.parametrize(
+    "result_match, result, expected",
+    [
+        (["a", "b", "c"], ["c", "a", "b"], True),
+        (["x", {"y": ["a", "b"]}], [{"y": ["b", "a"]}, "x"], True),
+        (["a", "b", "c"], ["a", "b"], False),
+        (["a", "b", "b"], ["a", "a", "b"], False)
+    ]
+)
+def test_order_insensitive_arrays(make_grant, result_match, result, expected):
+    grant = make_grant(result_match=result_match, order_insensitive_arrays=True)
+
+    assert gc.grant_result_matches(grant=grant, result=result) is expected
+
+
+@pytest.mark.parametrize(
+    "result, expected",
+    [
+        (0.3, True),
+        (0.1 + 0.2, True),
+        (0.31, False),
+        (True, False)
+    ]
+)
+def test_numeric_tolerance(make_grant, result, expected):
+    grant = make_grant(result_match=0.3, numeric_tolerance=0.001)
+
+    assert gc.grant_result_matches(grant=grant, result=result) is expected
+
+
+@pytest.mark.parametrize("numeric_tolerance", [-0.001, float("nan"), float("inf")])
+def test_numeric_tolerance_must_be_finite_and_not_negative(make_grant, numeric_tolerance):
+    with pytest.raises(ValueError, match="numeric_tolerance"):
+        make_grant(result_match=0.3, numeric_tolerance=numeric_tolerance)
+
+
+@pytest.mark.parametrize(
+    "result, expected",
+    [
+        ("red", True),
+        ("blue", True),
+        ("green", False),
+        (["red", "blue"], False)
+    ]
+)
+def test_one_of_match_mode(make_grant, result, expected):
+    grant = make_grant(result_match=["red", "blue"], match_mode=GrantMatchMode.ONE_OF)
+
+    assert gc.grant_result_matches(grant=grant, result=result) is expected
+
+
+def test_one_of_match_mode_requires_a_list(make_grant):
+    with pytest.raises(ValueError, match="must be a list"):
+        make_grant(result_match="red", match_mode=GrantMatchMode.ONE_OF)
+
+
+@pytest.mark.parametrize(
+    "result, expected",
+    [
+        ("red", True),
+        (0, True),
+        ([None], True),
+        (None, False),
+        (False, False),
+        ("", False),
+        ([], False),
+        ({}, False)
+    ]
+)
+def test_truthy_match_mode(make_grant, result, expected):
+    grant = make_grant(result_match=None, match_mode=GrantMatchMode.TRUTHY)
+
+    assert gc.grant_result_matches(grant=grant, result=result) is expected
+
+
+def test_truthy_match_mode_does_not_require_result_match():
+    grant = Grant(
+        name="truthy",
+        description="",
+        resource_type=Balloon,
+        resource_actions={BalloonAction.CreateBalloon},
+        jmespath_expression="resource.color",
+        match_mode=GrantMatchMode.TRUTHY
+    )
+
+    assert grant.result_match is None
+
+
+@pytest.mark.parametrize("result_match", [True, False, "red"])
+def test_truthy_match_mode_rejects_result_match(make_grant, result_match):
+    with pytest.raises(ValueError, match="must be None when 'match_mode' is TRUTHY"):
+        make_grant(result_match=result_match, match_mode=GrantMatchMode.TRUTHY)
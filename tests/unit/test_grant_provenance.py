@@ -0,0 +1,63 @@
+from authzee import GrantEffect, GrantProvenance
+from authzee.grant import compact_grants
+
+from conftest import Balloon, BalloonAction
+
+
+PROVENANCE = GrantProvenance(
+    source="balloons bundle",
+    importer="bundle loader",
+    caller="admin@example.com",
+    commit_sha="abc123"
+)
+
+
+def test_provenance_is_returned_with_the_grant(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(provenance=PROVENANCE))
+
+    listed = list(authzee_app.list_grants(effect=GrantEffect.ALLOW))
+    matching = list(
+        authzee_app.list_matching_grants(
+            effect=GrantEffect.ALLOW,
+            resource=Balloon(color="red", size=1),
+            **request_kwargs
+        )
+    )
+
+    assert listed[0].provenance == PROVENANCE
+    assert matching[0].provenance == PROVENANCE
+
+
+def test_provenance_is_optional(make_grant):
+    assert make_grant().provenance is None
+    assert GrantProvenance(source="balloons bundle").commit_sha is None
+
+
+def test_provenance_does_not_change_decisions(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color == 'red'", provenance=PROVENANCE)
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is False
+
+
+def test_grants_with_different_provenance_are_not_compacted(make_grant):
+    compaction = compact_grants(
+        [
+            make_grant(name="create", resource_actions={BalloonAction.CreateBalloon}, provenance=PROVENANCE),
+            make_grant(name="delete", resource_actions={BalloonAction.DeleteBalloon})
+        ]
+    )
+
+    assert compaction.compacted_count == 2
+    assert [grant.provenance for grant in compaction.grants] == [PROVENANCE, None]
+
+
+def test_provenance_survives_a_json_round_trip(make_grant):
+    grant = make_grant(provenance=PROVENANCE)
+
+    assert GrantProvenance.parse_raw(grant.provenance.json()) == PROVENANCE
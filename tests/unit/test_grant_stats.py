@@ -0,0 +1,52 @@
+import asyncio
+
+import pytest
+
+from authzee import GrantEffect
+
+from conftest import BalloonAction
+
+
+def test_grant_stats_without_grants(make_authzee):
+    stats = make_authzee().grant_stats()
+
+    assert stats.total_grants == 0
+    assert stats.grants_by_effect == {"ALLOW": 0, "DENY": 0}
+    assert stats.grants_by_resource_type == {"Balloon": 0}
+    assert stats.grants_by_action == {"BalloonAction.CreateBalloon": 0, "BalloonAction.DeleteBalloon": 0}
+    assert stats.average_grant_size == 0.0
+
+
+def test_grant_stats_count_coverage_and_size(make_authzee, make_grant):
+    authzee_app = make_authzee()
+    # "`true`" and "true" are 10 characters
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="allow"))
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="allow again"))
+    # "resource.color == 'red'" and "true" are 27 characters
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(
+            name="deny red",
+            resource_actions={BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon},
+            jmespath_expression="resource.color == 'red'"
+        )
+    )
+
+    stats = authzee_app.grant_stats(page_size=1)
+
+    assert stats.total_grants == 3
+    assert stats.grants_by_effect == {"ALLOW": 2, "DENY": 1}
+    assert stats.grants_by_resource_type == {"Balloon": 3}
+    assert stats.grants_by_action == {"BalloonAction.CreateBalloon": 3, "BalloonAction.DeleteBalloon": 1}
+    assert stats.average_grant_size == pytest.approx(47 / 3)
+
+
+def test_grant_stats_async_matches_sync(make_authzee, make_grant):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="allow"))
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="deny delete", resource_actions={BalloonAction.DeleteBalloon})
+    )
+
+    assert asyncio.run(authzee_app.grant_stats_async()) == authzee_app.grant_stats()
@@ -0,0 +1,54 @@
+from authzee import AuthzRequest, GrantEffect
+
+from conftest import Balloon, BalloonAction
+
+
+TAGS = {"owner": "team_a", "ticket": "SEC-42"}
+
+
+def test_tags_are_returned_with_the_grant(make_authzee, make_grant):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="tagged", tags=TAGS))
+    authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="untagged"))
+
+    assert {
+        grant.name: grant.tags
+        for grant in authzee_app.list_grants(effect=GrantEffect.ALLOW)
+    } == {"tagged": TAGS, "untagged": {}}
+
+
+def test_tags_do_not_change_what_a_grant_matches(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color == 'red'", tags=TAGS)
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is False
+    assert make_grant(tags=TAGS).canonical_hash() == make_grant().canonical_hash()
+
+
+def test_tags_are_shown_in_explanations(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee()
+    grant = authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(tags=TAGS))
+
+    explanation = authzee_app.explain(
+        request=AuthzRequest(resource=Balloon(color="red", size=1), **request_kwargs)
+    )
+
+    assert explanation.deciding_grant_uuid == grant.uuid
+    assert [grant_explanation.tags for grant_explanation in explanation.grants] == [TAGS]
+
+
+def test_tags_are_shown_in_policy_docs(make_authzee, make_grant):
+    authzee_app = make_authzee()
+    authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(name="deny delete", resource_actions={BalloonAction.DeleteBalloon}, tags=TAGS)
+    )
+
+    docs = authzee_app.policy_docs()
+
+    assert "| Tags |" in docs
+    assert "| owner=team_a, ticket=SEC-42 |" in docs
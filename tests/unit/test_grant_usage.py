@@ -0,0 +1,80 @@
+import asyncio
+import datetime
+
+from authzee import AuthzeeConfig, FixedClock, Grant, GrantEffect, ThreadedCompute, exceptions
+from authzee.storage import MemoryStorage
+
+from conftest import Balloon
+
+
+class FailingUsageStorage(MemoryStorage):
+
+    def record_grant_usage(self, effect, last_used):
+        raise exceptions.StorageBackendError("grant usage write failed")
+
+
+    async def record_grant_usage_async(self, effect, last_used):
+        raise exceptions.StorageBackendError("grant usage write failed")
+
+
+def add_allow_grant(authzee_app, make_grant) -> Grant:
+    return authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+
+
+def test_grant_usage_write_errors_do_not_change_decisions(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    compute_backend_factory
+):
+    authzee_app = make_authzee(
+        compute_backend=compute_backend_factory(),
+        storage_backend=FailingUsageStorage(),
+        config=AuthzeeConfig(track_grant_usage=True)
+    )
+    add_allow_grant(authzee_app, make_grant)
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize_many(resources=[Balloon(color="red", size=1)], **request_kwargs) == [True]
+
+
+def test_grant_usage_write_errors_do_not_change_async_decisions(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(
+        compute_backend=ThreadedCompute(max_workers=2),
+        storage_backend=FailingUsageStorage(),
+        config=AuthzeeConfig(track_grant_usage=True)
+    )
+    add_allow_grant(authzee_app, make_grant)
+
+    async def authorize():
+        return (
+            await authzee_app.authorize_async(resource=Balloon(color="red", size=1), **request_kwargs),
+            await authzee_app.authorize_many_async(resources=[Balloon(color="red", size=1)], **request_kwargs)
+        )
+
+    assert asyncio.run(authorize()) == (True, [True])
+
+
+def test_grant_usage_is_written_in_batches(make_authzee, make_grant, request_kwargs, compute_backend_factory):
+    now = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+    storage = MemoryStorage()
+    authzee_app = make_authzee(
+        compute_backend=compute_backend_factory(),
+        storage_backend=storage,
+        config=AuthzeeConfig(track_grant_usage=True, grant_usage_batch_size=2),
+        clock=FixedClock(now=now)
+    )
+    grant = add_allow_grant(authzee_app, make_grant)
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow small", jmespath_expression="resource.size < `10`")
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=50), **request_kwargs) is True
+    assert storage.get_grant_usage(effect=GrantEffect.ALLOW) == {}
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is True
+    assert len(storage.get_grant_usage(effect=GrantEffect.ALLOW)) == 2
+    assert authzee_app.get_grant_usage(effect=GrantEffect.ALLOW)[grant.uuid] == now
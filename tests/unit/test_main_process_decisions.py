@@ -0,0 +1,196 @@
+import pytest
+
+from authzee import (
+    AuthzeeConfig,
+    AuthzRequest,
+    FailPolicy,
+    FailureDecision,
+    GrantEffect,
+    RequestLimits,
+    exceptions
+)
+from authzee import authzee as authzee_module
+from authzee.storage import FaultInjectingStorage
+
+from conftest import ADUser, Balloon, BalloonAction
+from test_grant_inheritance import Document, DocumentAction, Folder, FolderAction, make_inheriting_authzee
+
+
+DOCUMENT_CASES = [
+    ("notes", "/team", "alice", True),
+    ("notes", "/team", "bob", False),
+    ("private", "/team", "alice", False),
+    ("notes", "/secret", "alice", False)
+]
+
+
+class RecordingLogger:
+
+    def __init__(self):
+        self.warnings = []
+
+
+    def warning(self, message):
+        self.warnings.append(message)
+
+
+    def __getattr__(self, name):
+        return lambda *args, **kwargs: None
+
+
+def document_request(name: str, path: str, cn: str) -> AuthzRequest:
+    return AuthzRequest(
+        resource=Document(name=name),
+        resource_action=DocumentAction.Read,
+        parent_resources=[Folder(path=path)],
+        identities=[ADUser(cn=cn)]
+    )
+
+
+@pytest.mark.parametrize("name, path, cn, expected", DOCUMENT_CASES)
+def test_main_process_decisions_inherit_parent_grants(name, path, cn, expected):
+    authzee_app = make_inheriting_authzee()
+    request = document_request(name=name, path=path, cn=cn)
+    request_kwargs = {
+        "resource": request.resource,
+        "resource_action": request.resource_action,
+        "parent_resources": request.parent_resources,
+        "child_resources": request.child_resources,
+        "identities": request.identities
+    }
+
+    assert authzee_app.authorize(**request_kwargs) is expected
+    assert authzee_app.explain(request=request).decision is expected
+    assert authzee_app.capture_capsule(request=request).decision is expected
+    assert authzee_app.evaluation_session().authorize(**request_kwargs) is expected
+
+
+def test_explain_names_the_inherited_deciding_grant():
+    authzee_app = make_inheriting_authzee()
+    explanation = authzee_app.explain(request=document_request(name="notes", path="/team", cn="alice"))
+    folder_grant = next(
+        grant
+        for grant in authzee_app.list_grants(effect=GrantEffect.ALLOW)
+        if grant.name == "team folder readers"
+    )
+
+    assert explanation.deciding_grant_uuid == folder_grant.uuid
+
+
+def test_shadow_grants_inherit_parent_shadow_grants(monkeypatch):
+    logger = RecordingLogger()
+    monkeypatch.setattr(authzee_module, "logger", logger)
+    authzee_app = make_inheriting_authzee()
+    stored_grants = {effect: list(authzee_app.list_grants(effect=effect)) for effect in GrantEffect}
+
+    for name, path, cn, expected in DOCUMENT_CASES:
+        request = document_request(name=name, path=path, cn=cn)
+        assert authzee_app.shadow_authorize(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=request.parent_resources,
+            child_resources=request.child_resources,
+            identities=request.identities,
+            shadow_allow_grants=stored_grants[GrantEffect.ALLOW],
+            shadow_deny_grants=stored_grants[GrantEffect.DENY]
+        ) is expected
+
+    assert logger.warnings == []
+
+
+def add_allow_grant(authzee_app, make_grant) -> None:
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+
+
+def balloon_request() -> AuthzRequest:
+    return AuthzRequest(
+        resource=Balloon(color="red", size=1),
+        resource_action=BalloonAction.CreateBalloon,
+        identities=[ADUser(cn="user_1")]
+    )
+
+
+def test_main_process_decisions_use_the_fail_policy(make_authzee, make_grant):
+    storage = FaultInjectingStorage()
+    authzee_app = make_authzee(
+        storage_backend=storage,
+        config=AuthzeeConfig(fail_policy=FailPolicy(storage_failure=FailureDecision.DENY))
+    )
+    add_allow_grant(authzee_app, make_grant)
+
+    storage.fail_on_call = len(storage.calls) + 1
+    assert authzee_app.explain(request=balloon_request()).decision is False
+    storage.fail_on_call = len(storage.calls) + 1
+    assert authzee_app.capture_capsule(request=balloon_request()).decision is False
+    assert authzee_app.explain(request=balloon_request()).decision is True
+
+
+def test_main_process_decisions_use_the_evaluation_budget(make_authzee, make_grant):
+    authzee_app = make_authzee(config=AuthzeeConfig(request_limits=RequestLimits(max_evaluation_cost=1)))
+    add_allow_grant(authzee_app, make_grant)
+    request = balloon_request()
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        authzee_app.authorize(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=[],
+            child_resources=[],
+            identities=request.identities
+        )
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        authzee_app.explain(request=request)
+
+    with pytest.raises(exceptions.EvaluationBudgetExceededError):
+        authzee_app.evaluation_session().authorize(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=[],
+            child_resources=[],
+            identities=request.identities
+        )
+
+
+def decision_or_error(decide):
+    try:
+        return decide()
+    except exceptions.AuthzeeError as error:
+        return type(error)
+
+
+@pytest.mark.parametrize(
+    "max_evaluation_cost, expected",
+    [
+        (1000, False),
+        (len("resource.name == 'private'") + 1, exceptions.EvaluationBudgetExceededError)
+    ]
+)
+def test_authorize_and_explain_agree_on_inherited_grants_over_the_budget(make_grant, max_evaluation_cost, expected):
+    authzee_app = make_inheriting_authzee(request_limits=RequestLimits(max_evaluation_cost=max_evaluation_cost))
+    for i in range(5):
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(
+                name="folder {}".format(i),
+                resource_type=Folder,
+                resource_actions={FolderAction.Read},
+                jmespath_expression="resource.path == '/folder_{}'".format(i),
+                applies_to_descendants=True
+            )
+        )
+    request = document_request(name="readme", path="/public", cn="bob")
+
+    assert decision_or_error(
+        lambda: authzee_app.authorize(
+            resource=request.resource,
+            resource_action=request.resource_action,
+            parent_resources=request.parent_resources,
+            child_resources=request.child_resources,
+            identities=request.identities
+        )
+    ) is expected
+    assert decision_or_error(lambda: authzee_app.explain(request=request).decision) is expected
@@ -0,0 +1,55 @@
+import asyncio
+import time
+
+from authzee import GrantEffect
+from authzee.storage import MemoryStorage
+
+from conftest import ADUser, BalloonAuthz
+
+
+def make_storage(make_grant, **kwargs) -> MemoryStorage:
+    storage = MemoryStorage(**kwargs)
+    storage.initialize(identity_types={ADUser}, resource_authzs=[BalloonAuthz()])
+    for i in range(5):
+        storage.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name="grant {}".format(i)))
+
+    return storage
+
+
+def test_pages_hold_the_grants_in_order(make_grant):
+    storage = make_storage(make_grant, default_page_size=2)
+    names = []
+    next_page_reference = None
+    page_count = 0
+    while True:
+        page = storage.get_raw_grants_page(effect=GrantEffect.ALLOW, next_page_reference=next_page_reference)
+        names += [grant.name for grant in page.raw_grants]
+        page_count += 1
+        next_page_reference = page.next_page_reference
+        if next_page_reference is None:
+            break
+
+    assert page_count == 3
+    assert names == ["grant {}".format(i) for i in range(5)]
+
+
+def test_kwargs_recreate_the_storage():
+    storage = MemoryStorage(default_page_size=3, page_latency=0.5)
+
+    assert storage.kwargs == {"default_page_size": 3, "page_latency": 0.5}
+    assert MemoryStorage(**storage.kwargs).kwargs == storage.kwargs
+
+
+def test_async_page_latency_does_not_block_the_event_loop(make_grant):
+    storage = make_storage(make_grant, page_latency=0.2)
+
+    async def get_pages():
+        return await asyncio.gather(
+            *[storage.get_raw_grants_page_async(effect=GrantEffect.ALLOW) for _ in range(5)]
+        )
+
+    start = time.monotonic()
+    pages = asyncio.run(get_pages())
+
+    assert time.monotonic() - start < 0.6
+    assert all(len(page.raw_grants) == 5 for page in pages)
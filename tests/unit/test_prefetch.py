@@ -0,0 +1,90 @@
+import pytest
+
+from authzee import GrantEffect, MainProcessCompute, exceptions
+from authzee.storage import MemoryStorage
+
+from conftest import Balloon
+
+
+RESOURCES = [
+    Balloon(color="red", size=1),
+    Balloon(color="red", size=50),
+    Balloon(color="green", size=1),
+    Balloon(color="blue", size=1)
+]
+
+
+class FailingPageStorage(MemoryStorage):
+    """Memory storage that fails on the second page of grants.
+    """
+
+    def __init__(self):
+        super().__init__(default_page_size=1)
+
+
+    def get_raw_grants_page(self, *args, next_page_reference=None, **kwargs):
+        if next_page_reference is not None:
+            raise exceptions.StorageBackendError("Second page is unavailable.")
+
+        return super().get_raw_grants_page(*args, next_page_reference=next_page_reference, **kwargs)
+
+
+def add_grants(authzee_app, make_grant) -> None:
+    for color in ("blue", "green", "red"):
+        authzee_app.add_grant(
+            effect=GrantEffect.ALLOW,
+            grant=make_grant(
+                name="allow {}".format(color),
+                jmespath_expression="resource.color == '{}'".format(color)
+            )
+        )
+
+    for size in (10, 20, 30):
+        authzee_app.add_grant(
+            effect=GrantEffect.DENY,
+            grant=make_grant(
+                name="deny size over {}".format(size),
+                jmespath_expression="resource.size > `{}`".format(size)
+            )
+        )
+
+
+def test_prefetched_decisions_match_unprefetched_decisions(make_authzee, make_grant, request_kwargs):
+    decisions = []
+    for compute_backend in (MainProcessCompute(), MainProcessCompute(prefetch_pages=2)):
+        authzee_app = make_authzee(
+            compute_backend=compute_backend,
+            storage_backend=MemoryStorage(default_page_size=1)
+        )
+        add_grants(authzee_app, make_grant)
+        decisions.append((
+            [authzee_app.authorize(resource=resource, **request_kwargs) for resource in RESOURCES],
+            authzee_app.authorize_many(resources=RESOURCES, **request_kwargs)
+        ))
+
+    assert decisions[0] == decisions[1]
+    assert decisions[1] == ([True, False, True, True], [True, False, True, True])
+
+
+@pytest.mark.parametrize("prefetch_pages", [1, 5])
+def test_prefetching_reaches_grants_on_the_last_page(make_authzee, make_grant, request_kwargs, prefetch_pages):
+    authzee_app = make_authzee(
+        compute_backend=MainProcessCompute(prefetch_pages=prefetch_pages),
+        storage_backend=MemoryStorage(default_page_size=1)
+    )
+    add_grants(authzee_app, make_grant)
+
+    assert authzee_app.authorize(resource=Balloon(color="yellow", size=1), **request_kwargs) is False
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=31), **request_kwargs) is False
+    assert authzee_app.authorize(resource=Balloon(color="blue", size=1), **request_kwargs) is True
+
+
+def test_prefetch_storage_errors_are_raised_to_the_caller(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(
+        compute_backend=MainProcessCompute(prefetch_pages=2),
+        storage_backend=FailingPageStorage()
+    )
+    add_grants(authzee_app, make_grant)
+
+    with pytest.raises(exceptions.StorageBackendError, match="Second page"):
+        authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs)
@@ -0,0 +1,133 @@
+from typing import List, Set, Type
+
+from pydantic import BaseModel
+import pytest
+
+from authzee import (
+    Authzee,
+    Grant,
+    GrantEffect,
+    MainProcessCompute,
+    MemoryStorage,
+    RelationshipResolver,
+    ResourceAction,
+    ResourceAuthz,
+    exceptions
+)
+
+from conftest import ADUser, Balloon, BalloonAction, BalloonAuthz
+
+
+class BalloonString(BaseModel):
+
+    color: str
+
+
+class BalloonStringAction(ResourceAction):
+
+    Cut: str = "strings:cut"
+
+
+class BalloonWithStringsAuthz(BalloonAuthz):
+
+    child_authz_names: Set[str] = {"BalloonStringAuthz"}
+
+
+class BalloonStringAuthz(ResourceAuthz):
+
+    resource_action_type: Type[ResourceAction] = BalloonStringAction
+    resource_type: Type[BaseModel] = BalloonString
+    parent_authz_names: Set[str] = {"BalloonWithStringsAuthz"}
+    child_authz_names: Set[str] = set()
+
+
+class StringResolver(RelationshipResolver):
+
+    def __init__(self):
+        super().__init__()
+        self.resolved: List[BaseModel] = []
+
+
+    def fetch_parent_resources(self, resource: BaseModel) -> List[BaseModel]:
+        return []
+
+
+    def fetch_child_resources(self, resource: BaseModel) -> List[BaseModel]:
+        self.resolved.append(resource)
+
+        return [BalloonString(color=resource.color)]
+
+
+class Unregistered(BaseModel):
+
+    color: str
+
+
+def make_resolving_authzee(resolver: RelationshipResolver) -> Authzee:
+    authzee_app = Authzee(
+        compute_backend=MainProcessCompute(),
+        storage_backend=MemoryStorage(),
+        identity_types={ADUser},
+        resource_authz_types={BalloonWithStringsAuthz, BalloonStringAuthz},
+        relationship_resolver=resolver
+    )
+    authzee_app.initialize()
+    authzee_app.setup()
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=Grant(
+            name="red strings",
+            description="",
+            resource_type=Balloon,
+            resource_actions={BalloonAction.CreateBalloon},
+            jmespath_expression="child_resources.BalloonString[0].color == 'red'",
+            result_match=True
+        )
+    )
+
+    return authzee_app
+
+
+def test_resolved_resources_are_used():
+    authzee_app = make_resolving_authzee(resolver=StringResolver())
+    identities = [ADUser(cn="user_1")]
+
+    assert authzee_app.authorize(
+        resource=Balloon(color="red", size=1),
+        resource_action=BalloonAction.CreateBalloon,
+        parent_resources=[],
+        child_resources=[],
+        identities=identities
+    ) is True
+    assert authzee_app.authorize_many(
+        resources=[Balloon(color="red", size=1), Balloon(color="blue", size=1)],
+        resource_action=BalloonAction.CreateBalloon,
+        parent_resources=[],
+        child_resources=[],
+        identities=identities
+    ) == [True, False]
+
+
+def test_input_is_verified_before_resolving():
+    resolver = StringResolver()
+    authzee_app = make_resolving_authzee(resolver=resolver)
+
+    with pytest.raises(exceptions.InputVerificationError):
+        authzee_app.authorize(
+            resource=Balloon(color="red", size=1),
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[Unregistered(color="red")],
+            identities=[ADUser(cn="user_1")]
+        )
+
+    with pytest.raises(exceptions.InputVerificationError):
+        authzee_app.authorize(
+            resource=Unregistered(color="red"),
+            resource_action=BalloonAction.CreateBalloon,
+            parent_resources=[],
+            child_resources=[],
+            identities=[ADUser(cn="user_1")]
+        )
+
+    assert resolver.resolved == []
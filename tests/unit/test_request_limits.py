@@ -0,0 +1,82 @@
+import pytest
+
+from authzee import AuthzeeConfig, GrantEffect, RequestLimits, exceptions
+
+from conftest import ADUser, Balloon, BalloonAction
+
+
+def make_limited_authzee(make_authzee, make_grant, **limits):
+    authzee_app = make_authzee(config=AuthzeeConfig(request_limits=RequestLimits(**limits)))
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(name="allow red", jmespath_expression="resource.color == 'red'")
+    )
+
+    return authzee_app
+
+
+def test_requests_at_the_limits_are_authorized(make_authzee, make_grant):
+    authzee_app = make_limited_authzee(
+        make_authzee,
+        make_grant,
+        max_resources=2,
+        max_parent_resources=0,
+        max_child_resources=0,
+        max_identities_per_type=2
+    )
+
+    assert authzee_app.authorize_many(
+        resources=[Balloon(color="red", size=1), Balloon(color="blue", size=1)],
+        resource_action=BalloonAction.CreateBalloon,
+        parent_resources=[],
+        child_resources=[],
+        identities=[ADUser(cn="user_1"), ADUser(cn="user_2")]
+    ) == [True, False]
+
+
+@pytest.mark.parametrize(
+    "limits, over_limit_kwargs, message",
+    [
+        (
+            {"max_parent_resources": 1},
+            {"parent_resources": [Balloon(color="red", size=1)] * 2},
+            "parent resources"
+        ),
+        (
+            {"max_child_resources": 0},
+            {"child_resources": [Balloon(color="red", size=1)]},
+            "child resources"
+        ),
+        (
+            {"max_identities_per_type": 1},
+            {"identities": [ADUser(cn="user_1"), ADUser(cn="user_2")]},
+            "ADUser"
+        )
+    ]
+)
+def test_requests_over_the_limits_are_rejected(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    limits,
+    over_limit_kwargs,
+    message
+):
+    authzee_app = make_limited_authzee(make_authzee, make_grant, **limits)
+
+    with pytest.raises(exceptions.RequestLimitError, match=message):
+        authzee_app.authorize(resource=Balloon(color="red", size=1), **{**request_kwargs, **over_limit_kwargs})
+
+
+def test_too_many_resources_are_rejected(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_limited_authzee(make_authzee, make_grant, max_resources=1)
+
+    with pytest.raises(exceptions.RequestLimitError, match="2 resources were given"):
+        authzee_app.authorize_many(
+            resources=[Balloon(color="red", size=1), Balloon(color="red", size=2)],
+            **request_kwargs
+        )
+
+
+def test_request_limit_errors_are_input_verification_errors():
+    assert issubclass(exceptions.RequestLimitError, exceptions.InputVerificationError)
@@ -0,0 +1,71 @@
+import random
+
+from authzee import GrantEffect, MainProcessCompute, Rng, SeededRng
+
+from conftest import Balloon
+
+
+def test_seeded_rng_repeats_the_sequence_for_a_seed():
+    first = SeededRng(seed=7)
+    second = SeededRng(seed=7)
+
+    assert [first.random() for _ in range(5)] == [second.random() for _ in range(5)]
+    assert SeededRng(seed=7).random() != SeededRng(seed=8).random()
+
+
+def test_rng_uses_the_random_module():
+    random.seed(3)
+    expected = random.random()
+    random.seed(3)
+
+    assert Rng().random() == expected
+
+
+def test_authzee_passes_rng_to_compute_backend(make_authzee):
+    rng = SeededRng(seed=1)
+    compute = MainProcessCompute()
+    make_authzee(compute_backend=compute, rng=rng)
+
+    assert compute._rng is rng
+
+
+def test_compute_backend_defaults_to_rng(make_authzee):
+    compute = MainProcessCompute()
+    make_authzee(compute_backend=compute)
+
+    assert type(compute._rng) is Rng
+
+
+class CountingRng(Rng):
+
+    def __init__(self):
+        self.calls = 0
+
+
+    def random(self) -> float:
+        self.calls += 1
+
+        return 0.0
+
+
+def test_canary_grants_sample_with_the_injected_rng(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    compute_backend_factory
+):
+    rng = CountingRng()
+    authzee_app = make_authzee(compute_backend=compute_backend_factory(), rng=rng)
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(
+            name="canary allow red",
+            jmespath_expression="resource.color == 'red'",
+            canary_sample_rate=0.5
+        )
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+    assert rng.calls == 1
+    assert authzee_app.authorize_many(resources=[Balloon(color="red", size=1)] * 2, **request_kwargs) == [False, False]
+    assert rng.calls == 3
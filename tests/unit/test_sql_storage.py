@@ -0,0 +1,151 @@
+import datetime
+
+import pytest
+
+pytest.importorskip("sqlalchemy")
+pytest.importorskip("aiosqlite")
+
+from sqlalchemy import text
+
+from authzee import (
+    AuthzeeConfig,
+    FixedClock,
+    GrantEffect,
+    GrantMatchMode,
+    GrantProvenance,
+    RelationTuple
+)
+from authzee.storage import SQLRelationStore, SQLStorage
+
+from conftest import Balloon, BalloonAction
+
+
+SQLITE_KWARGS = {"url": "sqlite+aiosqlite:///:memory:"}
+NOW = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+
+
+def test_grant_fields_are_stored(make_authzee, make_grant):
+    authzee_app = make_authzee(storage_backend=SQLStorage(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS))
+    grant = authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(
+            resource_actions={BalloonAction.CreateBalloon, BalloonAction.DeleteBalloon},
+            jmespath_expression="resource.size",
+            result_match=[0.3, 7],
+            order_insensitive_arrays=True,
+            numeric_tolerance=0.01,
+            match_mode=GrantMatchMode.ONE_OF,
+            applies_to_descendants=True,
+            provenance=GrantProvenance(source="balloons bundle", commit_sha="abc123"),
+            tags={"owner": "team_a"},
+            canary_sample_rate=0.5,
+            created_at=NOW
+        )
+    )
+
+    stored = list(authzee_app.list_grants(effect=GrantEffect.DENY))
+
+    assert len(stored) == 1
+    assert stored[0].model_dump(exclude={"storage_id"}) == grant.model_dump(exclude={"storage_id"})
+    assert list(authzee_app.list_grants(effect=GrantEffect.ALLOW)) == []
+
+
+def test_grants_are_evaluated(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(storage_backend=SQLStorage(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS))
+    authzee_app.add_grant(
+        effect=GrantEffect.ALLOW,
+        grant=make_grant(jmespath_expression="resource.color", match_mode=GrantMatchMode.TRUTHY, result_match=None)
+    )
+    deny = authzee_app.add_grant(
+        effect=GrantEffect.DENY,
+        grant=make_grant(jmespath_expression="resource.size > `100`")
+    )
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+    assert authzee_app.authorize(resource=Balloon(color="red", size=500), **request_kwargs) is False
+
+    authzee_app.delete_grant(effect=GrantEffect.DENY, uuid=deny.uuid)
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=500), **request_kwargs) is True
+
+
+def test_new_columns_have_server_defaults(make_authzee, request_kwargs, new_event_loop):
+    storage = SQLStorage(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS)
+    authzee_app = make_authzee(storage_backend=storage)
+
+    async def insert_grant_without_new_columns():
+        async with storage._engine.begin() as conn:
+            await conn.execute(
+                text(
+                    "INSERT INTO allow_grant (uuid, name, description, resource_type, jmespath_expression, result_match) "
+                    "VALUES ('legacy', 'legacy', '', 'Balloon', '`true`', 'true')"
+                )
+            )
+            await conn.execute(
+                text(
+                    "INSERT INTO allow_grant_action_association (allow_grant_storage_id, resource_action) "
+                    "SELECT storage_id, 'BalloonAction.CreateBalloon' FROM allow_grant WHERE uuid = 'legacy'"
+                )
+            )
+
+    new_event_loop.run_until_complete(insert_grant_without_new_columns())
+    grant = list(authzee_app.list_grants(effect=GrantEffect.ALLOW))[0]
+
+    assert grant.uuid == "legacy"
+    assert grant.order_insensitive_arrays is False
+    assert grant.numeric_tolerance is None
+    assert grant.match_mode is GrantMatchMode.EQUAL
+    assert grant.applies_to_descendants is False
+    assert grant.provenance is None
+    assert grant.tags == {}
+    assert grant.canary_sample_rate is None
+    assert grant.created_at is None
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True
+
+
+def test_archived_grants_are_stored(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(
+        storage_backend=SQLStorage(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS),
+        clock=FixedClock(now=NOW)
+    )
+    grant = authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(tags={"status": "retired"}))
+
+    archived = authzee_app.archive_grant(effect=GrantEffect.ALLOW, uuid=grant.uuid)
+
+    assert archived.archived_at == NOW
+    assert authzee_app.list_archived_grants(effect=GrantEffect.ALLOW) == [archived]
+    assert archived.grant.tags == {"status": "retired"}
+    assert list(authzee_app.list_grants(effect=GrantEffect.ALLOW)) == []
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is False
+
+
+def test_grant_usage_is_stored(make_authzee, make_grant, request_kwargs):
+    authzee_app = make_authzee(
+        storage_backend=SQLStorage(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS),
+        clock=FixedClock(now=NOW),
+        config=AuthzeeConfig(track_grant_usage=True)
+    )
+    grant = authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant())
+
+    authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs)
+
+    assert authzee_app.get_grant_usage(effect=GrantEffect.ALLOW) == {grant.uuid: NOW}
+
+    authzee_app.delete_grant(effect=GrantEffect.ALLOW, uuid=grant.uuid)
+
+    assert authzee_app.get_grant_usage(effect=GrantEffect.ALLOW) == {}
+
+
+def test_relation_tuples_are_stored():
+    store = SQLRelationStore(sqlalchemy_async_engine_kwargs=SQLITE_KWARGS)
+    store.initialize()
+    store.setup()
+    store.add_relation(RelationTuple(subject="group:admins#member", relation="viewer", object="folder:reports"))
+    store.add_relation(RelationTuple(subject="user:alice", relation="member", object="group:admins"))
+
+    assert store.check(subject="user:alice", relation="viewer", object="folder:reports") is True
+    assert store.check(subject="user:bob", relation="viewer", object="folder:reports") is False
+
+    store.delete_relation(RelationTuple(subject="user:alice", relation="member", object="group:admins"))
+
+    assert store.check(subject="user:alice", relation="viewer", object="folder:reports") is False
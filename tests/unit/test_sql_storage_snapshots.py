@@ -0,0 +1,109 @@
+import pytest
+
+pytest.importorskip("sqlalchemy")
+pytest.importorskip("aiosqlite")
+
+from authzee import GrantEffect
+from authzee.storage import SQLStorage
+
+from conftest import Balloon
+
+
+def make_sql_storage() -> SQLStorage:
+    return SQLStorage(
+        sqlalchemy_async_engine_kwargs={"url": "sqlite+aiosqlite:///:memory:"},
+        default_page_size=2
+    )
+
+
+def add_grants(authzee_app, make_grant, names):
+    return [
+        authzee_app.add_grant(effect=GrantEffect.ALLOW, grant=make_grant(name=name))
+        for name in names
+    ]
+
+
+def read_pages(authzee_app, next_page_reference):
+    names = []
+    while True:
+        page = authzee_app.get_grants_page(effect=GrantEffect.ALLOW, next_page_reference=next_page_reference)
+        names += [grant.name for grant in page.grants]
+        next_page_reference = page.next_page_reference
+        if next_page_reference is None:
+            return names
+
+
+def test_snapshot_pages_exclude_grants_added_after_the_snapshot(make_authzee, make_grant):
+    authzee_app = make_authzee(storage_backend=make_sql_storage())
+    add_grants(authzee_app, make_grant, ["grant 0", "grant 1", "grant 2"])
+    snapshot = authzee_app.create_snapshot(effect=GrantEffect.ALLOW)
+    add_grants(authzee_app, make_grant, ["grant 3"])
+
+    assert read_pages(authzee_app, snapshot) == ["grant 0", "grant 1", "grant 2"]
+    assert read_pages(authzee_app, None) == ["grant 0", "grant 1", "grant 2", "grant 3"]
+
+
+def test_storage_ids_of_deleted_grants_are_not_reused(make_authzee, make_grant):
+    authzee_app = make_authzee(storage_backend=make_sql_storage())
+    grants = add_grants(authzee_app, make_grant, ["grant 0", "grant 1"])
+    authzee_app.delete_grant(effect=GrantEffect.ALLOW, uuid=grants[1].uuid)
+    snapshot = authzee_app.create_snapshot(effect=GrantEffect.ALLOW)
+    new_grant = add_grants(authzee_app, make_grant, ["grant 2"])[0]
+
+    assert new_grant.storage_id > grants[1].storage_id
+    assert read_pages(authzee_app, snapshot) == ["grant 0"]
+
+
+@pytest.mark.parametrize(
+    "consistent, expected_snapshots",
+    [
+        (False, 0),
+        (True, 1)
+    ]
+)
+def test_listings_only_snapshot_when_consistent(
+    make_authzee,
+    make_grant,
+    request_kwargs,
+    monkeypatch,
+    consistent,
+    expected_snapshots
+):
+    storage = make_sql_storage()
+    authzee_app = make_authzee(storage_backend=storage)
+    add_grants(authzee_app, make_grant, ["grant 0", "grant 1", "grant 2"])
+    snapshots = []
+    create_snapshot = storage.create_snapshot
+
+    def counting_create_snapshot(effect):
+        snapshots.append(effect)
+        return create_snapshot(effect=effect)
+
+    monkeypatch.setattr(storage, "create_snapshot", counting_create_snapshot)
+
+    grants = list(authzee_app.list_grants(effect=GrantEffect.ALLOW, consistent=consistent))
+    matching_grants = list(
+        authzee_app.list_matching_grants(
+            effect=GrantEffect.ALLOW,
+            resource=Balloon(color="red", size=1),
+            consistent=consistent,
+            **request_kwargs
+        )
+    )
+
+    assert len(grants) == 3
+    assert len(matching_grants) == 3
+    assert len(snapshots) == 2 * expected_snapshots
+
+
+def test_authorize_does_not_snapshot(make_authzee, make_grant, request_kwargs, monkeypatch):
+    storage = make_sql_storage()
+    authzee_app = make_authzee(storage_backend=storage)
+    add_grants(authzee_app, make_grant, ["grant 0"])
+
+    def failing_create_snapshot(effect):
+        raise AssertionError("authorize should not create a snapshot")
+
+    monkeypatch.setattr(storage, "create_snapshot", failing_create_snapshot)
+
+    assert authzee_app.authorize(resource=Balloon(color="red", size=1), **request_kwargs) is True